@@ -27,6 +27,8 @@ const ETH_FETCH_DEADLINE: u64 = 10_000;
 pub enum EthereumBlockId {
     Hash(EthereumHash),
     Number(EthereumBlockNumber),
+    /// The latest block the node considers finalized.
+    Finalized,
 }
 
 #[derive(Serialize, Deserialize)] // used in config
@@ -38,7 +40,15 @@ pub struct EthereumBlock {
     pub parent_hash: EthereumHash,
     pub number: EthereumBlockNumber,
     #[serde(skip)]
-    pub events: Vec<EthereumEvent>,
+    pub events: Vec<EthereumLogEvent>,
+}
+
+/// A Starport event, along with the log which emitted it.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
+pub struct EthereumLogEvent {
+    pub transaction_hash: EthereumHash,
+    pub log_index: u64,
+    pub event: EthereumEvent,
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
@@ -50,6 +60,8 @@ pub enum EthereumClientError {
     InvalidUTF8,
     JsonParseError,
     NoResult,
+    BlockHashMismatch,
+    NoQuorum,
 }
 
 #[derive(Deserialize, Serialize, RuntimeDebug, PartialEq)]
@@ -142,6 +154,13 @@ fn deserialize_get_block_response(response: &str) -> Result<BlockResponse, Ether
     Ok(result.map_err(|_| parse_error(response))?)
 }
 
+fn deserialize_get_block_batch_response(
+    response: &str,
+) -> Result<Vec<BlockResponse>, EthereumClientError> {
+    let result: serde_json::error::Result<Vec<BlockResponse>> = serde_json::from_str(response);
+    Ok(result.map_err(|_| parse_error(response))?)
+}
+
 fn deserialize_block_number_response(
     response: &str,
 ) -> Result<BlockNumberResponse, EthereumClientError> {
@@ -162,7 +181,6 @@ pub fn send_rpc(
     method: serde_json::Value,
     params: Vec<serde_json::Value>,
 ) -> Result<String, EthereumClientError> {
-    let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(ETH_FETCH_DEADLINE));
     let data = serde_json::json!({
         "jsonrpc": "2.0",
         "method": method,
@@ -170,6 +188,32 @@ pub fn send_rpc(
         "id":1
     })
     .to_string();
+    post_rpc(server, data)
+}
+
+/// Send a batch of calls in a single request, numbering them in order starting from 1.
+/// Note that the server may respond to the calls in any order.
+pub fn send_rpc_batch(
+    server: &str,
+    calls: Vec<(serde_json::Value, Vec<serde_json::Value>)>,
+) -> Result<String, EthereumClientError> {
+    let batch: Vec<serde_json::Value> = calls
+        .into_iter()
+        .enumerate()
+        .map(|(i, (method, params))| {
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": method,
+                "params": params,
+                "id": i + 1
+            })
+        })
+        .collect();
+    post_rpc(server, serde_json::Value::Array(batch).to_string())
+}
+
+fn post_rpc(server: &str, data: String) -> Result<String, EthereumClientError> {
+    let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(ETH_FETCH_DEADLINE));
     trace!("RPC: {}", &data);
 
     let request = http::Request::post(server, vec![data]);
@@ -229,16 +273,90 @@ pub fn get_block(
         debug!("Found no events for Eth block {:?}", block_id);
     }
 
+    let events = decode_log_objects(event_objects, &get_logs_response_str)?;
+
+    to_ethereum_block(block_obj, events)
+}
+
+/// Fetch the blocks numbered `from` up to (but not including) `to`, stopping at the first which does not exist yet.
+/// Headers are fetched with a single batch request, and events with a single `eth_getLogs` over the range.
+pub fn get_blocks_range(
+    server: &str,
+    eth_starport_address: &[u8; 20],
+    from: EthereumBlockNumber,
+    to: EthereumBlockNumber,
+) -> Result<Vec<EthereumBlock>, EthereumClientError> {
+    let block_objs = get_block_objects_range(server, from, to)?;
+    let last_block_obj = match block_objs.last() {
+        Some(block_obj) => block_obj,
+        None => return Ok(vec![]),
+    };
+
+    let get_logs_params = vec![serde_json::json!({
+        "address": format!("0x{}", ::hex::encode(&eth_starport_address[..])),
+        "fromBlock": encode_block_number_hex(from),
+        "toBlock": &last_block_obj.number
+    })];
+    debug!("get_logs_params: {:?}", get_logs_params.clone());
+    let get_logs_response_str: String = send_rpc(server, "eth_getLogs".into(), get_logs_params)?;
+    let get_logs_response = deserialize_get_logs_response(&get_logs_response_str)?;
+    let event_objects = get_logs_response
+        .result
+        .ok_or_else(|| parse_error(&get_logs_response_str[..]))?;
+
+    if event_objects.len() > 0 {
+        info!(
+            "Found {} events for Eth blocks {}..{}",
+            event_objects.len(),
+            from,
+            to
+        );
+    } else {
+        debug!("Found no events for Eth blocks {}..{}", from, to);
+    }
+
+    // every log must belong to one of the headers we have, or the range changed under us
+    for ev_obj in event_objects.iter().filter(|ev_obj| !is_removed(ev_obj)) {
+        if !block_objs.iter().any(|b| b.hash == ev_obj.block_hash) {
+            warn!("Log from unexpected block {:?}", ev_obj.block_hash);
+            return Err(EthereumClientError::BlockHashMismatch);
+        }
+    }
+
+    let mut blocks = Vec::with_capacity(block_objs.len());
+    for block_obj in block_objs {
+        let block_event_objects = event_objects
+            .iter()
+            .filter(|ev_obj| ev_obj.block_hash == block_obj.hash)
+            .cloned()
+            .collect();
+        let events = decode_log_objects(block_event_objects, &get_logs_response_str)?;
+        blocks.push(to_ethereum_block(block_obj, events)?);
+    }
+    Ok(blocks)
+}
+
+fn decode_log_objects(
+    event_objects: Vec<LogObject>,
+    response_str: &str,
+) -> Result<Vec<EthereumLogEvent>, EthereumClientError> {
     let mut events = Vec::with_capacity(event_objects.len());
     for ev_obj in event_objects {
-        let topics = ev_obj
-            .topics
-            .ok_or_else(|| parse_error(&get_logs_response_str[..]))?;
-        let data = ev_obj
-            .data
-            .ok_or_else(|| parse_error(&get_logs_response_str[..]))?;
+        if is_removed(&ev_obj) {
+            warn!("Skipping removed log {:?}", ev_obj.log_index);
+            continue;
+        }
+        let topics = ev_obj.topics.ok_or_else(|| parse_error(response_str))?;
+        let data = ev_obj.data.ok_or_else(|| parse_error(response_str))?;
+        let transaction_hash =
+            parse_word(ev_obj.transaction_hash).ok_or_else(|| parse_error(response_str))?;
+        let log_index = parse_u64(ev_obj.log_index).ok_or_else(|| parse_error(response_str))?;
         match events::decode_event(topics, data) {
-            Ok(event) => events.push(event),
+            Ok(event) => events.push(EthereumLogEvent {
+                transaction_hash,
+                log_index,
+                event,
+            }),
             Err(events::EventError::UnknownEventTopic(topic)) => {
                 warn!("Skipping unrecognized topic {:?}", topic)
             }
@@ -248,7 +366,18 @@ pub fn get_block(
             }
         }
     }
+    Ok(events)
+}
 
+/// Whether the node flagged the log as removed, due to a chain reorganization.
+fn is_removed(ev_obj: &LogObject) -> bool {
+    ev_obj.removed == Some(true)
+}
+
+fn to_ethereum_block(
+    block_obj: BlockObject,
+    events: Vec<EthereumLogEvent>,
+) -> Result<EthereumBlock, EthereumClientError> {
     // note these error messages are imperfect as they don't show the broken data
     //  but also should never happen and not worth fixing for now
     Ok(EthereumBlock {
@@ -260,6 +389,15 @@ pub fn get_block(
     })
 }
 
+/// Build a block from its header and the Starport logs it contains, as delivered by a node.
+pub fn parse_block(
+    block_obj: BlockObject,
+    log_objs: Vec<LogObject>,
+) -> Result<EthereumBlock, EthereumClientError> {
+    let events = decode_log_objects(log_objs, "log objects")?;
+    to_ethereum_block(block_obj, events)
+}
+
 pub fn get_block_object(
     server: &str,
     block_id: EthereumBlockId,
@@ -274,11 +412,55 @@ pub fn get_block_object(
             let params = vec![encode_block_number_hex(number).into(), false.into()];
             send_rpc(server, "eth_getBlockByNumber".into(), params)?
         }
+
+        EthereumBlockId::Finalized => {
+            let params = vec!["finalized".into(), false.into()];
+            send_rpc(server, "eth_getBlockByNumber".into(), params)?
+        }
     };
     let response = deserialize_get_block_response(&response_str)?;
     response.result.ok_or(EthereumClientError::NoResult)
 }
 
+/// Fetch just the number of a block, e.g. to find out how far the chain has finalized.
+pub fn get_block_number(
+    server: &str,
+    block_id: EthereumBlockId,
+) -> Result<EthereumBlockNumber, EthereumClientError> {
+    let block_obj = get_block_object(server, block_id)?;
+    parse_u64(block_obj.number).ok_or_else(|| parse_error("bad block number"))
+}
+
+/// Fetch the block headers numbered `from` up to (but not including) `to` in a single batch request.
+/// Stops at the first block which does not exist yet, so the result may be shorter than the range.
+pub fn get_block_objects_range(
+    server: &str,
+    from: EthereumBlockNumber,
+    to: EthereumBlockNumber,
+) -> Result<Vec<BlockObject>, EthereumClientError> {
+    if from >= to {
+        return Ok(vec![]);
+    }
+    let calls = (from..to)
+        .map(|number| {
+            let params = vec![encode_block_number_hex(number).into(), false.into()];
+            ("eth_getBlockByNumber".into(), params)
+        })
+        .collect();
+    let response_str: String = send_rpc_batch(server, calls)?;
+    let mut responses = deserialize_get_block_batch_response(&response_str)?;
+    responses.sort_by_key(|response| response.id);
+
+    let mut block_objs = vec![];
+    for (expected_id, response) in (1..).zip(responses) {
+        match (response.id, response.result) {
+            (Some(id), Some(block_obj)) if id == expected_id => block_objs.push(block_obj),
+            _ => break,
+        }
+    }
+    Ok(block_objs)
+}
+
 pub fn get_latest_block_number(server: &str) -> Result<u64, EthereumClientError> {
     let response_str: String = send_rpc(server, "eth_blockNumber".into(), vec![])?;
     let response = deserialize_block_number_response(&response_str)?;
@@ -287,6 +469,166 @@ pub fn get_latest_block_number(server: &str) -> Result<u64, EthereumClientError>
         .ok_or(EthereumClientError::JsonParseError)
 }
 
+/// The node RPC endpoints configured by a validator for a single chain.
+///
+/// With a quorum of one, requests go to the first endpoint and fail over to the next one only
+/// when the transport fails. With a greater quorum, every endpoint is queried, and at least
+/// `quorum` of them must agree on the result.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct RpcEndpoints {
+    pub servers: Vec<String>,
+    pub quorum: usize,
+}
+
+impl RpcEndpoints {
+    /// Parse a comma-separated list of URLs, and an optional quorum out of them.
+    /// Returns None if there are no URLs, or if the quorum cannot possibly be reached.
+    pub fn parse(urls: &str, quorum: Option<&str>) -> Option<RpcEndpoints> {
+        let servers: Vec<String> = urls
+            .split(',')
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+            .map(String::from)
+            .collect();
+        let quorum = match quorum {
+            Some(quorum) => quorum.trim().parse().ok()?,
+            None => 1,
+        };
+        if servers.is_empty() || quorum == 0 || quorum > servers.len() {
+            return None;
+        }
+        Some(RpcEndpoints { servers, quorum })
+    }
+
+    pub fn get_block(
+        &self,
+        eth_starport_address: &[u8; 20],
+        block_id: EthereumBlockId,
+    ) -> Result<EthereumBlock, EthereumClientError> {
+        let request = |server: &str| get_block(server, eth_starport_address, block_id.clone());
+        if self.quorum > 1 {
+            self.quorum(request)
+        } else {
+            self.failover(request)
+        }
+    }
+
+    /// Fetch just the number of a block, as per `get_block_number`.
+    pub fn get_block_number(
+        &self,
+        block_id: EthereumBlockId,
+    ) -> Result<EthereumBlockNumber, EthereumClientError> {
+        let request = |server: &str| get_block_number(server, block_id.clone());
+        if self.quorum > 1 {
+            self.quorum(request)
+        } else {
+            self.failover(request)
+        }
+    }
+
+    /// Fetch a range of blocks, as per `get_blocks_range`.
+    /// When a quorum is required, the result is the longest run of blocks it agrees on,
+    /// which may be shorter than what any one endpoint returned.
+    pub fn get_blocks_range(
+        &self,
+        eth_starport_address: &[u8; 20],
+        from: EthereumBlockNumber,
+        to: EthereumBlockNumber,
+    ) -> Result<Vec<EthereumBlock>, EthereumClientError> {
+        let request = |server: &str| get_blocks_range(server, eth_starport_address, from, to);
+        if self.quorum > 1 {
+            self.quorum_prefix(request)
+        } else {
+            self.failover(request)
+        }
+    }
+
+    fn failover<T>(
+        &self,
+        request: impl Fn(&str) -> Result<T, EthereumClientError>,
+    ) -> Result<T, EthereumClientError> {
+        let mut result = Err(EthereumClientError::NoResult);
+        for server in &self.servers {
+            result = request(server);
+            match result {
+                Err(err) if is_transport_error(err) => {
+                    warn!("RPC endpoint failed ({:?}), failing over", err)
+                }
+                _ => break,
+            }
+        }
+        result
+    }
+
+    fn quorum<T: PartialEq>(
+        &self,
+        request: impl Fn(&str) -> Result<T, EthereumClientError>,
+    ) -> Result<T, EthereumClientError> {
+        let results: Vec<Result<T, EthereumClientError>> =
+            self.servers.iter().map(|server| request(server)).collect();
+        let agreed = results
+            .iter()
+            .position(|result| results.iter().filter(|r| *r == result).count() >= self.quorum);
+        match agreed {
+            Some(index) => results.into_iter().nth(index).unwrap(),
+            None => {
+                warn!("RPC endpoints failed to reach a quorum of {}", self.quorum);
+                Err(EthereumClientError::NoQuorum)
+            }
+        }
+    }
+
+    fn quorum_prefix<T: Clone + PartialEq>(
+        &self,
+        request: impl Fn(&str) -> Result<Vec<T>, EthereumClientError>,
+    ) -> Result<Vec<T>, EthereumClientError> {
+        let mut first_err = None;
+        let mut results = vec![];
+        for server in &self.servers {
+            match request(server) {
+                Ok(items) => results.push(items),
+                Err(err) => {
+                    first_err.get_or_insert(err);
+                }
+            }
+        }
+        if results.len() < self.quorum {
+            warn!("RPC endpoints failed to reach a quorum of {}", self.quorum);
+            return Err(first_err.unwrap_or(EthereumClientError::NoQuorum));
+        }
+
+        // For each candidate, the longest prefix shared with enough of the results
+        let agreed = results
+            .iter()
+            .map(|candidate| {
+                let mut shared: Vec<usize> = results
+                    .iter()
+                    .map(|r| {
+                        candidate
+                            .iter()
+                            .zip(r.iter())
+                            .take_while(|(a, b)| a == b)
+                            .count()
+                    })
+                    .collect();
+                shared.sort_unstable_by(|a, b| b.cmp(a));
+                &candidate[..shared[self.quorum - 1]]
+            })
+            .max_by_key(|prefix| prefix.len())
+            .unwrap_or(&[]);
+        Ok(agreed.to_vec())
+    }
+}
+
+fn is_transport_error(err: EthereumClientError) -> bool {
+    matches!(
+        err,
+        EthereumClientError::HttpIoError
+            | EthereumClientError::HttpTimeout
+            | EthereumClientError::HttpErrorCode(_)
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -348,7 +690,11 @@ mod tests {
             );
             assert_eq!(block.number, 1286);
             assert_eq!(
-                block.events,
+                block
+                    .events
+                    .iter()
+                    .map(|e| e.event.clone())
+                    .collect::<Vec<_>>(),
                 vec![EthereumEvent::Lock {
                     asset: [
                         238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238,
@@ -366,6 +712,149 @@ mod tests {
                     amount: 500000000000000000
                 }]
             );
+            assert_eq!(
+                block.events[0].transaction_hash.to_vec(),
+                ::hex::decode("bae1c242aea30e9ae20cb6c37e2f2d08982e31b42bf3d7dbde6466396abb360e")
+                    .unwrap()
+            );
+            assert_eq!(block.events[0].log_index, 0x58);
+        });
+    }
+
+    fn expect_get_blocks_range_requests(state: &mut testing::OffchainState, logs_response: &str) {
+        state.expect_request(testing::PendingRequest {
+            method: "POST".into(),
+            uri: "https://mainnet-eth.compound.finance".into(),
+            headers: vec![("Content-Type".to_owned(), "application/json".to_owned())],
+            body: br#"[{"jsonrpc":"2.0","method":"eth_getBlockByNumber","params":["0x506",false],"id":1},{"jsonrpc":"2.0","method":"eth_getBlockByNumber","params":["0x507",false],"id":2},{"jsonrpc":"2.0","method":"eth_getBlockByNumber","params":["0x508",false],"id":3}]"#.to_vec(),
+            response: Some(br#"[{"jsonrpc":"2.0","id":2,"result":{"hash":"0x72314c1c6837e15e60c5b6732f092118dd25e3ec681f5e089b3a9ad2374e5a8a","number":"0x507","parentHash":"0x61314c1c6837e15e60c5b6732f092118dd25e3ec681f5e089b3a9ad2374e5a8a"}},{"jsonrpc":"2.0","id":1,"result":{"hash":"0x61314c1c6837e15e60c5b6732f092118dd25e3ec681f5e089b3a9ad2374e5a8a","number":"0x506","parentHash":"0x062e77dced431eb671a56839f96da912f68d841024665748d38cd3d6795961ea"}},{"jsonrpc":"2.0","id":3,"result":null}]"#.to_vec()),
+            sent: true,
+            ..Default::default()
+        });
+        state.expect_request(testing::PendingRequest {
+            method: "POST".into(),
+            uri: "https://mainnet-eth.compound.finance".into(),
+            headers: vec![("Content-Type".to_owned(), "application/json".to_owned())],
+            body: br#"{"jsonrpc":"2.0","method":"eth_getLogs","params":[{"address":"0x3a275655586a049fe860be867d10cdae2ffc0f33","fromBlock":"0x506","toBlock":"0x507"}],"id":1}"#.to_vec(),
+            response: Some(logs_response.as_bytes().to_vec()),
+            sent: true,
+            ..Default::default()
+        });
+    }
+
+    const STARPORT: [u8; 20] = [
+        58, 39, 86, 85, 88, 106, 4, 159, 232, 96, 190, 134, 125, 16, 205, 174, 47, 252, 15, 51,
+    ];
+
+    #[test]
+    fn test_get_blocks_range() {
+        let (offchain, state) = testing::TestOffchainExt::new();
+        let mut t = sp_io::TestExternalities::default();
+        t.register_extension(OffchainDbExt::new(offchain.clone()));
+        t.register_extension(OffchainWorkerExt::new(offchain));
+        expect_get_blocks_range_requests(
+            &mut state.write(),
+            r#"{"jsonrpc":"2.0","id":1,"result":[{"address":"0x3a275655586a049fe860be867d10cdae2ffc0f33","blockHash":"0x72314c1c6837e15e60c5b6732f092118dd25e3ec681f5e089b3a9ad2374e5a8a","blockNumber":"0x507","data":"0x000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000006f05b59d3b2000000000000000000000000000000000000000000000000000000000000000000034554480000000000000000000000000000000000000000000000000000000000","logIndex":"0x58","removed":false,"topics":["0xc459acef3ffe957663bb49d644b20d0c790bcb41573893752a72ba6f023b9386","0x000000000000000000000000eeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee","0x000000000000000000000000d3a38d4bd07b87e4516f30ee46cfe8ec4e8b73a4","0xd3a38d4bd07b87e4516f30ee46cfe8ec4e8b73a4000000000000000000000000"],"transactionHash":"0xbae1c242aea30e9ae20cb6c37e2f2d08982e31b42bf3d7dbde6466396abb360e","transactionIndex":"0x24"}]}"#,
+        );
+        t.execute_with(|| {
+            let blocks = get_blocks_range(
+                "https://mainnet-eth.compound.finance",
+                &STARPORT,
+                0x506,
+                0x509,
+            )
+            .unwrap();
+            assert_eq!(blocks.len(), 2);
+            assert_eq!(blocks[0].number, 0x506);
+            assert_eq!(blocks[0].events, vec![]);
+            assert_eq!(blocks[1].number, 0x507);
+            assert_eq!(blocks[1].parent_hash, blocks[0].hash);
+            assert_eq!(
+                blocks[1]
+                    .events
+                    .iter()
+                    .map(|e| e.event.clone())
+                    .collect::<Vec<_>>(),
+                vec![EthereumEvent::Lock {
+                    asset: [238; 20],
+                    sender: [
+                        211, 163, 141, 75, 208, 123, 135, 228, 81, 111, 48, 238, 70, 207, 232, 236,
+                        78, 139, 115, 164
+                    ],
+                    chain: String::from("ETH"),
+                    recipient: [
+                        211, 163, 141, 75, 208, 123, 135, 228, 81, 111, 48, 238, 70, 207, 232, 236,
+                        78, 139, 115, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0
+                    ],
+                    amount: 500000000000000000
+                }]
+            );
+            assert_eq!(blocks[1].events[0].log_index, 0x58);
+        });
+    }
+
+    #[test]
+    fn test_get_blocks_range_log_from_unexpected_block() {
+        let (offchain, state) = testing::TestOffchainExt::new();
+        let mut t = sp_io::TestExternalities::default();
+        t.register_extension(OffchainDbExt::new(offchain.clone()));
+        t.register_extension(OffchainWorkerExt::new(offchain));
+        expect_get_blocks_range_requests(
+            &mut state.write(),
+            r#"{"jsonrpc":"2.0","id":1,"result":[{"address":"0x3a275655586a049fe860be867d10cdae2ffc0f33","blockHash":"0xc94ceed3c8c68f09b1c7be28f594cc6fb01f9cdd7b68f3bf516cab9e89486fcf","blockNumber":"0x9928cb","data":"0x000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000006f05b59d3b2000000000000000000000000000000000000000000000000000000000000000000034554480000000000000000000000000000000000000000000000000000000000","logIndex":"0x58","removed":false,"topics":["0xc459acef3ffe957663bb49d644b20d0c790bcb41573893752a72ba6f023b9386","0x000000000000000000000000eeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee","0x000000000000000000000000d3a38d4bd07b87e4516f30ee46cfe8ec4e8b73a4","0xd3a38d4bd07b87e4516f30ee46cfe8ec4e8b73a4000000000000000000000000"],"transactionHash":"0xbae1c242aea30e9ae20cb6c37e2f2d08982e31b42bf3d7dbde6466396abb360e","transactionIndex":"0x24"}]}"#,
+        );
+        t.execute_with(|| {
+            assert_eq!(
+                get_blocks_range(
+                    "https://mainnet-eth.compound.finance",
+                    &STARPORT,
+                    0x506,
+                    0x509,
+                ),
+                Err(EthereumClientError::BlockHashMismatch)
+            );
+        });
+    }
+
+    #[test]
+    fn test_get_blocks_range_skips_removed_logs() {
+        let (offchain, state) = testing::TestOffchainExt::new();
+        let mut t = sp_io::TestExternalities::default();
+        t.register_extension(OffchainDbExt::new(offchain.clone()));
+        t.register_extension(OffchainWorkerExt::new(offchain));
+        expect_get_blocks_range_requests(
+            &mut state.write(),
+            r#"{"jsonrpc":"2.0","id":1,"result":[{"address":"0x3a275655586a049fe860be867d10cdae2ffc0f33","blockHash":"0xc94ceed3c8c68f09b1c7be28f594cc6fb01f9cdd7b68f3bf516cab9e89486fcf","blockNumber":"0x507","data":"0x000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000006f05b59d3b2000000000000000000000000000000000000000000000000000000000000000000034554480000000000000000000000000000000000000000000000000000000000","logIndex":"0x58","removed":true,"topics":["0xc459acef3ffe957663bb49d644b20d0c790bcb41573893752a72ba6f023b9386","0x000000000000000000000000eeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee","0x000000000000000000000000d3a38d4bd07b87e4516f30ee46cfe8ec4e8b73a4","0xd3a38d4bd07b87e4516f30ee46cfe8ec4e8b73a4000000000000000000000000"],"transactionHash":"0xbae1c242aea30e9ae20cb6c37e2f2d08982e31b42bf3d7dbde6466396abb360e","transactionIndex":"0x24"}]}"#,
+        );
+        t.execute_with(|| {
+            let blocks = get_blocks_range(
+                "https://mainnet-eth.compound.finance",
+                &STARPORT,
+                0x506,
+                0x509,
+            )
+            .unwrap();
+            assert_eq!(blocks.len(), 2);
+            assert_eq!(blocks[0].events, vec![]);
+            assert_eq!(blocks[1].events, vec![]);
+        });
+    }
+
+    #[test]
+    fn test_get_blocks_range_empty() {
+        let (offchain, _state) = testing::TestOffchainExt::new();
+        let mut t = sp_io::TestExternalities::default();
+        t.register_extension(OffchainWorkerExt::new(offchain));
+        t.execute_with(|| {
+            assert_eq!(
+                get_blocks_range(
+                    "https://mainnet-eth.compound.finance",
+                    &STARPORT,
+                    0x506,
+                    0x506,
+                ),
+                Ok(vec![])
+            );
         });
     }
 
@@ -394,6 +883,33 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_get_finalized_block_number() {
+        let (offchain, state) = testing::TestOffchainExt::new();
+        let mut t = sp_io::TestExternalities::default();
+        t.register_extension(OffchainDbExt::new(offchain.clone()));
+        t.register_extension(OffchainWorkerExt::new(offchain));
+        {
+            let mut s = state.write();
+            s.expect_request(testing::PendingRequest {
+                method: "POST".into(),
+                uri: "https://mainnet-eth.compound.finance".into(),
+                headers: vec![("Content-Type".to_owned(), "application/json".to_owned())],
+                body: br#"{"jsonrpc":"2.0","method":"eth_getBlockByNumber","params":["finalized",false],"id":1}"#.to_vec(),
+                response: Some(br#"{"jsonrpc":"2.0","id":1,"result":{"hash":"0x61314c1c6837e15e60c5b6732f092118dd25e3ec681f5e089b3a9ad2374e5a8a","number":"0x506","parentHash":"0x062e77dced431eb671a56839f96da912f68d841024665748d38cd3d6795961ea"}}"#.to_vec()),
+                sent: true,
+                ..Default::default()
+            });
+        }
+        t.execute_with(|| {
+            let result = get_block_number(
+                "https://mainnet-eth.compound.finance",
+                EthereumBlockId::Finalized,
+            );
+            assert_eq!(result, Ok(0x506));
+        });
+    }
+
     #[test]
     fn test_get_block_object() {
         let (offchain, state) = testing::TestOffchainExt::new();
@@ -538,4 +1054,89 @@ mod tests {
     fn test_encode_block_number_hex() {
         assert_eq!(encode_block_number_hex(0xb27467 + 1), "0xB27468");
     }
+
+    #[test]
+    fn test_rpc_endpoints_parse() {
+        assert_eq!(
+            RpcEndpoints::parse("http://a, http://b,,", None),
+            Some(RpcEndpoints {
+                servers: vec![String::from("http://a"), String::from("http://b")],
+                quorum: 1,
+            })
+        );
+        assert_eq!(
+            RpcEndpoints::parse("http://a,http://b", Some("2")).map(|e| e.quorum),
+            Some(2)
+        );
+        assert_eq!(RpcEndpoints::parse("", None), None);
+        assert_eq!(RpcEndpoints::parse("http://a", Some("0")), None);
+        assert_eq!(RpcEndpoints::parse("http://a", Some("2")), None);
+        assert_eq!(RpcEndpoints::parse("http://a", Some("one")), None);
+    }
+
+    #[test]
+    fn test_rpc_endpoints_failover() {
+        let endpoints = RpcEndpoints::parse("http://a,http://b,http://c", None).unwrap();
+        let result = endpoints.failover(|server| match server {
+            "http://a" => Err(EthereumClientError::HttpTimeout),
+            "http://b" => Err(EthereumClientError::HttpErrorCode(502)),
+            _ => Ok(server.to_string()),
+        });
+        assert_eq!(result, Ok(String::from("http://c")));
+
+        // Only transport errors fail over, an answer from the node is final
+        let result: Result<(), _> = endpoints.failover(|server| match server {
+            "http://a" => Err(EthereumClientError::NoResult),
+            _ => Ok(()),
+        });
+        assert_eq!(result, Err(EthereumClientError::NoResult));
+
+        let result: Result<(), _> = endpoints.failover(|_| Err(EthereumClientError::HttpIoError));
+        assert_eq!(result, Err(EthereumClientError::HttpIoError));
+    }
+
+    #[test]
+    fn test_rpc_endpoints_quorum() {
+        let endpoints = RpcEndpoints::parse("http://a,http://b,http://c", Some("2")).unwrap();
+        let result = endpoints.quorum(|server| match server {
+            "http://a" => Ok(1),
+            "http://b" => Err(EthereumClientError::HttpTimeout),
+            _ => Ok(1),
+        });
+        assert_eq!(result, Ok(1));
+
+        let result = endpoints.quorum(|server| match server {
+            "http://a" => Ok(1),
+            "http://b" => Ok(2),
+            _ => Err(EthereumClientError::HttpTimeout),
+        });
+        assert_eq!(result, Err(EthereumClientError::NoQuorum));
+
+        let result: Result<(), _> = endpoints.quorum(|_| Err(EthereumClientError::NoResult));
+        assert_eq!(result, Err(EthereumClientError::NoResult));
+    }
+
+    #[test]
+    fn test_rpc_endpoints_quorum_prefix() {
+        let endpoints = RpcEndpoints::parse("http://a,http://b,http://c", Some("2")).unwrap();
+        let result = endpoints.quorum_prefix(|server| match server {
+            "http://a" => Ok(vec![1, 2, 3, 4]),
+            "http://b" => Ok(vec![1, 2, 3]),
+            _ => Ok(vec![1, 5]),
+        });
+        assert_eq!(result, Ok(vec![1, 2, 3]));
+
+        let result = endpoints.quorum_prefix(|server| match server {
+            "http://a" => Ok(vec![1, 2]),
+            "http://b" => Ok(vec![3, 4]),
+            _ => Err(EthereumClientError::HttpTimeout),
+        });
+        assert_eq!(result, Ok(vec![]));
+
+        let result = endpoints.quorum_prefix(|server| match server {
+            "http://a" => Ok(vec![1, 2]),
+            _ => Err(EthereumClientError::HttpTimeout),
+        });
+        assert_eq!(result, Err(EthereumClientError::HttpTimeout));
+    }
 }
@@ -0,0 +1,115 @@
+use our_std::vec::Vec;
+
+use crate::{keccak, HashedMessageBytes};
+
+/// The prefix which distinguishes EIP-712 typed data from other signed messages.
+pub const EIP712_PREFIX: &[u8] = b"\x19\x01";
+
+/// Encode an unsigned integer as an EIP-712 `uint256` value.
+pub fn eip712_encode_uint(value: u128) -> [u8; 32] {
+    let mut encoded = [0u8; 32];
+    encoded[16..].copy_from_slice(&value.to_be_bytes());
+    encoded
+}
+
+/// Encode an address as an EIP-712 `address` value.
+pub fn eip712_encode_address(address: &[u8; 20]) -> [u8; 32] {
+    let mut encoded = [0u8; 32];
+    encoded[12..].copy_from_slice(&address[..]);
+    encoded
+}
+
+/// Encode a string as an EIP-712 `string` value, i.e. by its hash.
+pub fn eip712_encode_string(value: &str) -> [u8; 32] {
+    keccak(value.as_bytes())
+}
+
+/// Encode an array of already encoded structs as an EIP-712 array value.
+pub fn eip712_encode_array(values: &[[u8; 32]]) -> [u8; 32] {
+    keccak(&values.concat()[..])
+}
+
+/// Hash a struct given its full encoded type and its encoded member values, in order.
+pub fn eip712_hash_struct(encoded_type: &[u8], values: &[[u8; 32]]) -> HashedMessageBytes {
+    let mut encoded: Vec<u8> = Vec::with_capacity(32 * (values.len() + 1));
+    encoded.extend_from_slice(&keccak(encoded_type)[..]);
+    for value in values {
+        encoded.extend_from_slice(&value[..]);
+    }
+    keccak(&encoded[..])
+}
+
+/// Return the message whose keccak is the EIP-712 digest to sign, for a domain and struct hash.
+pub fn eip712_message(domain_separator: &[u8; 32], struct_hash: &[u8; 32]) -> Vec<u8> {
+    let mut message: Vec<u8> = Vec::with_capacity(EIP712_PREFIX.len() + 64);
+    message.extend_from_slice(EIP712_PREFIX);
+    message.extend_from_slice(&domain_separator[..]);
+    message.extend_from_slice(&struct_hash[..]);
+    message
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // The example from the EIP-712 specification
+    const DOMAIN_TYPE: &[u8] =
+        b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)";
+    const MAIL_TYPE: &[u8] =
+        b"Mail(Person from,Person to,string contents)Person(string name,address wallet)";
+    const PERSON_TYPE: &[u8] = b"Person(string name,address wallet)";
+
+    fn person(name: &str, wallet: [u8; 20]) -> [u8; 32] {
+        eip712_hash_struct(
+            PERSON_TYPE,
+            &[eip712_encode_string(name), eip712_encode_address(&wallet)],
+        )
+    }
+
+    #[test]
+    fn test_eip712_mail_example() {
+        let domain_separator = eip712_hash_struct(
+            DOMAIN_TYPE,
+            &[
+                eip712_encode_string("Ether Mail"),
+                eip712_encode_string("1"),
+                eip712_encode_uint(1),
+                eip712_encode_address(&[0xcc; 20]),
+            ],
+        );
+        let mail = eip712_hash_struct(
+            MAIL_TYPE,
+            &[
+                person(
+                    "Cow",
+                    crate::eth_str_to_address("0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826")
+                        .unwrap(),
+                ),
+                person("Bob", [0xbb; 20]),
+                eip712_encode_string("Hello, Bob!"),
+            ],
+        );
+
+        assert_eq!(
+            hex::encode(domain_separator),
+            "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+        );
+        assert_eq!(
+            hex::encode(mail),
+            "c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e"
+        );
+        assert_eq!(
+            hex::encode(keccak(&eip712_message(&domain_separator, &mail)[..])),
+            "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+        );
+    }
+
+    #[test]
+    fn test_eip712_encode_array() {
+        assert_eq!(eip712_encode_array(&[]), keccak(&[]));
+        assert_eq!(
+            eip712_encode_array(&[[1; 32], [2; 32]]),
+            keccak(&[[1u8; 32], [2u8; 32]].concat()[..])
+        );
+    }
+}
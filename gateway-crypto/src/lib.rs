@@ -11,6 +11,10 @@ mod dev;
 #[cfg(feature = "std")]
 pub use crate::dev::*;
 
+mod eip712;
 mod no_std;
+mod ss58;
 
+pub use eip712::*;
 pub use no_std::*;
+pub use ss58::*;
@@ -199,6 +199,7 @@ pub fn eth_hash_string(eth_hash: &[u8; 32]) -> String {
     format!("0x{}", hex::encode(eth_hash))
 }
 
+/// Parse a Gateway address, either as 0x-prefixed hex or SS58 encoded with the Gateway prefix.
 pub fn gateway_str_to_address(address_str: &str) -> Option<[u8; 32]> {
     if address_str.len() == 66 && &address_str[0..2] == "0x" {
         if let Ok(bytes) = hex::decode(&address_str[2..66]) {
@@ -206,8 +207,34 @@ pub fn gateway_str_to_address(address_str: &str) -> Option<[u8; 32]> {
                 return Some(hex_address);
             }
         }
+        return None;
     }
-    return None;
+    match crate::ss58::gateway_ss58_decode(address_str) {
+        Some((prefix, address)) if prefix == crate::ss58::GATEWAY_SS58_PREFIX as u16 => {
+            Some(address)
+        }
+        _ => None,
+    }
+}
+
+/// Render a Gateway address as SS58 encoded with the Gateway prefix.
+pub fn gateway_address_string(address: &[u8; 32]) -> String {
+    crate::ss58::gateway_ss58_encode(address, crate::ss58::GATEWAY_SS58_PREFIX as u16)
+        .expect("Gateway SS58 prefix is valid")
+}
+
+/// Parse a Polkadot or parachain address, either as 0x-prefixed hex or SS58 encoded with any prefix.
+pub fn dot_str_to_address(address_str: &str) -> Option<[u8; 32]> {
+    if address_str.starts_with("0x") {
+        return eth_str_to_hash(address_str);
+    }
+    crate::ss58::gateway_ss58_decode(address_str).map(|(_prefix, address)| address)
+}
+
+/// Render a Polkadot address as SS58 encoded with the Polkadot prefix.
+pub fn dot_address_string(address: &[u8; 32]) -> String {
+    crate::ss58::gateway_ss58_encode(address, crate::ss58::POLKADOT_SS58_PREFIX as u16)
+        .expect("Polkadot SS58 prefix is valid")
 }
 
 #[cfg(test)]
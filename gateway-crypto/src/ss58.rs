@@ -0,0 +1,168 @@
+use our_std::{convert::TryInto, vec::Vec};
+use sp_core::hashing::blake2_512;
+
+/// The SS58 address type (network prefix) used for Gateway accounts.
+pub const GATEWAY_SS58_PREFIX: u8 = 42;
+
+/// The SS58 address type (network prefix) used for Polkadot relay chain accounts.
+pub const POLKADOT_SS58_PREFIX: u8 = 0;
+
+/// The context prefix hashed together with the payload to produce an SS58 checksum.
+const SS58_CHECKSUM_PREFIX: &[u8] = b"SS58PRE";
+
+/// The number of checksum bytes used for 32 byte account ids.
+const SS58_CHECKSUM_LEN: usize = 2;
+
+/// The highest address type (network prefix) representable in the SS58 format.
+const SS58_MAX_PREFIX: u16 = 16383;
+
+fn ss58_checksum(payload: &[u8]) -> [u8; SS58_CHECKSUM_LEN] {
+    let mut preimage = Vec::with_capacity(SS58_CHECKSUM_PREFIX.len() + payload.len());
+    preimage.extend_from_slice(SS58_CHECKSUM_PREFIX);
+    preimage.extend_from_slice(payload);
+    let hash = blake2_512(&preimage[..]);
+    [hash[0], hash[1]]
+}
+
+fn ss58_prefix_bytes(prefix: u16) -> Vec<u8> {
+    match prefix {
+        0..=63 => vec![prefix as u8],
+        _ => {
+            let first = ((prefix & 0b0000_0000_1111_1100) as u8) >> 2;
+            let second = ((prefix >> 8) as u8) | ((prefix & 0b0000_0000_0000_0011) as u8) << 6;
+            vec![first | 0b0100_0000, second]
+        }
+    }
+}
+
+/// Decode an SS58 encoded Gateway address, verifying its checksum.
+/// Returns the address type (network prefix) along with the raw account id.
+pub fn gateway_ss58_decode(address_str: &str) -> Option<(u16, [u8; 32])> {
+    let data = bs58::decode(address_str).into_vec().ok()?;
+    let (prefix, prefix_len) = match data.get(0)? {
+        first @ 0..=63 => (*first as u16, 1),
+        first @ 64..=127 => {
+            let second = *data.get(1)?;
+            let lower = (first << 2) | (second >> 6);
+            let upper = second & 0b0011_1111;
+            (lower as u16 | (upper as u16) << 8, 2)
+        }
+        _ => return None,
+    };
+
+    if data.len() != prefix_len + 32 + SS58_CHECKSUM_LEN {
+        return None;
+    }
+
+    let (payload, checksum) = data.split_at(prefix_len + 32);
+    if ss58_checksum(payload)[..] != checksum[..] {
+        return None;
+    }
+
+    Some((prefix, payload[prefix_len..].try_into().ok()?))
+}
+
+/// Encode a Gateway address in the SS58 format, for the given address type (network prefix).
+pub fn gateway_ss58_encode(address: &[u8; 32], prefix: u16) -> Option<String> {
+    if prefix > SS58_MAX_PREFIX {
+        return None;
+    }
+
+    let mut data = ss58_prefix_bytes(prefix);
+    data.extend_from_slice(&address[..]);
+    let checksum = ss58_checksum(&data[..]);
+    data.extend_from_slice(&checksum[..]);
+    Some(bs58::encode(data).into_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Alice's well-known development key
+    const ALICE: [u8; 32] = [
+        212, 53, 147, 199, 21, 253, 211, 28, 97, 20, 26, 189, 4, 169, 159, 214, 130, 44, 133, 88,
+        133, 76, 205, 227, 154, 86, 132, 231, 165, 109, 162, 125,
+    ];
+    const ALICE_SS58_SUBSTRATE: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+    const ALICE_SS58_POLKADOT: &str = "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5";
+
+    #[test]
+    fn test_ss58_decode() {
+        assert_eq!(gateway_ss58_decode(ALICE_SS58_SUBSTRATE), Some((42, ALICE)));
+        assert_eq!(gateway_ss58_decode(ALICE_SS58_POLKADOT), Some((0, ALICE)));
+    }
+
+    #[test]
+    fn test_ss58_decode_bad_checksum() {
+        assert_eq!(
+            gateway_ss58_decode("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQZ"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_ss58_decode_bad_input() {
+        assert_eq!(gateway_ss58_decode(""), None);
+        assert_eq!(gateway_ss58_decode("0x0101"), None);
+        assert_eq!(
+            gateway_ss58_decode("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehX"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_ss58_encode() {
+        assert_eq!(
+            gateway_ss58_encode(&ALICE, 42),
+            Some(String::from(ALICE_SS58_SUBSTRATE))
+        );
+        assert_eq!(
+            gateway_ss58_encode(&ALICE, 0),
+            Some(String::from(ALICE_SS58_POLKADOT))
+        );
+        assert_eq!(gateway_ss58_encode(&ALICE, SS58_MAX_PREFIX + 1), None);
+    }
+
+    #[test]
+    fn test_ss58_round_trip_two_byte_prefix() {
+        for prefix in [64, 255, 1000, SS58_MAX_PREFIX].iter() {
+            let encoded = gateway_ss58_encode(&ALICE, *prefix).unwrap();
+            assert_eq!(gateway_ss58_decode(&encoded), Some((*prefix, ALICE)));
+        }
+    }
+
+    #[test]
+    fn test_gateway_str_to_address() {
+        assert_eq!(
+            crate::gateway_str_to_address(ALICE_SS58_SUBSTRATE),
+            Some(ALICE)
+        );
+        assert_eq!(crate::gateway_str_to_address(ALICE_SS58_POLKADOT), None);
+        assert_eq!(
+            crate::gateway_str_to_address(&format!("0x{}", hex::encode(ALICE))),
+            Some(ALICE)
+        );
+    }
+
+    #[test]
+    fn test_gateway_address_string() {
+        assert_eq!(crate::gateway_address_string(&ALICE), ALICE_SS58_SUBSTRATE);
+    }
+
+    #[test]
+    fn test_dot_str_to_address() {
+        assert_eq!(crate::dot_str_to_address(ALICE_SS58_POLKADOT), Some(ALICE));
+        assert_eq!(crate::dot_str_to_address(ALICE_SS58_SUBSTRATE), Some(ALICE));
+        assert_eq!(
+            crate::dot_str_to_address(&format!("0x{}", hex::encode(ALICE))),
+            Some(ALICE)
+        );
+        assert_eq!(crate::dot_str_to_address("0x0101"), None);
+    }
+
+    #[test]
+    fn test_dot_address_string() {
+        assert_eq!(crate::dot_address_string(&ALICE), ALICE_SS58_POLKADOT);
+    }
+}
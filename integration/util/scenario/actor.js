@@ -64,18 +64,24 @@ class Actor {
     return await this.chain().sign(data, this);
   }
 
-  async signWithNonce(data) {
+  trxRequestDomain(expiry = null) {
+    return { genesisHash: this.ctx.getApi().genesisHash.toHex(), expiry };
+  }
+
+  async signWithNonce(data, domain) {
     let currentNonce = await this.nonce();
-    let signature = await this.sign(`${currentNonce}:${data}`);
+    let expiry = domain.expiry === null ? '' : domain.expiry;
+    let signature = await this.sign(`${domain.genesisHash}:${expiry}:${currentNonce}:${data}`);
     const signatureData = {};
     signatureData[this.chain().nameAsPascalCase()] = [this.ethAddress(), signature];
 
     return [ signatureData, currentNonce ];
   }
 
-  async runTrxRequest(trxReq) {
-    let [sig, currentNonce] = await this.signWithNonce(trxReq);
-    let call = this.ctx.getApi().tx.cash.execTrxRequest(trxReq, sig, currentNonce);
+  async runTrxRequest(trxReq, expiry = null) {
+    let domain = this.trxRequestDomain(expiry);
+    let [sig, currentNonce] = await this.signWithNonce(trxReq, domain);
+    let call = this.ctx.getApi().tx.cash.execTrxRequest(trxReq, sig, currentNonce, domain);
 
     return await this.ctx.eventTracker.sendAndWaitForEvents(call, { onFinalize: false });
   }
@@ -10,12 +10,15 @@ use sp_blockchain::HeaderBackend;
 use sp_runtime::{generic::BlockId, traits::Block as BlockT};
 
 use pallet_cash::{
-    chains::{ChainAccount, ChainAsset},
+    chains::{ChainAccount, ChainAsset, ChainBlockEvent, ChainId, UNKNOWN_TRANSACTION_HASH},
     core::BTreeMap,
     portfolio::Portfolio,
     rates::APR,
     reason::Reason,
-    types::{AssetAmount, AssetBalance, AssetInfo, InterestRateModel, Symbol, ValidatorKeys},
+    types::{
+        AssetAmount, AssetBalance, AssetInfo, EventKind, InterestRateModel, RiskModel, Symbol,
+        ValidatorKeys,
+    },
 };
 
 use pallet_cash_runtime_api::CashApi as CashRuntimeApi;
@@ -25,6 +28,7 @@ use types_derive::{type_alias, Types};
 
 const RUNTIME_ERROR: i64 = 1;
 const CHAIN_ERROR: i64 = 2;
+const PARSE_ERROR: i64 = 3;
 
 // Note: no 128 bit integers for the moment
 //  due to issues with serde/serde_json
@@ -102,6 +106,128 @@ pub struct ApiValidators {
     miner_payouts: Vec<(ChainAccount, String)>,
 }
 
+#[derive(Deserialize, Serialize, Types)]
+pub struct ApiRiskModel {
+    kind: EventKind,
+    factor: String,
+    half_life: u64,
+}
+
+#[derive(Deserialize, Serialize, Types)]
+pub struct ApiDeadLetterEvent {
+    block_number: u64,
+    transaction_hash: Option<String>,
+    log_index: Option<u64>,
+    event: String,
+    reason: String,
+}
+
+#[derive(Deserialize, Serialize, Types)]
+pub enum ApiTrxRequest {
+    Extract {
+        amount: String,
+        asset: String,
+        account: String,
+    },
+    Transfer {
+        amount: String,
+        asset: String,
+        account: String,
+    },
+    Liquidate {
+        amount: String,
+        borrowed_asset: String,
+        collateral_asset: String,
+        borrower: String,
+    },
+    Batch(Vec<ApiTrxRequest>),
+}
+
+#[derive(Deserialize, Serialize, Types)]
+pub struct ApiTrxRequestError {
+    error: String,
+    start: u32,
+    end: u32,
+    expected: String,
+}
+
+/// Converts a trx request parse failure into an RPC error.
+fn parse_err(err: trx_request::SpannedParseError) -> RpcError {
+    let (start, end) = err.span;
+    let data = ApiTrxRequestError {
+        error: format!("{:?}", err.error),
+        start: start as u32,
+        end: end as u32,
+        expected: format!("{:?}", err.expected()),
+    };
+    RpcError {
+        code: RpcErrorCode::ServerError(PARSE_ERROR),
+        message: "Invalid trx request".into(),
+        data: jsonrpc_core::to_value(data).ok(),
+    }
+}
+
+fn api_asset(asset: trx_request::Asset) -> String {
+    match asset {
+        trx_request::Asset::Cash => String::from("CASH"),
+        trx_request::Asset::Eth(address) => ChainAsset::Eth(address).into(),
+        trx_request::Asset::Matic(address) => ChainAsset::Matic(address).into(),
+        trx_request::Asset::Symbol(trx_request::Chain::Gate, symbol) => format!("GATE:{}", symbol),
+        trx_request::Asset::Symbol(trx_request::Chain::Eth, symbol) => format!("ETH:{}", symbol),
+        trx_request::Asset::Symbol(trx_request::Chain::Matic, symbol) => {
+            format!("MATIC:{}", symbol)
+        }
+    }
+}
+
+fn api_trx_request(request: trx_request::TrxRequest) -> ApiTrxRequest {
+    match request {
+        trx_request::TrxRequest::Extract(amount, asset, account) => ApiTrxRequest::Extract {
+            amount: amount.to_string(),
+            asset: api_asset(asset),
+            account: ChainAccount::from(account).into(),
+        },
+        trx_request::TrxRequest::Transfer(amount, asset, account) => ApiTrxRequest::Transfer {
+            amount: amount.to_string(),
+            asset: api_asset(asset),
+            account: ChainAccount::from(account).into(),
+        },
+        trx_request::TrxRequest::Liquidate(amount, borrowed_asset, collateral_asset, borrower) => {
+            ApiTrxRequest::Liquidate {
+                amount: amount.to_string(),
+                borrowed_asset: api_asset(borrowed_asset),
+                collateral_asset: api_asset(collateral_asset),
+                borrower: ChainAccount::from(borrower).into(),
+            }
+        }
+        trx_request::TrxRequest::Batch(requests) => {
+            ApiTrxRequest::Batch(requests.into_iter().map(api_trx_request).collect())
+        }
+    }
+}
+
+fn api_dead_letter_event((event, reason): (ChainBlockEvent, Reason)) -> ApiDeadLetterEvent {
+    let (block_number, log, event) = match event {
+        ChainBlockEvent::Eth(block_number, log)
+        | ChainBlockEvent::Matic(block_number, log)
+        | ChainBlockEvent::Evm(_, block_number, log) => {
+            let event = format!("{:?}", log.event);
+            (block_number, Some(log), event)
+        }
+        ChainBlockEvent::Dot(block_number, event) => (block_number, None, format!("{:?}", event)),
+        ChainBlockEvent::Reserved => (0, None, String::from("Reserved")),
+    };
+    // events stored before their log was recorded are not traceable to a transaction
+    let log = log.filter(|log| log.transaction_hash != UNKNOWN_TRANSACTION_HASH);
+    ApiDeadLetterEvent {
+        block_number,
+        transaction_hash: log.as_ref().map(|log| hex::encode(log.transaction_hash)),
+        log_index: log.map(|log| log.log_index),
+        event,
+        reason: format!("{:?}", reason),
+    }
+}
+
 /// Converts a runtime trap into an RPC error.
 fn runtime_err(err: impl std::fmt::Debug) -> RpcError {
     RpcError {
@@ -170,6 +296,19 @@ pub trait GatewayRpcApi<BlockHash> {
 
     #[rpc(name = "gateway_validators")]
     fn validators(&self, at: Option<BlockHash>) -> RpcResult<ApiValidators>;
+
+    #[rpc(name = "gateway_riskModels")]
+    fn risk_models(&self, at: Option<BlockHash>) -> RpcResult<Vec<ApiRiskModel>>;
+
+    #[rpc(name = "gateway_deadLetterEvents")]
+    fn dead_letter_events(
+        &self,
+        chain_id: ChainId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ApiDeadLetterEvent>>;
+
+    #[rpc(name = "gateway_parseTrxRequest")]
+    fn parse_trx_request(&self, request: String) -> RpcResult<ApiTrxRequest>;
 }
 
 pub struct GatewayRpcHandler<C, B> {
@@ -445,4 +584,65 @@ where
             miner_payouts: miner_payouts,
         })
     }
+
+    fn risk_models(&self, at: Option<<B as BlockT>::Hash>) -> RpcResult<Vec<ApiRiskModel>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let risk_models: Vec<(EventKind, RiskModel)> = api
+            .get_risk_models(&at)
+            .map_err(runtime_err)?
+            .map_err(chain_err)?;
+
+        Ok(risk_models
+            .into_iter()
+            .map(|(kind, model)| ApiRiskModel {
+                kind,
+                factor: format!("{}", model.factor.0),
+                half_life: model.half_life,
+            })
+            .collect())
+    }
+
+    fn dead_letter_events(
+        &self,
+        chain_id: ChainId,
+        at: Option<<B as BlockT>::Hash>,
+    ) -> RpcResult<Vec<ApiDeadLetterEvent>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let dead_letters = api
+            .get_dead_letter_events(&at, chain_id)
+            .map_err(runtime_err)?
+            .map_err(chain_err)?;
+
+        Ok(dead_letters
+            .into_iter()
+            .map(api_dead_letter_event)
+            .collect())
+    }
+
+    fn parse_trx_request(&self, request: String) -> RpcResult<ApiTrxRequest> {
+        let trx_request = trx_request::parse_request(&request).map_err(parse_err)?;
+        Ok(api_trx_request(trx_request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_trx_request_whole_decimal() {
+        let request = trx_request::parse_request(
+            "(Extract 7.0 Cash Eth:0x0101010101010101010101010101010101010101)",
+        )
+        .unwrap();
+        match api_trx_request(request) {
+            ApiTrxRequest::Extract { amount, asset, .. } => {
+                assert_eq!(amount, "7.0");
+                assert_eq!(asset, "CASH");
+            }
+            _ => panic!("expected an extract"),
+        }
+    }
 }
@@ -210,6 +210,7 @@ fn testnet_genesis(
                 .collect::<Vec<_>>(),
             starports: starports,
             genesis_blocks: genesis_blocks,
+            ingress_configs: vec![],
         },
 
         pallet_oracle: OracleConfig {
@@ -9,9 +9,24 @@ pub struct GatewayCmd {
     ///
     /// ETH_KEY_ID
     /// ETH_RPC_URL
+    /// ETH_RPC_QUORUM
+    /// ETH_WS_URL
+    /// MATIC_RPC_URL
+    /// MATIC_RPC_QUORUM
+    /// DOT_RPC_URL
     /// MINER
     /// OPF_URL
     ///
+    /// as well as the RPC URL key governance registers for each EVM chain, e.g. ARB_RPC_URL,
+    /// and its quorum, under the same key with the _URL suffix replaced by _QUORUM, e.g. ARB_RPC_QUORUM
+    ///
+    /// The Ethereum-like RPC URLs accept a comma-separated list, which is failed over in order
+    /// unless a quorum greater than one is set, in which case that many must agree on each block
+    ///
+    /// If ETH_WS_URL is set, the node subscribes to new Ethereum heads over websocket and caches
+    /// them for the offchain worker, which then only polls ETH_RPC_URL for blocks not yet cached,
+    /// unless ETH_RPC_QUORUM is greater than one, in which case the cache is not used
+    ///
     /// example ./gateway .... --env ETH_RPC_URL=http://... ETH_KEY_ID=.. MINER=Eth:0x01234567890123456789 OPF_URL=http://....
     pub env: Vec<String>,
 }
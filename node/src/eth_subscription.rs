@@ -0,0 +1,315 @@
+//! Node-side ingestion of Ethereum blocks over a websocket subscription.
+//!
+//! When a validator sets ETH_WS_URL, the node subscribes to `newHeads`, fetches the Starport logs
+//! for each new head over the same connection, and caches the resulting blocks in offchain
+//! storage. The offchain worker reads blocks from that cache before polling ETH_RPC_URL.
+
+use codec::Encode;
+use ethereum_client::{BlockObject, EthereumBlock, EthereumClientError, LogObject};
+use our_std::{debug, info, warn};
+use pallet_cash::events::eth_block_cache_key;
+use serde::Deserialize;
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
+use std::collections::VecDeque;
+use std::io::{Read, Write};
+use std::time::Duration;
+use tungstenite::{Message, WebSocket};
+
+/// The validator config key for the Ethereum node websocket URL.
+pub const ETH_WS_URL: &str = "ETH_WS_URL";
+
+/// How many blocks behind the latest head are kept in the cache.
+const ETH_BLOCK_CACHE_DEPTH: u64 = 256;
+
+/// How long to wait before reconnecting after the subscription drops.
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+#[derive(Debug)]
+pub enum SubscriptionError {
+    Websocket(tungstenite::Error),
+    Json(serde_json::Error),
+    Rpc(serde_json::Value),
+    Client(EthereumClientError),
+}
+
+impl From<tungstenite::Error> for SubscriptionError {
+    fn from(err: tungstenite::Error) -> Self {
+        SubscriptionError::Websocket(err)
+    }
+}
+
+impl From<serde_json::Error> for SubscriptionError {
+    fn from(err: serde_json::Error) -> Self {
+        SubscriptionError::Json(err)
+    }
+}
+
+/// Either a response to one of our calls, or a notification for our subscription.
+#[derive(Deserialize)]
+struct Incoming {
+    id: Option<u64>,
+    result: Option<serde_json::Value>,
+    error: Option<serde_json::Value>,
+    params: Option<Notification>,
+}
+
+#[derive(Deserialize)]
+struct Notification {
+    result: BlockObject,
+}
+
+/// The blocks cached in offchain storage, for the offchain worker to read.
+pub struct EthBlockCache<S> {
+    storage: S,
+    highest: Option<u64>,
+}
+
+impl<S: OffchainStorage> EthBlockCache<S> {
+    pub fn new(storage: S) -> Self {
+        EthBlockCache {
+            storage,
+            highest: None,
+        }
+    }
+
+    /// Cache a new head, forgetting any blocks above it, as those must have been reorged away.
+    pub fn insert(&mut self, starport: [u8; 20], block: EthereumBlock) {
+        let number = block.number;
+        if let Some(highest) = self.highest {
+            for stale in number + 1..=highest {
+                self.remove(stale);
+            }
+        }
+        if number >= ETH_BLOCK_CACHE_DEPTH {
+            self.remove(number - ETH_BLOCK_CACHE_DEPTH);
+        }
+        self.storage.set(
+            STORAGE_PREFIX,
+            eth_block_cache_key(number).as_bytes(),
+            &(starport, block).encode(),
+        );
+        self.highest = Some(number);
+    }
+
+    fn remove(&mut self, number: u64) {
+        self.storage
+            .remove(STORAGE_PREFIX, eth_block_cache_key(number).as_bytes());
+    }
+}
+
+/// Keep the subscription alive for as long as the node runs, reconnecting whenever it drops.
+pub fn run<S: OffchainStorage>(
+    ws_url: String,
+    starport: impl Fn() -> Option<[u8; 20]>,
+    storage: S,
+) {
+    let mut cache = EthBlockCache::new(storage);
+    loop {
+        if let Err(err) = subscribe(&ws_url, &starport, &mut cache) {
+            warn!("Eth subscription dropped: {:?}", err);
+        }
+        std::thread::sleep(RECONNECT_DELAY);
+    }
+}
+
+/// Subscribe to new heads at `ws_url`, caching each block until the connection drops.
+pub fn subscribe<S: OffchainStorage>(
+    ws_url: &str,
+    starport: impl Fn() -> Option<[u8; 20]>,
+    cache: &mut EthBlockCache<S>,
+) -> Result<(), SubscriptionError> {
+    let (mut socket, _) = tungstenite::connect(ws_url)?;
+    info!("Subscribing to Eth heads at {}", ws_url);
+    ingest(&mut socket, starport, cache)
+}
+
+fn ingest<T: Read + Write, S: OffchainStorage>(
+    socket: &mut WebSocket<T>,
+    starport: impl Fn() -> Option<[u8; 20]>,
+    cache: &mut EthBlockCache<S>,
+) -> Result<(), SubscriptionError> {
+    let mut heads = VecDeque::new();
+    send(socket, 1, "eth_subscribe", serde_json::json!(["newHeads"]))?;
+    await_response(socket, 1, &mut heads)?;
+
+    let mut next_id = 2;
+    loop {
+        let head = match heads.pop_front() {
+            Some(head) => head,
+            None => {
+                read(socket, &mut heads)?;
+                continue;
+            }
+        };
+        let starport_address = match starport() {
+            Some(starport_address) => starport_address,
+            None => continue,
+        };
+        let id = next_id;
+        next_id += 1;
+        let params = serde_json::json!([{
+            "address": format!("0x{}", hex::encode(&starport_address[..])),
+            "blockHash": &head.hash
+        }]);
+        send(socket, id, "eth_getLogs", params)?;
+        let log_objs: Vec<LogObject> =
+            serde_json::from_value(await_response(socket, id, &mut heads)?)?;
+        let block =
+            ethereum_client::parse_block(head, log_objs).map_err(SubscriptionError::Client)?;
+        debug!("Caching Eth block {}", block.number);
+        cache.insert(starport_address, block);
+    }
+}
+
+fn send<T: Read + Write>(
+    socket: &mut WebSocket<T>,
+    id: u64,
+    method: &str,
+    params: serde_json::Value,
+) -> Result<(), SubscriptionError> {
+    let data = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": method,
+        "params": params,
+        "id": id
+    });
+    socket.write_message(Message::Text(data.to_string()))?;
+    Ok(())
+}
+
+/// Read the next message, queueing it if it is a new head, or returning it if it is a response.
+fn read<T: Read + Write>(
+    socket: &mut WebSocket<T>,
+    heads: &mut VecDeque<BlockObject>,
+) -> Result<Option<Incoming>, SubscriptionError> {
+    match socket.read_message()? {
+        Message::Text(text) => {
+            let mut incoming: Incoming = serde_json::from_str(&text)?;
+            match incoming.params.take() {
+                Some(notification) => {
+                    heads.push_back(notification.result);
+                    Ok(None)
+                }
+                None => Ok(Some(incoming)),
+            }
+        }
+        _ => Ok(None),
+    }
+}
+
+fn await_response<T: Read + Write>(
+    socket: &mut WebSocket<T>,
+    id: u64,
+    heads: &mut VecDeque<BlockObject>,
+) -> Result<serde_json::Value, SubscriptionError> {
+    loop {
+        if let Some(incoming) = read(socket, heads)? {
+            if incoming.id == Some(id) {
+                return match (incoming.result, incoming.error) {
+                    (Some(result), None) => Ok(result),
+                    (_, error) => Err(SubscriptionError::Rpc(
+                        error.unwrap_or(serde_json::Value::Null),
+                    )),
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codec::Decode;
+    use sp_core::offchain::storage::InMemOffchainStorage;
+    use std::net::TcpListener;
+
+    const STARPORT: [u8; 20] = [0x77; 20];
+
+    fn cached(storage: &InMemOffchainStorage, number: u64) -> Option<([u8; 20], EthereumBlock)> {
+        storage
+            .get(STORAGE_PREFIX, eth_block_cache_key(number).as_bytes())
+            .map(|data| Decode::decode(&mut &data[..]).unwrap())
+    }
+
+    fn read_json<T: Read + Write>(socket: &mut WebSocket<T>) -> serde_json::Value {
+        let text = socket.read_message().unwrap().into_text().unwrap();
+        serde_json::from_str(&text).unwrap()
+    }
+
+    fn block(number: u64, fork: u8) -> EthereumBlock {
+        EthereumBlock {
+            hash: [number as u8 + fork; 32],
+            parent_hash: [number as u8 - 1 + fork; 32],
+            number,
+            events: vec![],
+        }
+    }
+
+    #[test]
+    fn test_cache_forgets_reorged_blocks() {
+        let mut cache = EthBlockCache::new(InMemOffchainStorage::default());
+        cache.insert(STARPORT, block(3, 0));
+        cache.insert(STARPORT, block(4, 0));
+        cache.insert(STARPORT, block(5, 0));
+        cache.insert(STARPORT, block(4, 100));
+
+        assert_eq!(cached(&cache.storage, 3), Some((STARPORT, block(3, 0))));
+        assert_eq!(cached(&cache.storage, 4), Some((STARPORT, block(4, 100))));
+        assert_eq!(cached(&cache.storage, 5), None);
+    }
+
+    #[test]
+    fn test_subscribe_caches_heads() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let ws_url = format!("ws://{}", listener.local_addr().unwrap());
+
+        // A mock node, which sends a single head before hanging up
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut socket = tungstenite::accept(stream).unwrap();
+            assert_eq!(
+                read_json(&mut socket),
+                serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "method": "eth_subscribe",
+                    "params": ["newHeads"],
+                    "id": 1
+                })
+            );
+            socket
+                .write_message(Message::Text(
+                    r#"{"jsonrpc":"2.0","id":1,"result":"0x9ce59a13059e417087c02d3236a0b1cc"}"#
+                        .into(),
+                ))
+                .unwrap();
+            socket
+                .write_message(Message::Text(r#"{"jsonrpc":"2.0","method":"eth_subscription","params":{"subscription":"0x9ce59a13059e417087c02d3236a0b1cc","result":{"hash":"0x0303030303030303030303030303030303030303030303030303030303030303","number":"0x3","parentHash":"0x0202020202020202020202020202020202020202020202020202020202020202"}}}"#.into()))
+                .unwrap();
+            assert_eq!(
+                read_json(&mut socket),
+                serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "method": "eth_getLogs",
+                    "params": [{
+                        "address": "0x7777777777777777777777777777777777777777",
+                        "blockHash": "0x0303030303030303030303030303030303030303030303030303030303030303"
+                    }],
+                    "id": 2
+                })
+            );
+            socket
+                .write_message(Message::Text(
+                    r#"{"jsonrpc":"2.0","id":2,"result":[]}"#.into(),
+                ))
+                .unwrap();
+            socket.close(None).unwrap();
+            while socket.read_message().is_ok() {}
+        });
+
+        let mut cache = EthBlockCache::new(InMemOffchainStorage::default());
+        assert!(subscribe(&ws_url, || Some(STARPORT), &mut cache).is_err());
+        server.join().unwrap();
+
+        assert_eq!(cached(&cache.storage, 3), Some((STARPORT, block(3, 0))));
+    }
+}
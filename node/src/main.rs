@@ -7,6 +7,7 @@ mod service;
 mod api;
 mod cli;
 mod command;
+mod eth_subscription;
 mod rpc;
 
 fn main() -> sc_cli::Result<()> {
@@ -2,13 +2,18 @@
 
 use gateway_runtime::{self as node_runtime, opaque::Block, RuntimeApi};
 use pallet_cash;
+use pallet_cash::chains::{ChainId, ChainStarport};
+use pallet_cash_runtime_api::CashApi;
 use pallet_oracle;
-use sc_client_api::{ExecutorProvider, RemoteBackend};
+use sc_client_api::{Backend, ExecutorProvider, RemoteBackend};
 use sc_executor::native_executor_instance;
 use sc_service::{config::Configuration, error::Error as ServiceError, TaskManager};
 use sc_telemetry::{Telemetry, TelemetryWorker};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
 use sp_consensus::SlotData;
 use sp_consensus_aura::sr25519::AuthorityPair as AuraPair;
+use sp_runtime::generic::BlockId;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -132,6 +137,20 @@ pub fn new_partial(
     })
 }
 
+/// Read the Ethereum Starport address from the latest state, to filter its logs by.
+fn get_eth_starport(client: &FullClient) -> Option<[u8; 20]> {
+    let best = BlockId::Hash(client.info().best_hash);
+    match client
+        .runtime_api()
+        .get_starport(&best, ChainId::Eth)
+        .ok()?
+        .ok()?
+    {
+        ChainStarport::Eth(starport_address) => Some(starport_address),
+        _ => None,
+    }
+}
+
 pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError> {
     let sc_service::PartialComponents {
         client,
@@ -167,6 +186,18 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
             client.clone(),
             network.clone(),
         );
+
+        let eth_ws_url = runtime_interfaces::validator_config_interface::get(
+            crate::eth_subscription::ETH_WS_URL,
+        );
+        if let (Some(ws_url), Some(storage)) = (eth_ws_url, backend.offchain_storage()) {
+            let client = client.clone();
+            task_manager
+                .spawn_handle()
+                .spawn_blocking("eth-subscription", async move {
+                    crate::eth_subscription::run(ws_url, move || get_eth_starport(&client), storage)
+                });
+        }
     }
 
     let role = config.role.clone();
@@ -1,11 +1,12 @@
 use pallet_cash::{
-    chains::{ChainAccount, ChainAsset},
+    chains::{ChainAccount, ChainAsset, ChainBlockEvent, ChainId, ChainStarport},
     core::BTreeMap,
     portfolio::Portfolio,
     rates::APR,
     reason::Reason,
     types::{
-        AssetAmount, AssetBalance, AssetInfo, Balance, CashIndex, CashPrincipal, ValidatorKeys,
+        AssetAmount, AssetBalance, AssetInfo, Balance, CashIndex, CashPrincipal, EventKind,
+        RiskModel, ValidatorKeys,
     },
 };
 use pallet_oracle::{ticker::Ticker, types::AssetPrice};
@@ -36,5 +37,8 @@ sp_api::decl_runtime_apis! {
         fn get_accounts_liquidity() -> Result<Vec<(ChainAccount, String)>, Reason>;
         fn get_portfolio(account: ChainAccount) -> Result<Portfolio, Reason>;
         fn get_validator_info() -> Result<(Vec<ValidatorKeys>, Vec<(ChainAccount, String)>), Reason>;
+        fn get_risk_models() -> Result<Vec<(EventKind, RiskModel)>, Reason>;
+        fn get_starport(chain_id: ChainId) -> Result<ChainStarport, Reason>;
+        fn get_dead_letter_events(chain_id: ChainId) -> Result<Vec<(ChainBlockEvent, Reason)>, Reason>;
     }
 }
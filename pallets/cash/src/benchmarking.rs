@@ -76,7 +76,7 @@ fn init_asset_balance(asset: ChainAsset, account: ChainAccount, balance: AssetBa
     AssetsWithNonZeroBalance::insert(account, asset, ());
 }
 
-fn construct_reorg(num_events: u32) -> (ChainReorg, ethereum_client::EthereumBlock) {
+fn lock_events(num_events: u32) -> Vec<ethereum_client::EthereumLogEvent> {
     let mut events = vec![];
 
     let event = ethereum_client::EthereumEvent::Lock {
@@ -87,10 +87,20 @@ fn construct_reorg(num_events: u32) -> (ChainReorg, ethereum_client::EthereumBlo
         amount: Quantity::from_nominal("10", ETH_UNIT).value,
     };
 
-    for _i in 0..num_events {
-        events.push(event.clone());
+    for i in 0..num_events {
+        events.push(ethereum_client::EthereumLogEvent {
+            transaction_hash: [5; 32],
+            log_index: i.into(),
+            event: event.clone(),
+        });
     }
 
+    events
+}
+
+fn construct_reorg(num_events: u32) -> (ChainReorg, ethereum_client::EthereumBlock) {
+    let events = lock_events(num_events);
+
     let last_hash = [4; 32];
     let chain_id = chains::ChainId::Eth;
     let last_block = ethereum_client::EthereumBlock {
@@ -208,7 +218,10 @@ benchmarks! {
         assert_eq!(Cash::<T>::set_yield_next(RawOrigin::Root.into(), APR(100).into(), 86400500), Ok(()));
     }
 
+    // worst case: a sole validator, so the blocks are accepted and every event is applied
     receive_chain_blocks {
+        let b in 1 .. params::MAX_CHAIN_BLOCKS;
+        let e in 0 .. params::MAX_CHAIN_BLOCK_EVENTS;
         let substrate_id = AccountId32::new([12u8; 32]);
         let eth_address = <Ethereum as Chain>::signer_address().unwrap();
         LastProcessedBlock::insert(
@@ -222,6 +235,9 @@ benchmarks! {
                 }
             )
         );
+        for validator_id in Validators::iter_keys().collect::<Vec<_>>() {
+            Validators::remove(validator_id);
+        }
         Validators::insert(
             substrate_id.clone(),
             ValidatorKeys {
@@ -229,10 +245,39 @@ benchmarks! {
                 eth_address,
             },
         );
-        let blocks = ChainBlocks::Eth(vec![]);
+        let eth_asset = ChainAsset::Eth(ETH_BYTES);
+        SupportedAssets::insert(&eth_asset, AssetInfo::minimal(eth_asset, ETH_UNIT));
+        Prices::insert(ETH_UNIT.ticker, 2_000_000_000); // $2000
+        IngressConfigs::insert(
+            ChainId::Eth,
+            IngressConfig {
+                quota: params::INGRESS_LARGE.value,
+                min_event_blocks: 0,
+                ..IngressConfig::default()
+            },
+        );
+
+        let mut eth_blocks = vec![];
+        let mut parent_hash = [22; 32];
+        for number in 1..=b.into() {
+            let mut hash = [0; 32];
+            hash[..8].copy_from_slice(&u64::to_be_bytes(number));
+            let events = if number == 1 { lock_events(e) } else { vec![] };
+            eth_blocks.push(ethereum_client::EthereumBlock {
+                hash,
+                parent_hash,
+                number,
+                events,
+            });
+            parent_hash = hash;
+        }
+        let blocks = ChainBlocks::Eth(eth_blocks);
         let signature = ChainSignature::Eth(<Ethereum as Chain>::sign_message(&blocks.encode()).unwrap());
     }: {
         assert_ok!(Cash::<T>::receive_chain_blocks(RawOrigin::None.into(), blocks, signature));
+    } verify {
+        assert_eq!(LastProcessedBlock::get(ChainId::Eth).map(|block| block.number()), Some(b.into()));
+        assert_eq!(IngressionQueue::get(ChainId::Eth).map(|queue| queue.len()), Some(0));
     }
 
     receive_chain_reorg_pending {
@@ -266,10 +311,91 @@ benchmarks! {
         assert_eq!(PendingChainReorgs::get(ChainId::Eth).len(), 1);
     }
 
-    // TODO
-    // receive_chain_reorg_applied {
-    // * sign chain reorg w all validators
-    // }
+    // worst case: a sole validator, so the reorg is applied, unapplying and reapplying every event
+    receive_chain_reorg {
+        let b in 1 .. params::MAX_CHAIN_BLOCKS;
+        let e in 0 .. params::MAX_CHAIN_BLOCK_EVENTS;
+        let substrate_id = AccountId32::new([12u8; 32]);
+        let eth_address = <Ethereum as Chain>::signer_address().unwrap();
+        LastProcessedBlock::insert(
+            ChainId::Eth,
+            ChainBlock::Eth(
+                ethereum_client::EthereumBlock {
+                    hash: [22; 32],
+                    parent_hash: [1; 32],
+                    number: 0,
+                    events: vec![],
+                }
+            )
+        );
+        for validator_id in Validators::iter_keys().collect::<Vec<_>>() {
+            Validators::remove(validator_id);
+        }
+        Validators::insert(
+            substrate_id.clone(),
+            ValidatorKeys {
+                substrate_id,
+                eth_address,
+            },
+        );
+        let eth_asset = ChainAsset::Eth(ETH_BYTES);
+        SupportedAssets::insert(&eth_asset, AssetInfo::minimal(eth_asset, ETH_UNIT));
+        Prices::insert(ETH_UNIT.ticker, 2_000_000_000); // $2000
+        IngressConfigs::insert(
+            ChainId::Eth,
+            IngressConfig {
+                quota: params::INGRESS_LARGE.value,
+                min_event_blocks: 0,
+                ..IngressConfig::default()
+            },
+        );
+
+        // the same events are emitted by other transactions on the new fork
+        let chain = |pad: u8, transaction_hash: [u8; 32]| {
+            let mut eth_blocks = vec![];
+            let mut parent_hash = [22; 32];
+            for number in 1..=b.into() {
+                let mut hash = [pad; 32];
+                hash[..8].copy_from_slice(&u64::to_be_bytes(number));
+                let events = if number == 1 {
+                    lock_events(e)
+                        .into_iter()
+                        .map(|event| ethereum_client::EthereumLogEvent { transaction_hash, ..event })
+                        .collect()
+                } else {
+                    vec![]
+                };
+                eth_blocks.push(ethereum_client::EthereumBlock {
+                    hash,
+                    parent_hash,
+                    number,
+                    events,
+                });
+                parent_hash = hash;
+            }
+            eth_blocks
+        };
+        let reverse_blocks = chain(0, [5; 32]);
+        let forward_blocks = chain(1, [6; 32]);
+
+        let blocks = ChainBlocks::Eth(reverse_blocks.clone());
+        let signature = ChainSignature::Eth(<Ethereum as Chain>::sign_message(&blocks.encode()).unwrap());
+        assert_ok!(Cash::<T>::receive_chain_blocks(RawOrigin::None.into(), blocks, signature));
+
+        let to_hash = forward_blocks.last().unwrap().hash;
+        let reorg = ChainReorg::Eth {
+            from_hash: reverse_blocks.last().unwrap().hash,
+            to_hash,
+            reverse_blocks: reverse_blocks.into_iter().rev().collect(),
+            forward_blocks,
+        };
+        let signature = ChainSignature::Eth(<Ethereum as Chain>::sign_message(&reorg.encode()).unwrap());
+    }: {
+        assert_ok!(Cash::<T>::receive_chain_reorg(RawOrigin::None.into(), reorg, signature));
+    } verify {
+        assert_eq!(LastProcessedBlock::get(ChainId::Eth).map(|block| block.hash()), Some(ChainHash::Eth(to_hash)));
+        assert_eq!(IngressionQueue::get(ChainId::Eth).map(|queue| queue.len()), Some(0));
+    }
 
     support_asset {
         let info = AssetInfo::minimal(
@@ -369,16 +495,17 @@ benchmarks! {
         // amount, asset, account
         let raw_req: String = format!("(Extract {} Eth:{} Eth:{})", extract_amt, TKN_ADDR, ALICE_ADDRESS);
         let request_vec: Vec<u8> = raw_req.as_bytes().into();
-        let prepended_request = format!("{}:{}", nonce, raw_req);
-
-        let full_request: Vec<u8> =  format!("\x19Ethereum Signed Message:\n{}{}", prepended_request.len(), prepended_request).as_bytes().into();
+        let domain = TrxRequestDomain { genesis_hash: internal::exec_trx_request::get_genesis_hash::<T>(), expiry: None };
+        let signed_payload = internal::exec_trx_request::get_signed_payload(&request_vec, nonce, domain);
+        let mut full_request: Vec<u8> = format!("\x19Ethereum Signed Message:\n{}", signed_payload.len()).as_bytes().into();
+        full_request.extend_from_slice(&signed_payload[..]);
 
         let eth_key_id = runtime_interfaces::validator_config_interface::get_eth_key_id().unwrap();
         let signature_raw = runtime_interfaces::keyring_interface::sign_one(full_request, eth_key_id).unwrap();
         let signature = ChainAccountSignature::Eth(signer_vec, signature_raw);
 
     }: {
-        assert_eq!(Cash::<T>::exec_trx_request(RawOrigin::None.into(), request_vec, signature, nonce), Ok(()));
+        assert_eq!(Cash::<T>::exec_trx_request(RawOrigin::None.into(), request_vec, signature, nonce, domain), Ok(()));
     }
 
     exec_trx_request_transfer {
@@ -391,16 +518,17 @@ benchmarks! {
         // max, asset, dest_acct
         let raw_req: String = format!("(Transfer {} Eth:{} Eth:{})", transfer_amt, TKN_ADDR, ALICE_ADDRESS);
         let request_vec: Vec<u8> = raw_req.as_bytes().into();
-        let prepended_request = format!("{}:{}", nonce, raw_req);
-
-        let full_request: Vec<u8> =  format!("\x19Ethereum Signed Message:\n{}{}", prepended_request.len(), prepended_request).as_bytes().into();
+        let domain = TrxRequestDomain { genesis_hash: internal::exec_trx_request::get_genesis_hash::<T>(), expiry: None };
+        let signed_payload = internal::exec_trx_request::get_signed_payload(&request_vec, nonce, domain);
+        let mut full_request: Vec<u8> = format!("\x19Ethereum Signed Message:\n{}", signed_payload.len()).as_bytes().into();
+        full_request.extend_from_slice(&signed_payload[..]);
 
         let eth_key_id = runtime_interfaces::validator_config_interface::get_eth_key_id().unwrap();
         let signature_raw = runtime_interfaces::keyring_interface::sign_one(full_request, eth_key_id).unwrap();
         let signature = ChainAccountSignature::Eth(signer_vec, signature_raw);
 
     }: {
-        assert_eq!(Cash::<T>::exec_trx_request(RawOrigin::None.into(), request_vec, signature, nonce), Ok(()));
+        assert_eq!(Cash::<T>::exec_trx_request(RawOrigin::None.into(), request_vec, signature, nonce, domain), Ok(()));
     }
 
     exec_trx_request_liquidate {
@@ -417,13 +545,15 @@ benchmarks! {
         endow_tkn::<T>(signer_vec, transfer_amt * 5, [2; 20]);
         let raw_req: String = format!("(Liquidate {} Eth:{} Eth:{} Eth:{})", MIN_TX_VALUE, ETH_ADDR, TKN_ADDR, BOB_ADDRESS);
         let request_vec: Vec<u8> = raw_req.as_bytes().into();
-        let prepended_request = format!("{}:{}", nonce, raw_req);
-        let full_request: Vec<u8> = format!("\x19Ethereum Signed Message:\n{}{}", prepended_request.len(), prepended_request).as_bytes().into();
+        let domain = TrxRequestDomain { genesis_hash: internal::exec_trx_request::get_genesis_hash::<T>(), expiry: None };
+        let signed_payload = internal::exec_trx_request::get_signed_payload(&request_vec, nonce, domain);
+        let mut full_request: Vec<u8> = format!("\x19Ethereum Signed Message:\n{}", signed_payload.len()).as_bytes().into();
+        full_request.extend_from_slice(&signed_payload[..]);
         let eth_key_id = runtime_interfaces::validator_config_interface::get_eth_key_id().unwrap();
         let signature_raw = runtime_interfaces::keyring_interface::sign_one(full_request, eth_key_id).unwrap();
         let signature = ChainAccountSignature::Eth(signer_vec, signature_raw);
     }: {
-        assert_eq!(Cash::<T>::exec_trx_request(RawOrigin::None.into(), request_vec, signature, nonce), Ok(()));
+        assert_eq!(Cash::<T>::exec_trx_request(RawOrigin::None.into(), request_vec, signature, nonce, domain), Ok(()));
     }
 }
 
@@ -444,6 +574,7 @@ mod tests {
             assert_ok!(test_benchmark_on_initialize::<Test>());
             assert_ok!(test_benchmark_receive_chain_blocks::<Test>());
             assert_ok!(test_benchmark_receive_chain_reorg_pending::<Test>());
+            assert_ok!(test_benchmark_receive_chain_reorg::<Test>());
             assert_ok!(test_benchmark_publish_signature::<Test>());
             assert_ok!(test_benchmark_set_yield_next::<Test>());
             assert_ok!(test_benchmark_support_asset::<Test>());
@@ -1,11 +1,12 @@
 use codec::{Decode, Encode};
-use ethereum_client::{EthereumBlock, EthereumEvent, EthereumHash};
+use ethereum_client::{EthereumBlock, EthereumHash, EthereumLogEvent};
 use gateway_crypto::public_key_bytes_to_eth_address;
 use our_std::vec::Vec;
 use our_std::{
     collections::btree_set::BTreeSet, str::FromStr, vec, Debuggable, Deserialize, RuntimeDebug,
     Serialize,
 };
+use polkadot_client::{PolkadotAssetId, PolkadotBlock, PolkadotEvent};
 use types_derive::{type_alias, Types};
 
 use crate::{
@@ -18,6 +19,22 @@ use crate::{
 #[type_alias]
 type Reserved = ();
 
+/// Type for the chain id of an EVM chain registered by governance, as in EIP-155.
+#[type_alias]
+pub type EvmChainId = u32;
+
+/// Type for the configuration of an EVM chain registered by governance.
+/// EVM chains share Ethereum's addresses, hashes, events and starport interface.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
+pub struct EvmChainConfig {
+    /// The address of the starport deployed on the chain.
+    pub starport: <Ethereum as Chain>::Address,
+    /// The validator config key under which each validator sets its node RPC URL for the chain.
+    pub rpc_url_key: String,
+    /// The number of blocks an event must be buried under before it is ingressed.
+    pub confirmation_depth: ChainBlockNumber,
+}
+
 /// Type for representing the selection of an underlying chain.
 #[derive(Serialize, Deserialize)] // used in config
 #[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Encode, Decode, RuntimeDebug, Types)]
@@ -26,6 +43,7 @@ pub enum ChainId {
     Eth,
     Dot,
     Matic,
+    Evm(EvmChainId),
 }
 
 impl ChainId {
@@ -35,6 +53,10 @@ impl ChainId {
             ChainId::Eth => Ok(ChainAccount::Eth(Ethereum::str_to_address(addr)?)),
             ChainId::Matic => Ok(ChainAccount::Matic(Polygon::str_to_address(addr)?)),
             ChainId::Dot => Ok(ChainAccount::Dot(Polkadot::str_to_address(addr)?)),
+            ChainId::Evm(evm_chain_id) => Ok(ChainAccount::Evm(
+                evm_chain_id,
+                Ethereum::str_to_address(addr)?,
+            )),
         }
     }
 
@@ -43,7 +65,13 @@ impl ChainId {
             ChainId::Gate => Err(Reason::Unreachable),
             ChainId::Eth => Ok(ChainAsset::Eth(Ethereum::str_to_address(addr)?)),
             ChainId::Matic => Ok(ChainAsset::Matic(Polygon::str_to_address(addr)?)),
-            ChainId::Dot => Err(Reason::NotImplemented),
+            ChainId::Dot => Ok(ChainAsset::Dot(
+                PolkadotAssetId::from_str(addr).map_err(|_| Reason::BadAsset)?,
+            )),
+            ChainId::Evm(evm_chain_id) => Ok(ChainAsset::Evm(
+                evm_chain_id,
+                Ethereum::str_to_address(addr)?,
+            )),
         }
     }
 
@@ -53,6 +81,9 @@ impl ChainId {
             ChainId::Eth => Ok(ChainHash::Eth(Ethereum::str_to_hash(hash)?)),
             ChainId::Matic => Ok(ChainHash::Matic(Polygon::str_to_hash(hash)?)),
             ChainId::Dot => Ok(ChainHash::Dot(Polkadot::str_to_hash(hash)?)),
+            ChainId::Evm(evm_chain_id) => {
+                Ok(ChainHash::Evm(evm_chain_id, Ethereum::str_to_hash(hash)?))
+            }
         }
     }
 
@@ -61,7 +92,12 @@ impl ChainId {
             ChainId::Gate => Ok(ChainAccount::Gate(<Gateway as Chain>::signer_address()?)),
             ChainId::Eth => Ok(ChainAccount::Eth(<Ethereum as Chain>::signer_address()?)),
             ChainId::Matic => Ok(ChainAccount::Matic(<Polygon as Chain>::signer_address()?)),
-            ChainId::Dot => Ok(ChainAccount::Dot(<Polkadot as Chain>::signer_address()?)),
+            // validators sign for the Dot starport with their Ethereum keys
+            ChainId::Dot => Ok(ChainAccount::Eth(<Ethereum as Chain>::signer_address()?)),
+            ChainId::Evm(evm_chain_id) => Ok(ChainAccount::Evm(
+                evm_chain_id,
+                <Ethereum as Chain>::signer_address()?,
+            )),
         }
     }
 
@@ -71,6 +107,9 @@ impl ChainId {
             ChainId::Eth => ChainHash::Eth(<Ethereum as Chain>::hash_bytes(data)),
             ChainId::Matic => ChainHash::Matic(<Polygon as Chain>::hash_bytes(data)),
             ChainId::Dot => ChainHash::Dot(<Polkadot as Chain>::hash_bytes(data)),
+            ChainId::Evm(evm_chain_id) => {
+                ChainHash::Evm(evm_chain_id, <Ethereum as Chain>::hash_bytes(data))
+            }
         }
     }
 
@@ -85,9 +124,14 @@ impl ChainId {
             ChainId::Matic => Ok(ChainSignature::Matic(<Polygon as Chain>::sign_message(
                 message,
             )?)),
-            ChainId::Dot => Ok(ChainSignature::Dot(<Polkadot as Chain>::sign_message(
+            // validators sign for the Dot starport with their Ethereum keys
+            ChainId::Dot => Ok(ChainSignature::Dot(<Ethereum as Chain>::sign_message(
                 message,
             )?)),
+            ChainId::Evm(evm_chain_id) => Ok(ChainSignature::Evm(
+                evm_chain_id,
+                <Ethereum as Chain>::sign_message(message)?,
+            )),
         }
     }
 
@@ -97,6 +141,39 @@ impl ChainId {
             ChainId::Eth => ChainHash::Eth(<Ethereum as Chain>::zero_hash()),
             ChainId::Matic => ChainHash::Matic(<Polygon as Chain>::zero_hash()),
             ChainId::Dot => ChainHash::Dot(<Polkadot as Chain>::zero_hash()),
+            ChainId::Evm(evm_chain_id) => {
+                ChainHash::Evm(evm_chain_id, <Ethereum as Chain>::zero_hash())
+            }
+        }
+    }
+
+    /// Wrap an address on a chain which shares Ethereum's address format.
+    pub fn eth_like_account(self, address: [u8; 20]) -> Result<ChainAccount, Reason> {
+        match self {
+            ChainId::Eth => Ok(ChainAccount::Eth(address)),
+            ChainId::Matic => Ok(ChainAccount::Matic(address)),
+            ChainId::Evm(evm_chain_id) => Ok(ChainAccount::Evm(evm_chain_id, address)),
+            _ => Err(Reason::Unreachable),
+        }
+    }
+
+    /// Wrap an asset on a chain which shares Ethereum's address format.
+    pub fn eth_like_asset(self, address: [u8; 20]) -> Result<ChainAsset, Reason> {
+        match self {
+            ChainId::Eth => Ok(ChainAsset::Eth(address)),
+            ChainId::Matic => Ok(ChainAsset::Matic(address)),
+            ChainId::Evm(evm_chain_id) => Ok(ChainAsset::Evm(evm_chain_id, address)),
+            _ => Err(Reason::Unreachable),
+        }
+    }
+
+    /// Wrap a hash on a chain which shares Ethereum's hash format.
+    pub fn eth_like_hash(self, hash: [u8; 32]) -> Result<ChainHash, Reason> {
+        match self {
+            ChainId::Eth => Ok(ChainHash::Eth(hash)),
+            ChainId::Matic => Ok(ChainHash::Matic(hash)),
+            ChainId::Evm(evm_chain_id) => Ok(ChainHash::Evm(evm_chain_id, hash)),
+            _ => Err(Reason::Unreachable),
         }
     }
 }
@@ -111,6 +188,7 @@ pub enum ChainAccount {
     Eth(<Ethereum as Chain>::Address),
     Dot(<Polkadot as Chain>::Address),
     Matic(<Polygon as Chain>::Address),
+    Evm(EvmChainId, <Ethereum as Chain>::Address),
 }
 
 impl ChainAccount {
@@ -120,6 +198,7 @@ impl ChainAccount {
             ChainAccount::Eth(_) => ChainId::Eth,
             ChainAccount::Matic(_) => ChainId::Matic,
             ChainAccount::Dot(_) => ChainId::Dot,
+            ChainAccount::Evm(evm_chain_id, _) => ChainId::Evm(*evm_chain_id),
         }
     }
 }
@@ -143,10 +222,13 @@ impl FromStr for ChainAccount {
 impl From<ChainAccount> for String {
     fn from(asset: ChainAccount) -> String {
         match asset {
-            ChainAccount::Gate(address) => format!("GATE:0x{}", hex::encode(address)),
+            ChainAccount::Gate(address) => format!("GATE:{}", Gateway::address_string(&address)),
             ChainAccount::Eth(address) => format!("ETH:0x{}", hex::encode(address)),
             ChainAccount::Matic(address) => format!("MATIC:0x{}", hex::encode(address)),
-            ChainAccount::Dot(_) => String::from("DOT"), // XXX
+            ChainAccount::Dot(address) => format!("DOT:{}", Polkadot::address_string(&address)),
+            ChainAccount::Evm(evm_chain_id, address) => {
+                format!("EVM{}:0x{}", evm_chain_id, hex::encode(address))
+            }
         }
     }
 }
@@ -156,8 +238,9 @@ impl From<ChainAccount> for String {
 pub enum ChainAsset {
     Gate(Reserved),
     Eth(<Ethereum as Chain>::Address),
-    Dot(Reserved),
+    Dot(PolkadotAssetId),
     Matic(<Polygon as Chain>::Address),
+    Evm(EvmChainId, <Ethereum as Chain>::Address),
 }
 
 // For serialize (which we don't really use, but are required to implement)
@@ -168,6 +251,7 @@ impl ChainAsset {
             ChainAsset::Eth(_) => ChainId::Eth,
             ChainAsset::Matic(_) => ChainId::Matic,
             ChainAsset::Dot(_) => ChainId::Dot,
+            ChainAsset::Evm(evm_chain_id, _) => ChainId::Evm(*evm_chain_id),
         }
     }
 }
@@ -193,7 +277,10 @@ impl From<ChainAsset> for String {
             ChainAsset::Gate(_) => String::from("GATE"), // XXX
             ChainAsset::Eth(address) => format!("ETH:0x{}", hex::encode(address)),
             ChainAsset::Matic(address) => format!("MATIC:0x{}", hex::encode(address)),
-            ChainAsset::Dot(_) => String::from("DOT"), // XXX
+            ChainAsset::Dot(asset_id) => format!("DOT:{}", asset_id),
+            ChainAsset::Evm(evm_chain_id, address) => {
+                format!("EVM{}:0x{}", evm_chain_id, hex::encode(address))
+            }
         }
     }
 }
@@ -205,26 +292,40 @@ pub enum ChainAccountSignature {
     Eth(<Ethereum as Chain>::Address, <Ethereum as Chain>::Signature),
     Dot(<Polkadot as Chain>::Address, <Polkadot as Chain>::Signature),
     Matic(<Polygon as Chain>::Address, <Polygon as Chain>::Signature),
+    EthTypedData(<Ethereum as Chain>::Address, <Ethereum as Chain>::Signature),
+    Evm(
+        EvmChainId,
+        <Ethereum as Chain>::Address,
+        <Ethereum as Chain>::Signature,
+    ),
 }
 
 impl ChainAccountSignature {
-    pub fn to_chain_signature(self) -> ChainSignature {
-        match self {
-            ChainAccountSignature::Gate(_, sig) => ChainSignature::Gate(sig),
-            ChainAccountSignature::Eth(_, sig) => ChainSignature::Eth(sig),
-            ChainAccountSignature::Matic(_, sig) => ChainSignature::Matic(sig),
-            ChainAccountSignature::Dot(_, sig) => ChainSignature::Dot(sig),
-        }
-    }
-
+    /// Recover the signer of the message, which must be the account claiming to have signed it.
+    /// User signatures are recovered over the raw message, typed data over its EIP-712 digest.
     fn check_chain_signature<T: Chain>(
+        recover: fn(&[u8], T::Signature) -> Result<T::Address, Reason>,
         message: &[u8],
         account: T::Address,
         signature: T::Signature,
-    ) -> Result<ChainAccount, Reason> {
-        let recovered = T::recover_user_address(message, signature)?;
+    ) -> Result<T::Address, Reason> {
+        let recovered = recover(message, signature)?;
         if account == recovered {
-            Ok(T::chain_account(recovered))
+            Ok(recovered)
+        } else {
+            Err(Reason::SignatureAccountMismatch)
+        }
+    }
+
+    /// Verify the message was signed by a Substrate style account, whose address is its public key.
+    /// Both Gateway and Polkadot accounts sign this way, so their signatures cannot be recovered.
+    fn check_substrate_signature(
+        message: &[u8],
+        account: [u8; 32],
+        signature: GatewaySignature,
+    ) -> Result<[u8; 32], Reason> {
+        if Gateway::verify_user_signature(message, &account, signature) {
+            Ok(account)
         } else {
             Err(Reason::SignatureAccountMismatch)
         }
@@ -232,14 +333,48 @@ impl ChainAccountSignature {
 
     pub fn recover_account(self, message: &[u8]) -> Result<ChainAccount, Reason> {
         match self {
-            ChainAccountSignature::Gate(_, _) => Err(Reason::Unreachable),
+            ChainAccountSignature::Gate(gate_account, gate_sig) => {
+                Self::check_substrate_signature(message, gate_account, gate_sig)
+                    .map(ChainAccount::Gate)
+            }
             ChainAccountSignature::Eth(eth_account, eth_sig) => {
-                Self::check_chain_signature::<Ethereum>(message, eth_account, eth_sig)
+                Self::check_chain_signature::<Ethereum>(
+                    Ethereum::recover_user_address,
+                    message,
+                    eth_account,
+                    eth_sig,
+                )
+                .map(ChainAccount::Eth)
             }
-            ChainAccountSignature::Matic(account, sig) => {
-                Self::check_chain_signature::<Polygon>(message, account, sig)
+            ChainAccountSignature::Matic(account, sig) => Self::check_chain_signature::<Polygon>(
+                Polygon::recover_user_address,
+                message,
+                account,
+                sig,
+            )
+            .map(ChainAccount::Matic),
+            ChainAccountSignature::Dot(dot_account, dot_sig) => {
+                Self::check_substrate_signature(message, dot_account, dot_sig)
+                    .map(ChainAccount::Dot)
+            }
+            ChainAccountSignature::EthTypedData(eth_account, eth_sig) => {
+                Self::check_chain_signature::<Ethereum>(
+                    Ethereum::recover_address,
+                    message,
+                    eth_account,
+                    eth_sig,
+                )
+                .map(ChainAccount::Eth)
+            }
+            ChainAccountSignature::Evm(evm_chain_id, account, sig) => {
+                Self::check_chain_signature::<Ethereum>(
+                    Ethereum::recover_user_address,
+                    message,
+                    account,
+                    sig,
+                )
+                .map(|account| ChainAccount::Evm(evm_chain_id, account))
             }
-            ChainAccountSignature::Dot(_, _) => Err(Reason::Unreachable),
         }
     }
 }
@@ -255,6 +390,7 @@ pub enum ChainHash {
     Eth(<Ethereum as Chain>::Hash),
     Dot(<Polkadot as Chain>::Hash),
     Matic(<Polygon as Chain>::Hash),
+    Evm(EvmChainId, <Ethereum as Chain>::Hash),
 }
 
 // Display so we can format local storage keys.
@@ -265,6 +401,7 @@ impl our_std::fmt::Display for ChainHash {
             ChainHash::Eth(eth_hash) => write!(f, "ETH#{:X?}", eth_hash),
             ChainHash::Matic(hash) => write!(f, "MATIC#{:X?}", hash),
             ChainHash::Dot(dot_hash) => write!(f, "DOT#{:X?}", dot_hash),
+            ChainHash::Evm(evm_chain_id, hash) => write!(f, "EVM{}#{:X?}", evm_chain_id, hash),
         }
     }
 }
@@ -290,7 +427,8 @@ impl From<ChainHash> for String {
             ChainHash::Gate(_) => format!("GATE"), // XXX
             ChainHash::Eth(eth_hash) => <Ethereum as Chain>::hash_string(&eth_hash),
             ChainHash::Matic(hash) => <Polygon as Chain>::hash_string(&hash),
-            ChainHash::Dot(_) => format!("DOT"), // XXX
+            ChainHash::Dot(hash) => <Polkadot as Chain>::hash_string(&hash),
+            ChainHash::Evm(_, hash) => <Ethereum as Chain>::hash_string(&hash),
         }
     }
 }
@@ -300,8 +438,9 @@ impl From<ChainHash> for String {
 pub enum ChainSignature {
     Gate(<Gateway as Chain>::Signature),
     Eth(<Ethereum as Chain>::Signature),
-    Dot(<Polkadot as Chain>::Signature),
+    Dot(<Ethereum as Chain>::Signature),
     Matic(<Polygon as Chain>::Signature),
+    Evm(EvmChainId, <Ethereum as Chain>::Signature),
 }
 
 impl ChainSignature {
@@ -311,6 +450,7 @@ impl ChainSignature {
             ChainSignature::Eth(_) => ChainId::Eth,
             ChainSignature::Matic(_) => ChainId::Matic,
             ChainSignature::Dot(_) => ChainId::Dot,
+            ChainSignature::Evm(evm_chain_id, _) => ChainId::Evm(*evm_chain_id),
         }
     }
 
@@ -323,18 +463,29 @@ impl ChainSignature {
             ChainSignature::Matic(sig) => Ok(ChainAccount::Matic(
                 <Polygon as Chain>::recover_address(message, *sig)?,
             )),
-            ChainSignature::Dot(_) => Err(Reason::Unreachable),
+            ChainSignature::Dot(sig) => Ok(ChainAccount::Eth(
+                <Ethereum as Chain>::recover_address(message, *sig)?,
+            )),
+            ChainSignature::Evm(evm_chain_id, sig) => Ok(ChainAccount::Evm(
+                *evm_chain_id,
+                <Ethereum as Chain>::recover_address(message, *sig)?,
+            )),
         }
     }
 }
 
 /// Type for a list of chain signatures.
+/// Note that validators sign for the Dot starport using their Ethereum keys.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
 pub enum ChainSignatureList {
     Gate(Reserved),
     Eth(Vec<(<Ethereum as Chain>::Address, <Ethereum as Chain>::Signature)>),
-    Dot(Vec<(<Polkadot as Chain>::Address, <Polkadot as Chain>::Signature)>),
+    Dot(Vec<(<Ethereum as Chain>::Address, <Ethereum as Chain>::Signature)>),
     Matic(Vec<(<Polygon as Chain>::Address, <Polygon as Chain>::Signature)>),
+    Evm(
+        EvmChainId,
+        Vec<(<Ethereum as Chain>::Address, <Ethereum as Chain>::Signature)>,
+    ),
 }
 
 impl ChainSignatureList {
@@ -346,6 +497,15 @@ impl ChainSignatureList {
             (ChainSignatureList::Matic(eth_signature_pairs), ChainAccount::Matic(eth_account)) => {
                 eth_signature_pairs.iter().any(|(s, _)| *s == eth_account)
             }
+            (ChainSignatureList::Dot(eth_signature_pairs), ChainAccount::Eth(eth_account)) => {
+                eth_signature_pairs.iter().any(|(s, _)| *s == eth_account)
+            }
+            (
+                ChainSignatureList::Evm(evm_chain_id, eth_signature_pairs),
+                ChainAccount::Evm(account_chain_id, eth_account),
+            ) if *evm_chain_id == account_chain_id => {
+                eth_signature_pairs.iter().any(|(s, _)| *s == eth_account)
+            }
             _ => false,
         }
     }
@@ -354,6 +514,10 @@ impl ChainSignatureList {
         match chain_id {
             ChainId::Eth => self.has_signer(ChainAccount::Eth(validator.eth_address)),
             ChainId::Matic => self.has_signer(ChainAccount::Matic(validator.eth_address)),
+            ChainId::Dot => self.has_signer(ChainAccount::Eth(validator.eth_address)),
+            ChainId::Evm(evm_chain_id) => {
+                self.has_signer(ChainAccount::Evm(evm_chain_id, validator.eth_address))
+            }
             _ => false,
         }
     }
@@ -370,6 +534,15 @@ impl ChainSignatureList {
             (ChainSignatureList::Matic(eth_sig_list), ChainSignature::Matic(eth_sig)) => {
                 Ok(eth_sig_list.push((validator.eth_address, eth_sig.clone())))
             }
+            (ChainSignatureList::Dot(eth_sig_list), ChainSignature::Dot(eth_sig)) => {
+                Ok(eth_sig_list.push((validator.eth_address, eth_sig.clone())))
+            }
+            (
+                ChainSignatureList::Evm(evm_chain_id, eth_sig_list),
+                ChainSignature::Evm(sig_chain_id, eth_sig),
+            ) if *evm_chain_id == *sig_chain_id => {
+                Ok(eth_sig_list.push((validator.eth_address, eth_sig.clone())))
+            }
             _ => Err(Reason::SignatureMismatch),
         }
     }
@@ -385,7 +558,11 @@ impl FromStr for ChainId {
             "DOT" => Ok(ChainId::Dot),
             "GATE" => Ok(ChainId::Gate),
             "MATIC" => Ok(ChainId::Matic),
-            _ => Err(Reason::BadChainId),
+            // i.e. "EVM10" <> Evm(10)
+            other => match other.strip_prefix("EVM").map(EvmChainId::from_str) {
+                Some(Ok(evm_chain_id)) => Ok(ChainId::Evm(evm_chain_id)),
+                _ => Err(Reason::BadChainId),
+            },
         }
     }
 }
@@ -396,6 +573,8 @@ impl FromStr for ChainId {
 pub enum ChainBlock {
     Eth(<Ethereum as Chain>::Block),
     Matic(<Polygon as Chain>::Block),
+    Dot(<Polkadot as Chain>::Block),
+    Evm(EvmChainId, <Ethereum as Chain>::Block),
 }
 
 impl ChainBlock {
@@ -403,6 +582,8 @@ impl ChainBlock {
         match self {
             ChainBlock::Eth(_) => ChainId::Eth,
             ChainBlock::Matic(_) => ChainId::Matic,
+            ChainBlock::Dot(_) => ChainId::Dot,
+            ChainBlock::Evm(evm_chain_id, _) => ChainId::Evm(*evm_chain_id),
         }
     }
 
@@ -410,6 +591,8 @@ impl ChainBlock {
         match self {
             ChainBlock::Eth(block) => ChainHash::Eth(block.hash),
             ChainBlock::Matic(block) => ChainHash::Matic(block.hash),
+            ChainBlock::Dot(block) => ChainHash::Dot(block.hash),
+            ChainBlock::Evm(evm_chain_id, block) => ChainHash::Evm(*evm_chain_id, block.hash),
         }
     }
 
@@ -417,6 +600,10 @@ impl ChainBlock {
         match self {
             ChainBlock::Eth(block) => ChainHash::Eth(block.parent_hash),
             ChainBlock::Matic(block) => ChainHash::Matic(block.parent_hash),
+            ChainBlock::Dot(block) => ChainHash::Dot(block.parent_hash),
+            ChainBlock::Evm(evm_chain_id, block) => {
+                ChainHash::Evm(*evm_chain_id, block.parent_hash)
+            }
         }
     }
 
@@ -424,6 +611,8 @@ impl ChainBlock {
         match self {
             ChainBlock::Eth(block) => block.number,
             ChainBlock::Matic(block) => block.number,
+            ChainBlock::Dot(block) => block.number,
+            ChainBlock::Evm(_, block) => block.number,
         }
     }
 
@@ -432,7 +621,7 @@ impl ChainBlock {
         mut chain_block_event_fn: F,
     ) -> Vec<ChainBlockEvent>
     where
-        F: FnMut(ChainBlockNumber, EthereumEvent) -> ChainBlockEvent + 'static,
+        F: FnMut(ChainBlockNumber, EthereumLogEvent) -> ChainBlockEvent + 'static,
     {
         block
             .events
@@ -445,6 +634,17 @@ impl ChainBlock {
         match self {
             ChainBlock::Eth(block) => Self::map_events_eth_like(block, ChainBlockEvent::Eth),
             ChainBlock::Matic(block) => Self::map_events_eth_like(block, ChainBlockEvent::Matic),
+            ChainBlock::Dot(block) => block
+                .events
+                .iter()
+                .map(|e| ChainBlockEvent::Dot(block.number, e.clone()))
+                .collect(),
+            ChainBlock::Evm(evm_chain_id, block) => {
+                let evm_chain_id = *evm_chain_id;
+                Self::map_events_eth_like(block, move |number, event| {
+                    ChainBlockEvent::Evm(evm_chain_id, number, event)
+                })
+            }
         }
     }
 
@@ -456,8 +656,18 @@ impl ChainBlock {
             (ChainBlock::Matic(block), ChainBlocks::Matic(blocks)) => {
                 Ok(ChainBlocks::Matic([vec![block], blocks].concat()))
             }
-            (ChainBlock::Matic(_), ChainBlocks::Eth(_)) => Err(Reason::InvalidChainBlock),
-            (ChainBlock::Eth(_), ChainBlocks::Matic(_)) => Err(Reason::InvalidChainBlock),
+            (ChainBlock::Dot(block), ChainBlocks::Dot(blocks)) => {
+                Ok(ChainBlocks::Dot([vec![block], blocks].concat()))
+            }
+            (ChainBlock::Evm(evm_chain_id, block), ChainBlocks::Evm(blocks_chain_id, blocks))
+                if evm_chain_id == blocks_chain_id =>
+            {
+                Ok(ChainBlocks::Evm(
+                    evm_chain_id,
+                    [vec![block], blocks].concat(),
+                ))
+            }
+            _ => Err(Reason::InvalidChainBlock),
         }
     }
 }
@@ -467,6 +677,8 @@ impl ChainBlock {
 pub enum ChainBlocks {
     Eth(Vec<<Ethereum as Chain>::Block>),
     Matic(Vec<<Polygon as Chain>::Block>),
+    Dot(Vec<<Polkadot as Chain>::Block>),
+    Evm(EvmChainId, Vec<<Ethereum as Chain>::Block>),
 }
 
 impl ChainBlocks {
@@ -474,6 +686,8 @@ impl ChainBlocks {
         match self {
             ChainBlocks::Eth(_) => ChainId::Eth,
             ChainBlocks::Matic(_) => ChainId::Matic,
+            ChainBlocks::Dot(_) => ChainId::Dot,
+            ChainBlocks::Evm(evm_chain_id, _) => ChainId::Evm(*evm_chain_id),
         }
     }
 
@@ -481,6 +695,17 @@ impl ChainBlocks {
         match self {
             ChainBlocks::Eth(blocks) => blocks.len(),
             ChainBlocks::Matic(blocks) => blocks.len(),
+            ChainBlocks::Dot(blocks) => blocks.len(),
+            ChainBlocks::Evm(_, blocks) => blocks.len(),
+        }
+    }
+
+    pub fn event_count(&self) -> usize {
+        match self {
+            ChainBlocks::Eth(blocks) => blocks.iter().map(|b| b.events.len()).sum(),
+            ChainBlocks::Matic(blocks) => blocks.iter().map(|b| b.events.len()).sum(),
+            ChainBlocks::Dot(blocks) => blocks.iter().map(|b| b.events.len()).sum(),
+            ChainBlocks::Evm(_, blocks) => blocks.iter().map(|b| b.events.len()).sum(),
         }
     }
 
@@ -498,6 +723,11 @@ impl ChainBlocks {
         match self {
             ChainBlocks::Eth(blocks) => Self::blocks_eth_like(blocks, ChainBlock::Eth),
             ChainBlocks::Matic(blocks) => Self::blocks_eth_like(blocks, ChainBlock::Matic),
+            ChainBlocks::Dot(blocks) => blocks.iter().map(|b| ChainBlock::Dot(b.clone())).collect(),
+            ChainBlocks::Evm(evm_chain_id, blocks) => {
+                let evm_chain_id = *evm_chain_id;
+                Self::blocks_eth_like(blocks, move |b| ChainBlock::Evm(evm_chain_id, b))
+            }
         }
     }
 
@@ -509,6 +739,8 @@ impl ChainBlocks {
         match self {
             ChainBlocks::Eth(blocks) => Self::blocks_numbers_eth_like(blocks),
             ChainBlocks::Matic(blocks) => Self::blocks_numbers_eth_like(blocks),
+            ChainBlocks::Dot(blocks) => blocks.iter().map(|b| b.number).collect(),
+            ChainBlocks::Evm(_, blocks) => Self::blocks_numbers_eth_like(blocks),
         }
     }
 
@@ -550,6 +782,22 @@ impl ChainBlocks {
                 blocks,
                 ChainHash::Matic,
             )),
+            ChainBlocks::Dot(blocks) => ChainBlocks::Dot(
+                blocks
+                    .into_iter()
+                    .filter(|block| {
+                        !pending_blocks.iter().any(|t| {
+                            t.block.hash() == ChainHash::Dot(block.hash) && t.has_supporter(signer)
+                        })
+                    })
+                    .collect(),
+            ),
+            ChainBlocks::Evm(evm_chain_id, blocks) => ChainBlocks::Evm(
+                evm_chain_id,
+                Self::filter_already_signed_eth_like(signer, pending_blocks, blocks, |hash| {
+                    ChainHash::Evm(evm_chain_id, hash)
+                }),
+            ),
         }
     }
 }
@@ -559,6 +807,8 @@ impl From<ChainBlock> for ChainBlocks {
         match block {
             ChainBlock::Eth(block) => ChainBlocks::Eth(vec![block]),
             ChainBlock::Matic(block) => ChainBlocks::Matic(vec![block]),
+            ChainBlock::Dot(block) => ChainBlocks::Dot(vec![block]),
+            ChainBlock::Evm(evm_chain_id, block) => ChainBlocks::Evm(evm_chain_id, vec![block]),
         }
     }
 }
@@ -578,6 +828,19 @@ pub enum ChainReorg {
         reverse_blocks: Vec<<Polygon as Chain>::Block>,
         forward_blocks: Vec<<Polygon as Chain>::Block>,
     },
+    Dot {
+        from_hash: <Polkadot as Chain>::Hash,
+        to_hash: <Polkadot as Chain>::Hash,
+        reverse_blocks: Vec<<Polkadot as Chain>::Block>,
+        forward_blocks: Vec<<Polkadot as Chain>::Block>,
+    },
+    Evm {
+        evm_chain_id: EvmChainId,
+        from_hash: <Ethereum as Chain>::Hash,
+        to_hash: <Ethereum as Chain>::Hash,
+        reverse_blocks: Vec<<Ethereum as Chain>::Block>,
+        forward_blocks: Vec<<Ethereum as Chain>::Block>,
+    },
 }
 
 impl ChainReorg {
@@ -585,6 +848,8 @@ impl ChainReorg {
         match self {
             ChainReorg::Eth { .. } => ChainId::Eth,
             ChainReorg::Matic { .. } => ChainId::Matic,
+            ChainReorg::Dot { .. } => ChainId::Dot,
+            ChainReorg::Evm { evm_chain_id, .. } => ChainId::Evm(*evm_chain_id),
         }
     }
 
@@ -592,6 +857,12 @@ impl ChainReorg {
         match self {
             ChainReorg::Eth { from_hash, .. } => ChainHash::Eth(*from_hash),
             ChainReorg::Matic { from_hash, .. } => ChainHash::Matic(*from_hash),
+            ChainReorg::Dot { from_hash, .. } => ChainHash::Dot(*from_hash),
+            ChainReorg::Evm {
+                evm_chain_id,
+                from_hash,
+                ..
+            } => ChainHash::Evm(*evm_chain_id, *from_hash),
         }
     }
 
@@ -599,6 +870,12 @@ impl ChainReorg {
         match self {
             ChainReorg::Eth { to_hash, .. } => ChainHash::Eth(*to_hash),
             ChainReorg::Matic { to_hash, .. } => ChainHash::Matic(*to_hash),
+            ChainReorg::Dot { to_hash, .. } => ChainHash::Dot(*to_hash),
+            ChainReorg::Evm {
+                evm_chain_id,
+                to_hash,
+                ..
+            } => ChainHash::Evm(*evm_chain_id, *to_hash),
         }
     }
 
@@ -606,6 +883,12 @@ impl ChainReorg {
         match self {
             ChainReorg::Eth { reverse_blocks, .. } => ChainBlocks::Eth(reverse_blocks.to_vec()),
             ChainReorg::Matic { reverse_blocks, .. } => ChainBlocks::Matic(reverse_blocks.to_vec()),
+            ChainReorg::Dot { reverse_blocks, .. } => ChainBlocks::Dot(reverse_blocks.to_vec()),
+            ChainReorg::Evm {
+                evm_chain_id,
+                reverse_blocks,
+                ..
+            } => ChainBlocks::Evm(*evm_chain_id, reverse_blocks.to_vec()),
         }
     }
 
@@ -613,6 +896,12 @@ impl ChainReorg {
         match self {
             ChainReorg::Eth { forward_blocks, .. } => ChainBlocks::Eth(forward_blocks.to_vec()),
             ChainReorg::Matic { forward_blocks, .. } => ChainBlocks::Matic(forward_blocks.to_vec()),
+            ChainReorg::Dot { forward_blocks, .. } => ChainBlocks::Dot(forward_blocks.to_vec()),
+            ChainReorg::Evm {
+                evm_chain_id,
+                forward_blocks,
+                ..
+            } => ChainBlocks::Evm(*evm_chain_id, forward_blocks.to_vec()),
         }
     }
 
@@ -687,7 +976,7 @@ pub struct ChainReorgTally {
 impl ChainReorgTally {
     pub fn new(chain_id: ChainId, reorg: ChainReorg, validator: &ValidatorKeys) -> ChainReorgTally {
         match chain_id {
-            ChainId::Eth | ChainId::Matic => ChainReorgTally {
+            ChainId::Eth | ChainId::Matic | ChainId::Dot | ChainId::Evm(_) => ChainReorgTally {
                 reorg,
                 support: [validator.substrate_id.clone()].iter().cloned().collect(),
             },
@@ -725,6 +1014,8 @@ pub enum ChainBlockEvent {
     Reserved,
     Eth(ChainBlockNumber, <Ethereum as Chain>::Event),
     Matic(ChainBlockNumber, <Polygon as Chain>::Event),
+    Dot(ChainBlockNumber, <Polkadot as Chain>::Event),
+    Evm(EvmChainId, ChainBlockNumber, <Ethereum as Chain>::Event),
 }
 
 impl ChainBlockEvent {
@@ -733,6 +1024,8 @@ impl ChainBlockEvent {
             ChainBlockEvent::Reserved => panic!("reserved"),
             ChainBlockEvent::Eth(..) => ChainId::Eth,
             ChainBlockEvent::Matic(..) => ChainId::Matic,
+            ChainBlockEvent::Dot(..) => ChainId::Dot,
+            ChainBlockEvent::Evm(evm_chain_id, ..) => ChainId::Evm(*evm_chain_id),
         }
     }
 
@@ -741,6 +1034,38 @@ impl ChainBlockEvent {
             ChainBlockEvent::Reserved => panic!("reserved"),
             ChainBlockEvent::Eth(block_num, _) => *block_num,
             ChainBlockEvent::Matic(block_num, _) => *block_num,
+            ChainBlockEvent::Dot(block_num, _) => *block_num,
+            ChainBlockEvent::Evm(_, block_num, _) => *block_num,
+        }
+    }
+
+    /// Whether both events were emitted by the same log, even if included by different blocks.
+    /// Only events from Ethereum-like chains identify the transaction which emitted them.
+    pub fn is_same_log(&self, other: &ChainBlockEvent) -> bool {
+        match (self, other) {
+            (ChainBlockEvent::Eth(_, a), ChainBlockEvent::Eth(_, b)) => is_same_eth_log(a, b),
+            (ChainBlockEvent::Matic(_, a), ChainBlockEvent::Matic(_, b)) => is_same_eth_log(a, b),
+            (ChainBlockEvent::Evm(a_chain_id, _, a), ChainBlockEvent::Evm(b_chain_id, _, b)) => {
+                a_chain_id == b_chain_id && is_same_eth_log(a, b)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether both events were emitted by the same log of the same block.
+    pub fn is_same_block_log(&self, other: &ChainBlockEvent) -> bool {
+        match (self, other) {
+            (ChainBlockEvent::Eth(a_num, a), ChainBlockEvent::Eth(b_num, b)) => {
+                a_num == b_num && is_same_eth_block_log(a, b)
+            }
+            (ChainBlockEvent::Matic(a_num, a), ChainBlockEvent::Matic(b_num, b)) => {
+                a_num == b_num && is_same_eth_block_log(a, b)
+            }
+            (
+                ChainBlockEvent::Evm(a_chain_id, a_num, a),
+                ChainBlockEvent::Evm(b_chain_id, b_num, b),
+            ) => a_chain_id == b_chain_id && a_num == b_num && is_same_eth_block_log(a, b),
+            _ => self == other,
         }
     }
 
@@ -749,12 +1074,39 @@ impl ChainBlockEvent {
     }
 }
 
+/// The transaction hash of events stored before Ethereum-like events carried their log.
+pub const UNKNOWN_TRANSACTION_HASH: EthereumHash = [0u8; 32];
+
+/// Whether both events were emitted by the same transaction, with the same payload.
+/// The index of a log within its block changes when the transaction is included by another block,
+///  so it does not identify the log across blocks.
+fn is_same_eth_log(a: &EthereumLogEvent, b: &EthereumLogEvent) -> bool {
+    a.transaction_hash == b.transaction_hash && a.event == b.event
+}
+
+/// Whether both events are the same log, given they come from the same block.
+/// Events whose transaction is unknown can only be matched by payload.
+fn is_same_eth_block_log(a: &EthereumLogEvent, b: &EthereumLogEvent) -> bool {
+    if a.transaction_hash == UNKNOWN_TRANSACTION_HASH
+        || b.transaction_hash == UNKNOWN_TRANSACTION_HASH
+    {
+        a.event == b.event
+    } else {
+        a == b
+    }
+}
+
 /// Type for describing a set of events coming from an underlying chain.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
 pub enum ChainBlockEvents {
     Reserved,
     Eth(Vec<(ChainBlockNumber, <Ethereum as Chain>::Event)>),
     Matic(Vec<(ChainBlockNumber, <Polygon as Chain>::Event)>),
+    Dot(Vec<(ChainBlockNumber, <Polkadot as Chain>::Event)>),
+    Evm(
+        EvmChainId,
+        Vec<(ChainBlockNumber, <Ethereum as Chain>::Event)>,
+    ),
 }
 
 impl ChainBlockEvents {
@@ -764,7 +1116,8 @@ impl ChainBlockEvents {
             ChainId::Gate => Err(Reason::Unreachable),
             ChainId::Eth => Ok(ChainBlockEvents::Eth(vec![])),
             ChainId::Matic => Ok(ChainBlockEvents::Matic(vec![])),
-            ChainId::Dot => Err(Reason::NotImplemented),
+            ChainId::Dot => Ok(ChainBlockEvents::Dot(vec![])),
+            ChainId::Evm(evm_chain_id) => Ok(ChainBlockEvents::Evm(evm_chain_id, vec![])),
         }
     }
 
@@ -774,11 +1127,13 @@ impl ChainBlockEvents {
             ChainBlockEvents::Reserved => panic!("reserved"),
             ChainBlockEvents::Eth(eth_block_events) => eth_block_events.len(),
             ChainBlockEvents::Matic(block_events) => block_events.len(),
+            ChainBlockEvents::Dot(block_events) => block_events.len(),
+            ChainBlockEvents::Evm(_, block_events) => block_events.len(),
         }
     }
 
     fn push_eth_events(
-        eth_block_events: &mut Vec<(ChainBlockNumber, EthereumEvent)>,
+        eth_block_events: &mut Vec<(ChainBlockNumber, EthereumLogEvent)>,
         eth_block: &EthereumBlock,
     ) -> () {
         for event in eth_block.events.iter() {
@@ -802,7 +1157,42 @@ impl ChainBlockEvents {
                 }
                 _ => panic!("block type mismatch"),
             },
+            ChainBlockEvents::Dot(block_events) => match block {
+                ChainBlock::Dot(dot_block) => {
+                    for event in dot_block.events.iter() {
+                        block_events.push((dot_block.number, event.clone()));
+                    }
+                }
+                _ => panic!("block type mismatch"),
+            },
+            ChainBlockEvents::Evm(evm_chain_id, block_events) => match block {
+                ChainBlock::Evm(block_chain_id, eth_block) if *block_chain_id == *evm_chain_id => {
+                    Self::push_eth_events(block_events, eth_block);
+                }
+                _ => panic!("block type mismatch"),
+            },
+        }
+    }
+
+    /// Push a single event onto this queue of events.
+    pub fn push_event(&mut self, event: ChainBlockEvent) -> Result<(), Reason> {
+        match (self, event) {
+            (ChainBlockEvents::Eth(block_events), ChainBlockEvent::Eth(block_num, event)) => {
+                block_events.push((block_num, event))
+            }
+            (ChainBlockEvents::Matic(block_events), ChainBlockEvent::Matic(block_num, event)) => {
+                block_events.push((block_num, event))
+            }
+            (ChainBlockEvents::Dot(block_events), ChainBlockEvent::Dot(block_num, event)) => {
+                block_events.push((block_num, event))
+            }
+            (
+                ChainBlockEvents::Evm(evm_chain_id, block_events),
+                ChainBlockEvent::Evm(event_chain_id, block_num, event),
+            ) if *evm_chain_id == event_chain_id => block_events.push((block_num, event)),
+            _ => return Err(Reason::ChainMismatch),
         }
+        Ok(())
     }
 
     /// Sift through these events, retaining only the ones which pass the given predicate.
@@ -810,16 +1200,25 @@ impl ChainBlockEvents {
     where
         F: FnMut(&ChainBlockEvent) -> bool,
     {
-        // let mut chain_block_event_fn;
+        // note early return below
         let chain_block_event_fn = match self {
             ChainBlockEvents::Reserved => panic!("reserved"),
             ChainBlockEvents::Eth(_) => ChainBlockEvent::Eth,
             ChainBlockEvents::Matic(_) => ChainBlockEvent::Matic,
+            ChainBlockEvents::Dot(dot_block_events) => {
+                return dot_block_events.retain(|(b, e)| f(&ChainBlockEvent::Dot(*b, e.clone())));
+            }
+            ChainBlockEvents::Evm(evm_chain_id, evm_block_events) => {
+                let evm_chain_id = *evm_chain_id;
+                return evm_block_events
+                    .retain(|(b, e)| f(&ChainBlockEvent::Evm(evm_chain_id, *b, e.clone())));
+            }
         };
         let eth_block_events = match self {
             ChainBlockEvents::Reserved => panic!("reserved"), // for exhaustive match - really just making the compiler happy here
             ChainBlockEvents::Eth(e) => e,
             ChainBlockEvents::Matic(e) => e,
+            ChainBlockEvents::Dot(_) | ChainBlockEvents::Evm(..) => panic!("unreachable"),
         };
 
         eth_block_events.retain(|(b, e)| f(&chain_block_event_fn(*b, e.clone())));
@@ -842,11 +1241,27 @@ impl ChainBlockEvents {
                 }
                 _ => panic!("unreachable"),
             },
+            ChainBlockEvents::Dot(dot_block_events) => match event {
+                ChainBlockEvent::Dot(block_num, dot_event) => {
+                    return dot_block_events
+                        .iter()
+                        .position(|(b, e)| *b == *block_num && *e == *dot_event);
+                }
+                _ => panic!("unreachable"),
+            },
+            ChainBlockEvents::Evm(evm_chain_id, eth_block_events) => match event {
+                ChainBlockEvent::Evm(event_chain_id, block_num, eth_block)
+                    if event_chain_id == evm_chain_id =>
+                {
+                    (eth_block_events, block_num, eth_block)
+                }
+                _ => panic!("unreachable"),
+            },
         };
 
         eth_block_events
             .iter()
-            .position(|(b, e)| *b == *block_num && *e == *eth_block)
+            .position(|(b, e)| *b == *block_num && is_same_eth_block_log(e, eth_block))
     }
 
     /// Remove the event at the given position.
@@ -855,6 +1270,11 @@ impl ChainBlockEvents {
             ChainBlockEvents::Reserved => panic!("reserved"),
             ChainBlockEvents::Eth(eth_block_events) => eth_block_events,
             ChainBlockEvents::Matic(eth_block_events) => eth_block_events,
+            ChainBlockEvents::Dot(dot_block_events) => {
+                dot_block_events.remove(pos);
+                return;
+            }
+            ChainBlockEvents::Evm(_, eth_block_events) => eth_block_events,
         };
 
         eth_block_events.remove(pos);
@@ -895,6 +1315,48 @@ pub trait Chain {
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
 pub struct Gateway {}
 
+/// Type for a signature by a Gateway account, under either of the schemes Substrate keys use.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug, Types)]
+pub enum GatewaySignature {
+    Sr25519([u8; 64]),
+    Ed25519([u8; 64]),
+}
+
+/// The wrapping which Substrate wallets apply to raw bytes before signing them.
+const GATEWAY_BYTES_PREFIX: &[u8] = b"<Bytes>";
+const GATEWAY_BYTES_SUFFIX: &[u8] = b"</Bytes>";
+
+impl Gateway {
+    fn verify_signature(data: &[u8], address: &[u8; 32], signature: GatewaySignature) -> bool {
+        match signature {
+            GatewaySignature::Sr25519(sig) => sp_io::crypto::sr25519_verify(
+                &sp_core::sr25519::Signature::from_raw(sig),
+                data,
+                &sp_core::sr25519::Public::from_raw(*address),
+            ),
+            GatewaySignature::Ed25519(sig) => sp_io::crypto::ed25519_verify(
+                &sp_core::ed25519::Signature::from_raw(sig),
+                data,
+                &sp_core::ed25519::Public::from_raw(*address),
+            ),
+        }
+    }
+
+    /// Verify a user signature for the Gateway account, whose address is its public key.
+    /// Since wallets sign raw bytes wrapped in `<Bytes>` tags, either form is accepted.
+    pub fn verify_user_signature(
+        data: &[u8],
+        address: &[u8; 32],
+        signature: GatewaySignature,
+    ) -> bool {
+        if Self::verify_signature(data, address, signature) {
+            return true;
+        }
+        let wrapped = [GATEWAY_BYTES_PREFIX, data, GATEWAY_BYTES_SUFFIX].concat();
+        Self::verify_signature(&wrapped[..], address, signature)
+    }
+}
+
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
 pub struct Ethereum {}
 
@@ -929,7 +1391,7 @@ impl Chain for Gateway {
     type PublicKey = [u8; 64];
 
     #[type_alias("Gateway__Chain__")]
-    type Signature = [u8; 65];
+    type Signature = GatewaySignature;
 
     #[type_alias("Gateway__Chain__")]
     type Event = ();
@@ -954,11 +1416,12 @@ impl Chain for Gateway {
         _data: &[u8],
         _signature: Self::Signature,
     ) -> Result<Self::Address, Reason> {
-        panic!("XXX not implemented");
+        // Keys cannot be recovered from sr25519 or ed25519 signatures, use `verify_user_signature`
+        Err(Reason::Unreachable)
     }
 
     fn recover_address(_data: &[u8], _signature: Self::Signature) -> Result<Self::Address, Reason> {
-        panic!("XXX not implemented");
+        Err(Reason::Unreachable)
     }
 
     fn sign_message(_message: &[u8]) -> Result<Self::Signature, Reason> {
@@ -969,15 +1432,15 @@ impl Chain for Gateway {
         panic!("XXX not implemented");
     }
 
-    fn str_to_address(_addr: &str) -> Result<Self::Address, Reason> {
-        match gateway_crypto::gateway_str_to_address(_addr) {
+    fn str_to_address(addr: &str) -> Result<Self::Address, Reason> {
+        match gateway_crypto::gateway_str_to_address(addr) {
             Some(s) => Ok(s),
             None => Err(Reason::BadAddress),
         }
     }
 
-    fn address_string(_address: &Self::Address) -> String {
-        panic!("XXX not implemented");
+    fn address_string(address: &Self::Address) -> String {
+        gateway_crypto::gateway_address_string(address)
     }
 
     fn str_to_hash(_hash: &str) -> Result<Self::Hash, Reason> {
@@ -1025,7 +1488,7 @@ impl Chain for Ethereum {
     type Signature = [u8; 65];
 
     #[type_alias("Ethereum__Chain__")]
-    type Event = EthereumEvent;
+    type Event = EthereumLogEvent;
 
     #[type_alias("Ethereum__Chain__")]
     type Block = EthereumBlock;
@@ -1137,7 +1600,7 @@ impl Chain for Polygon {
     type Signature = [u8; 65];
 
     #[type_alias("Polygon__Chain__")]
-    type Event = EthereumEvent;
+    type Event = EthereumLogEvent;
 
     #[type_alias("Polygon__Chain__")]
     type Block = EthereumBlock;
@@ -1216,86 +1679,101 @@ impl Chain for Polkadot {
     type Hash = [u8; 32];
 
     #[type_alias("Polkadot__Chain__")]
-    type PublicKey = [u8; 64];
+    type PublicKey = [u8; 32];
 
     #[type_alias("Polkadot__Chain__")]
-    type Signature = [u8; 65];
+    type Signature = GatewaySignature;
 
     #[type_alias("Polkadot__Chain__")]
-    type Event = ();
+    type Event = PolkadotEvent;
 
     #[type_alias("Polkadot__Chain__")]
-    type Block = ();
+    type Block = PolkadotBlock;
 
     fn zero_hash() -> Self::Hash {
         [0u8; 32]
     }
 
-    fn hash_bytes(_data: &[u8]) -> Self::Hash {
-        panic!("XXX not implemented");
+    fn hash_bytes(data: &[u8]) -> Self::Hash {
+        sp_io::hashing::blake2_256(data)
     }
 
     fn recover_user_address(
         _data: &[u8],
         _signature: Self::Signature,
     ) -> Result<Self::Address, Reason> {
-        panic!("XXX not implemented");
+        // sr25519 and ed25519 signatures cannot be recovered, use `Gateway::verify_user_signature`
+        Err(Reason::Unreachable)
     }
 
     fn recover_address(_data: &[u8], _signature: Self::Signature) -> Result<Self::Address, Reason> {
-        panic!("XXX not implemented");
+        // sr25519 and ed25519 signatures cannot be recovered, use `Gateway::verify_user_signature`
+        Err(Reason::Unreachable)
     }
 
     fn sign_message(_message: &[u8]) -> Result<Self::Signature, Reason> {
-        panic!("XXX not implemented");
+        // validators sign for the Dot starport with their Ethereum keys
+        Err(Reason::Unreachable)
     }
 
     fn signer_address() -> Result<Self::Address, Reason> {
-        panic!("XXX not implemented");
+        // validators sign for the Dot starport with their Ethereum keys
+        Err(Reason::Unreachable)
     }
 
-    fn str_to_address(_addr: &str) -> Result<Self::Address, Reason> {
-        panic!("XXX not implemented");
+    fn str_to_address(addr: &str) -> Result<Self::Address, Reason> {
+        match gateway_crypto::dot_str_to_address(addr) {
+            Some(s) => Ok(s),
+            None => Err(Reason::BadAddress),
+        }
     }
 
-    fn address_string(_address: &Self::Address) -> String {
-        panic!("XXX not implemented");
+    fn address_string(address: &Self::Address) -> String {
+        gateway_crypto::dot_address_string(address)
     }
 
-    fn str_to_hash(_hash: &str) -> Result<Self::Hash, Reason> {
-        panic!("XXX not implemented");
+    fn str_to_hash(hash: &str) -> Result<Self::Hash, Reason> {
+        match gateway_crypto::eth_str_to_hash(hash) {
+            Some(s) => Ok(s),
+            None => Err(Reason::BadHash),
+        }
     }
 
-    fn hash_string(_hash: &Self::Hash) -> String {
-        panic!("XXX not implemented");
+    fn hash_string(hash: &Self::Hash) -> String {
+        format!("0x{}", hex::encode(hash))
     }
 
     fn chain_account(address: Self::Address) -> ChainAccount {
         ChainAccount::Dot(address)
     }
 
-    fn chain_block(_block: Self::Block) -> ChainBlock {
-        panic!("XXX not implemented");
+    fn chain_block(block: Self::Block) -> ChainBlock {
+        ChainBlock::Dot(block)
     }
 }
 
 pub fn get_chain_account(chain: String, recipient: [u8; 32]) -> Result<ChainAccount, Reason> {
-    let chain_account_fn = match &chain.to_ascii_uppercase()[..] {
-        "ETH" => ChainAccount::Eth,
-        "MATIC" => ChainAccount::Matic,
-        "MAT" => ChainAccount::Matic,
-        _ => return Err(Reason::InvalidChain),
+    let chain_id = match &chain.to_ascii_uppercase()[..] {
+        "ETH" => ChainId::Eth,
+        "MATIC" => ChainId::Matic,
+        "MAT" => ChainId::Matic,
+        "DOT" => return Ok(ChainAccount::Dot(recipient)),
+        other => match ChainId::from_str(other) {
+            Ok(ChainId::Evm(evm_chain_id)) => ChainId::Evm(evm_chain_id),
+            _ => return Err(Reason::InvalidChain),
+        },
     };
 
     let mut eth_recipient: [u8; 20] = [0; 20];
     eth_recipient[..].clone_from_slice(&recipient[0..20]);
 
-    Ok(chain_account_fn(eth_recipient))
+    chain_id.eth_like_account(eth_recipient)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tests::eth_log_event;
     use ethereum_client::{EthereumBlock, EthereumEvent};
 
     #[test]
@@ -1312,29 +1790,100 @@ mod tests {
             hash: [2u8; 32],
             parent_hash: [1u8; 32],
             number: 2,
-            events: vec![EthereumEvent::Lock {
-                asset: [4u8; 20],
-                sender: [5u8; 20],
-                chain: String::from("ETH"),
-                recipient: [6u8; 32],
-                amount: 100,
-            }],
-        }));
-        assert_eq!(
-            a,
-            ChainBlockEvents::Eth(vec![(
-                2,
+            events: vec![eth_log_event(
+                0,
                 EthereumEvent::Lock {
                     asset: [4u8; 20],
                     sender: [5u8; 20],
                     chain: String::from("ETH"),
                     recipient: [6u8; 32],
                     amount: 100,
-                }
+                },
+            )],
+        }));
+        assert_eq!(
+            a,
+            ChainBlockEvents::Eth(vec![(
+                2,
+                eth_log_event(
+                    0,
+                    EthereumEvent::Lock {
+                        asset: [4u8; 20],
+                        sender: [5u8; 20],
+                        chain: String::from("ETH"),
+                        recipient: [6u8; 32],
+                        amount: 100,
+                    }
+                )
             )])
         );
     }
 
+    #[test]
+    fn test_chain_block_event_is_same_log() {
+        let event = EthereumEvent::NoticeInvoked {
+            era_id: 0,
+            era_index: 0,
+            notice_hash: [1u8; 32],
+            result: vec![],
+        };
+        let log = eth_log_event(0, event.clone());
+        let other_tx_log = EthereumLogEvent {
+            transaction_hash: [2u8; 32],
+            ..log.clone()
+        };
+
+        assert!(
+            ChainBlockEvent::Eth(2, log.clone()).is_same_log(&ChainBlockEvent::Eth(3, log.clone()))
+        );
+        assert!(!ChainBlockEvent::Eth(2, log.clone())
+            .is_same_log(&ChainBlockEvent::Eth(2, other_tx_log)));
+        assert!(ChainBlockEvent::Eth(2, log.clone())
+            .is_same_log(&ChainBlockEvent::Eth(3, eth_log_event(5, event))));
+        assert!(
+            !ChainBlockEvent::Eth(2, log.clone()).is_same_log(&ChainBlockEvent::Eth(
+                2,
+                eth_log_event(
+                    0,
+                    EthereumEvent::NoticeInvoked {
+                        era_id: 0,
+                        era_index: 1,
+                        notice_hash: [1u8; 32],
+                        result: vec![],
+                    }
+                )
+            ))
+        );
+        assert!(!ChainBlockEvent::Eth(2, log.clone()).is_same_log(&ChainBlockEvent::Matic(2, log)));
+    }
+
+    #[test]
+    fn test_chain_block_event_is_same_block_log() {
+        let event = EthereumEvent::NoticeInvoked {
+            era_id: 0,
+            era_index: 0,
+            notice_hash: [1u8; 32],
+            result: vec![],
+        };
+        let log = eth_log_event(0, event.clone());
+        let unknown_log = EthereumLogEvent {
+            transaction_hash: UNKNOWN_TRANSACTION_HASH,
+            ..log.clone()
+        };
+
+        assert!(ChainBlockEvent::Eth(2, log.clone())
+            .is_same_block_log(&ChainBlockEvent::Eth(2, log.clone())));
+        assert!(!ChainBlockEvent::Eth(2, log.clone())
+            .is_same_block_log(&ChainBlockEvent::Eth(3, log.clone())));
+        assert!(!ChainBlockEvent::Eth(2, log.clone())
+            .is_same_block_log(&ChainBlockEvent::Eth(2, eth_log_event(5, event.clone()))));
+        assert!(ChainBlockEvent::Eth(2, unknown_log.clone())
+            .is_same_block_log(&ChainBlockEvent::Eth(2, eth_log_event(5, event))));
+        assert!(
+            !ChainBlockEvent::Eth(2, unknown_log).is_same_block_log(&ChainBlockEvent::Eth(3, log))
+        );
+    }
+
     #[test]
     fn test_chain_blocks_filter_already_suppported() {
         let signer = sp_core::crypto::AccountId32::new([7u8; 32]);
@@ -1438,4 +1987,156 @@ mod tests {
         assert_eq!(reorg.is_already_signed(&signer, vec![]), false);
         assert_eq!(reorg.is_already_signed(&signer, pending_reorgs), true);
     }
+
+    #[test]
+    fn test_gate_account_ss58() {
+        let alice = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+        let account = ChainAccount::from_str(&format!("Gate:{}", alice)).unwrap();
+        assert_eq!(
+            account,
+            ChainAccount::from_str(
+                "Gate:0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
+            )
+            .unwrap()
+        );
+        assert_eq!(String::from(account), format!("GATE:{}", alice));
+        assert_eq!(
+            ChainAccount::from_str("Gate:15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"),
+            Err(Reason::BadAddress)
+        );
+    }
+
+    #[test]
+    fn test_dot_account_ss58() {
+        let alice = "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5";
+        let account = ChainAccount::from_str(&format!("Dot:{}", alice)).unwrap();
+        assert_eq!(
+            account,
+            ChainAccount::from_str(
+                "Dot:0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            account,
+            ChainAccount::from_str("Dot:5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap()
+        );
+        assert_eq!(String::from(account), format!("DOT:{}", alice));
+        assert_eq!(
+            ChainAccount::from_str("Dot:0x0101"),
+            Err(Reason::BadAddress)
+        );
+    }
+
+    #[test]
+    fn test_dot_asset_and_hash() {
+        let asset = ChainAsset::from_str("Dot:7").unwrap();
+        assert_eq!(asset, ChainAsset::Dot(7));
+        assert_eq!(String::from(asset), "DOT:7");
+        assert_eq!(ChainAsset::from_str("Dot:0x01"), Err(Reason::BadAsset));
+
+        let hash = ChainHash::from_str(
+            "Dot:0x0202020202020202020202020202020202020202020202020202020202020202",
+        )
+        .unwrap();
+        assert_eq!(hash, ChainHash::Dot([2u8; 32]));
+        assert_eq!(
+            String::from(hash),
+            "0x0202020202020202020202020202020202020202020202020202020202020202"
+        );
+    }
+
+    #[test]
+    fn test_dot_account_signature() {
+        use sp_core::Pair;
+        let pair = sp_core::sr25519::Pair::from_seed(&[1u8; 32]);
+        let message = b"(Extract 3 Cash Dot:0x0101)";
+        let signature = GatewaySignature::Sr25519(pair.sign(&message[..]).0);
+
+        assert_eq!(
+            ChainAccountSignature::Dot(pair.public().0, signature).recover_account(&message[..]),
+            Ok(ChainAccount::Dot(pair.public().0))
+        );
+        assert_eq!(
+            ChainAccountSignature::Dot([9u8; 32], signature).recover_account(&message[..]),
+            Err(Reason::SignatureAccountMismatch)
+        );
+
+        // Wallets wrap the bytes they sign, which is accepted just as for Gateway accounts
+        let wrapped = [GATEWAY_BYTES_PREFIX, &message[..], GATEWAY_BYTES_SUFFIX].concat();
+        let wrapped_signature = GatewaySignature::Sr25519(pair.sign(&wrapped[..]).0);
+        assert_eq!(
+            ChainAccountSignature::Dot(pair.public().0, wrapped_signature)
+                .recover_account(&message[..]),
+            Ok(ChainAccount::Dot(pair.public().0))
+        );
+    }
+
+    #[test]
+    fn test_evm_chain_id_from_str() {
+        assert_eq!(ChainId::from_str("EVM10"), Ok(ChainId::Evm(10)));
+        assert_eq!(ChainId::from_str("evm42161"), Ok(ChainId::Evm(42161)));
+        assert_eq!(ChainId::from_str("EVM"), Err(Reason::BadChainId));
+        assert_eq!(ChainId::from_str("EVMx"), Err(Reason::BadChainId));
+
+        let account =
+            ChainAccount::from_str("Evm10:0x0101010101010101010101010101010101010101").unwrap();
+        assert_eq!(account, ChainAccount::Evm(10, [1u8; 20]));
+        assert_eq!(account.chain_id(), ChainId::Evm(10));
+        assert_eq!(
+            String::from(account),
+            "EVM10:0x0101010101010101010101010101010101010101"
+        );
+
+        let asset =
+            ChainAsset::from_str("EVM10:0x0202020202020202020202020202020202020202").unwrap();
+        assert_eq!(asset, ChainAsset::Evm(10, [2u8; 20]));
+        assert_ne!(asset, ChainAsset::Evm(11, [2u8; 20]));
+    }
+
+    #[test]
+    fn test_evm_chain_block_events() {
+        let block = ChainBlock::Evm(
+            10,
+            EthereumBlock {
+                hash: [2u8; 32],
+                parent_hash: [1u8; 32],
+                number: 2,
+                events: vec![eth_log_event(
+                    0,
+                    EthereumEvent::LockCash {
+                        sender: [5u8; 20],
+                        chain: String::from("EVM10"),
+                        recipient: [6u8; 32],
+                        amount: 100,
+                        principal: 90,
+                    },
+                )],
+            },
+        );
+        assert_eq!(block.hash(), ChainHash::Evm(10, [2u8; 32]));
+        assert_eq!(block.parent_hash(), ChainHash::Evm(10, [1u8; 32]));
+
+        let mut events = ChainBlockEvents::empty(ChainId::Evm(10)).unwrap();
+        events.push(&block);
+        assert_eq!(events.len(), 1);
+
+        let event = &block.events()[0];
+        assert_eq!(event.chain_id(), ChainId::Evm(10));
+        assert_eq!(events.position(event), Some(0));
+        events.retain(|e| e.block_number() != 2);
+        assert_eq!(events.len(), 0);
+    }
+
+    #[test]
+    fn test_get_chain_account_evm() {
+        assert_eq!(
+            get_chain_account(String::from("EVM10"), [1u8; 32]),
+            Ok(ChainAccount::Evm(10, [1u8; 20]))
+        );
+        assert_eq!(
+            get_chain_account(String::from("EVMX"), [1u8; 32]),
+            Err(Reason::InvalidChain)
+        );
+    }
 }
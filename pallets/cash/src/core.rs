@@ -11,20 +11,25 @@ pub use our_std::{
 
 use crate::{
     chains::{
-        self, Chain, ChainAccount, ChainAsset, ChainBlock, ChainBlockEvent, ChainBlockEvents,
-        ChainHash, ChainId, ChainSignature, Ethereum, Polygon,
+        Chain, ChainAccount, ChainAsset, ChainBlock, ChainBlockEvent, ChainBlockEvents, ChainId,
+        ChainSignature, Ethereum, EvmChainConfig, EvmChainId, Polygon,
     },
-    internal, log, pipeline,
+    log,
+    params::MAX_EVENT_BLOCKS,
+    pipeline,
     portfolio::Portfolio,
     rates::APR,
     reason::Reason,
+    require,
+    starport::StarportEvent,
     types::{
-        AssetAmount, AssetBalance, Balance, CashPrincipalAmount, GovernanceResult, NoticeId,
-        SignersSet, Timestamp, ValidatorKeys,
+        AssetAmount, AssetBalance, Balance, CashPrincipalAmount, EventKind, GovernanceResult,
+        IngressConfig, RiskModel, SignersSet, Timestamp, ValidatorKeys,
     },
     AssetBalances, AssetsWithNonZeroBalance, CashIndex, CashPrincipals, CashYield, Config, Event,
-    FirstBlock, GlobalCashIndex, IngressionQueue, LastProcessedBlock, Pallet, Starports,
-    SupportedAssets, TotalBorrowAssets, TotalCashPrincipal, TotalSupplyAssets, Validators,
+    EvmChains, FirstBlock, GlobalCashIndex, IngressConfigs, IngressionQueue, LastProcessedBlock,
+    Pallet, RiskModels, Starports, SupportedAssets, TotalBorrowAssets, TotalCashPrincipal,
+    TotalSupplyAssets, Validators,
 };
 
 use codec::Decode;
@@ -36,7 +41,6 @@ use frame_support::{
 };
 use timestamp::GetConvertedTimestamp;
 
-use crate::events::EventError;
 pub use our_std::debug;
 
 // Public helper functions //
@@ -51,6 +55,29 @@ pub fn get_event_queue<T: Config>(chain_id: ChainId) -> Result<ChainBlockEvents,
     Ok(IngressionQueue::get(chain_id).unwrap_or(ChainBlockEvents::empty(chain_id)?))
 }
 
+/// Return the ingress config for the underlying chain.
+/// Chains without their own config fall back to the global params,
+///  EVM chains taking their minimum event blocks from the registered confirmation depth.
+pub fn get_ingress_config<T: Config>(chain_id: ChainId) -> IngressConfig {
+    match (IngressConfigs::get(chain_id), chain_id) {
+        (Some(config), _) => config,
+        (None, ChainId::Evm(evm_chain_id)) => match EvmChains::get(evm_chain_id) {
+            Some(evm_chain) => IngressConfig {
+                min_event_blocks: evm_chain.confirmation_depth,
+                max_event_blocks: max(evm_chain.confirmation_depth, MAX_EVENT_BLOCKS),
+                ..IngressConfig::default()
+            },
+            None => IngressConfig::default(),
+        },
+        (None, _) => IngressConfig::default(),
+    }
+}
+
+/// Return the risk model for the kind of event, falling back to the default if not set.
+pub fn get_risk_model<T: Config>(kind: EventKind) -> RiskModel {
+    RiskModels::get(kind).unwrap_or_default()
+}
+
 /// Return the last processed block for the underlying chain.
 pub fn get_first_block<T: Config>(chain_id: ChainId) -> Result<ChainBlock, Reason> {
     FirstBlock::get(chain_id).ok_or(Reason::MissingBlock)
@@ -202,7 +229,18 @@ pub fn get_current_validator<T: Config>() -> Result<ValidatorKeys, Reason> {
 
 /// Return the starport associated with a given chain
 pub fn get_starport<T: Config>(chain_id: ChainId) -> Result<ChainAccount, Reason> {
-    Starports::get(chain_id).ok_or(Reason::StarportMissing)
+    match chain_id {
+        ChainId::Evm(evm_chain_id) => Ok(ChainAccount::Evm(
+            evm_chain_id,
+            get_evm_chain::<T>(evm_chain_id)?.starport,
+        )),
+        _ => Starports::get(chain_id).ok_or(Reason::StarportMissing),
+    }
+}
+
+/// Return the configuration of an EVM chain registered by governance.
+pub fn get_evm_chain<T: Config>(evm_chain_id: EvmChainId) -> Result<EvmChainConfig, Reason> {
+    EvmChains::get(evm_chain_id).ok_or(Reason::StarportMissing)
 }
 
 /// Return the validator which signed the given data, given signature.
@@ -228,6 +266,14 @@ pub fn recover_validator<T: Config>(
                 }
             }
         }
+        ChainSignature::Dot(eth_sig) | ChainSignature::Evm(_, eth_sig) => {
+            let eth_address = <Ethereum as Chain>::recover_address(data, eth_sig)?;
+            for (_, validator) in Validators::iter() {
+                if validator.eth_address == eth_address {
+                    return Ok(validator);
+                }
+            }
+        }
 
         _ => {
             // this is a placeholder for future variants, which should be kept minimal
@@ -253,177 +299,25 @@ pub fn validator_sign<T: Config>(data: &[u8]) -> Result<ChainSignature, Reason>
 pub fn apply_chain_event_internal<T: Config>(event: &ChainBlockEvent) -> Result<(), Reason> {
     log!("apply_chain_event_internal(event): {:?}", event);
 
-    match event {
-        ChainBlockEvent::Reserved => panic!("reserved"),
-        ChainBlockEvent::Eth(_block_num, eth_event) => match eth_event {
-            ethereum_client::EthereumEvent::Lock {
-                asset,
-                sender,
-                chain,
-                recipient,
-                amount,
-            } => internal::lock::lock_internal::<T>(
-                internal::assets::get_asset::<T>(ChainAsset::Eth(*asset))?,
-                ChainAccount::Eth(*sender),
-                chains::get_chain_account(chain.to_string(), *recipient)?,
-                internal::assets::get_quantity::<T>(ChainAsset::Eth(*asset), *amount)?,
-            ),
-
-            ethereum_client::EthereumEvent::LockCash {
-                sender,
-                chain,
-                recipient,
-                principal,
-                ..
-            } => internal::lock::lock_cash_principal_internal::<T>(
-                ChainAccount::Eth(*sender),
-                chains::get_chain_account(chain.to_string(), *recipient)?,
-                CashPrincipalAmount(*principal),
-            ),
-
-            ethereum_client::EthereumEvent::ExecuteProposal {
-                title: _title,
-                extrinsics,
-            } => dispatch_extrinsics_internal::<T>(extrinsics.to_vec()),
-
-            ethereum_client::EthereumEvent::ExecTrxRequest {
-                account,
-                trx_request,
-            } => internal::exec_trx_request::exec_trx_request::<T>(
-                &trx_request[..],
-                ChainAccount::Eth(*account),
-                None,
-            ),
-
-            ethereum_client::EthereumEvent::NoticeInvoked {
-                era_id,
-                era_index,
-                notice_hash,
-                result,
-            } => internal::notices::handle_notice_invoked::<T>(
-                ChainId::Eth,
-                NoticeId(*era_id, *era_index),
-                ChainHash::Eth(*notice_hash),
-                result.to_vec(),
-            ),
-        },
-        ChainBlockEvent::Matic(_block_num, eth_event) => match eth_event {
-            ethereum_client::EthereumEvent::Lock {
-                asset,
-                sender,
-                chain,
-                recipient,
-                amount,
-            } => internal::lock::lock_internal::<T>(
-                internal::assets::get_asset::<T>(ChainAsset::Matic(*asset))?,
-                ChainAccount::Matic(*sender),
-                chains::get_chain_account(chain.to_string(), *recipient)?,
-                internal::assets::get_quantity::<T>(ChainAsset::Matic(*asset), *amount)?,
-            ),
-
-            ethereum_client::EthereumEvent::LockCash {
-                sender,
-                chain,
-                recipient,
-                principal,
-                ..
-            } => internal::lock::lock_cash_principal_internal::<T>(
-                ChainAccount::Matic(*sender),
-                chains::get_chain_account(chain.to_string(), *recipient)?,
-                CashPrincipalAmount(*principal),
-            ),
-
-            ethereum_client::EthereumEvent::ExecuteProposal { .. } => {
-                Err(EventError::ActionNotSupported)?
-            }
-
-            ethereum_client::EthereumEvent::ExecTrxRequest {
-                account,
-                trx_request,
-            } => internal::exec_trx_request::exec_trx_request::<T>(
-                &trx_request[..],
-                ChainAccount::Matic(*account),
-                None,
-            ),
-
-            ethereum_client::EthereumEvent::NoticeInvoked {
-                era_id,
-                era_index,
-                notice_hash,
-                result,
-            } => internal::notices::handle_notice_invoked::<T>(
-                ChainId::Matic,
-                NoticeId(*era_id, *era_index),
-                ChainHash::Matic(*notice_hash),
-                result.to_vec(),
-            ),
-        },
-    }
+    StarportEvent::new(event).apply::<T>()
 }
 
 /// Un-apply the event on the current state, undoing the action to the extent possible/necessary.
 pub fn unapply_chain_event_internal<T: Config>(event: &ChainBlockEvent) -> Result<(), Reason> {
     log!("unapply_chain_event_internal(event): {:?}", event);
 
-    match event {
-        ChainBlockEvent::Reserved => panic!("reserved"),
-        ChainBlockEvent::Eth(_block_num, eth_event) => match eth_event {
-            ethereum_client::EthereumEvent::Lock {
-                asset,
-                sender,
-                chain,
-                recipient,
-                amount,
-            } => internal::lock::undo_lock_internal::<T>(
-                internal::assets::get_asset::<T>(ChainAsset::Eth(*asset))?,
-                ChainAccount::Eth(*sender),
-                chains::get_chain_account(chain.to_string(), *recipient)?,
-                internal::assets::get_quantity::<T>(ChainAsset::Eth(*asset), *amount)?,
-            ),
-
-            ethereum_client::EthereumEvent::LockCash {
-                sender,
-                chain,
-                recipient,
-                principal,
-                ..
-            } => internal::lock::undo_lock_cash_principal_internal::<T>(
-                ChainAccount::Eth(*sender),
-                chains::get_chain_account(chain.to_string(), *recipient)?,
-                CashPrincipalAmount(*principal),
-            ),
-
-            _ => Ok(()),
-        },
-        ChainBlockEvent::Matic(_block_num, eth_event) => match eth_event {
-            ethereum_client::EthereumEvent::Lock {
-                asset,
-                sender,
-                chain,
-                recipient,
-                amount,
-            } => internal::lock::undo_lock_internal::<T>(
-                internal::assets::get_asset::<T>(ChainAsset::Matic(*asset))?,
-                ChainAccount::Matic(*sender),
-                chains::get_chain_account(chain.to_string(), *recipient)?,
-                internal::assets::get_quantity::<T>(ChainAsset::Matic(*asset), *amount)?,
-            ),
-
-            ethereum_client::EthereumEvent::LockCash {
-                sender,
-                chain,
-                recipient,
-                principal,
-                ..
-            } => internal::lock::undo_lock_cash_principal_internal::<T>(
-                ChainAccount::Matic(*sender),
-                chains::get_chain_account(chain.to_string(), *recipient)?,
-                CashPrincipalAmount(*principal),
-            ),
-
-            _ => Ok(()),
-        },
-    }
+    // only happens for a reorg deeper than the max event blocks, which must not be let through
+    require!(
+        !is_chain_event_irreversible(event),
+        Reason::IrreversibleEventReorged
+    );
+
+    StarportEvent::new(event).unapply::<T>()
+}
+
+/// Whether the event has effects which un-applying cannot undo.
+pub fn is_chain_event_irreversible(event: &ChainBlockEvent) -> bool {
+    StarportEvent::new(event).is_irreversible()
 }
 
 pub fn dispatch_extrinsics_internal<T: Config>(extrinsics: Vec<Vec<u8>>) -> Result<(), Reason> {
@@ -1,14 +1,17 @@
 use crate::{
     chains::{
-        Chain, ChainBlock, ChainBlockNumber, ChainBlocks, ChainHash, ChainId, ChainStarport,
-        Ethereum,
+        ChainBlock, ChainBlockNumber, ChainBlocks, ChainHash, ChainId, ChainStarport, Ethereum,
+        Polkadot, Polygon,
     },
     debug,
     reason::Reason,
+    starport::{EthLikeStarport, StarportChain},
 };
 use codec::{Decode, Encode};
-use ethereum_client::{EthereumBlock, EthereumBlockId, EthereumClientError};
+use ethereum_client::{EthereumBlock, EthereumBlockId, EthereumClientError, RpcEndpoints};
 use our_std::RuntimeDebug;
+use polkadot_client::{PolkadotBlock, PolkadotBlockId, PolkadotClientError};
+use sp_runtime::offchain::storage::StorageValueRef;
 use types_derive::Types;
 
 /// Type for errors coming from event ingression.
@@ -20,6 +23,9 @@ pub enum EventError {
     ErrorDecodingHex,
     PolygonClientError(EthereumClientError),
     ActionNotSupported,
+    PolkadotClientError(PolkadotClientError),
+    EvmClientError(EthereumClientError),
+    BadRpcConfig,
 }
 
 /// Fetch a block from the underlying chain by hash.
@@ -30,10 +36,24 @@ pub fn fetch_chain_block_by_hash(
 ) -> Result<ChainBlock, Reason> {
     match (chain_id, hash, starport) {
         (ChainId::Gate, _, _) => Err(Reason::Unreachable),
-        (ChainId::Eth, ChainHash::Eth(eth_hash), ChainStarport::Eth(eth_starport_address)) => {
-            Ok(fetch_eth_block_by_hash(eth_hash, &eth_starport_address).map(ChainBlock::Eth)?)
+        (ChainId::Eth, ChainHash::Eth(hash), ChainStarport::Eth(starport_address)) => {
+            Ok(Ethereum::fetch_block_by_hash(hash, &starport_address).map(ChainBlock::Eth)?)
+        }
+        (ChainId::Matic, ChainHash::Matic(hash), ChainStarport::Matic(starport_address)) => {
+            Ok(Polygon::fetch_block_by_hash(hash, &starport_address).map(ChainBlock::Matic)?)
+        }
+        (ChainId::Dot, ChainHash::Dot(hash), ChainStarport::Dot(starport_address)) => {
+            Ok(Polkadot::fetch_block_by_hash(hash, &starport_address).map(ChainBlock::Dot)?)
+        }
+        (
+            ChainId::Evm(evm_chain_id),
+            ChainHash::Evm(hash_chain_id, hash),
+            ChainStarport::Evm(starport_chain_id, starport_address),
+        ) if evm_chain_id == hash_chain_id && evm_chain_id == starport_chain_id => {
+            Ok(EthLikeStarport::new(chain_id)
+                .fetch_block_by_hash(hash, &starport_address)
+                .map(|block| ChainBlock::Evm(evm_chain_id, block))?)
         }
-        (ChainId::Dot, _, _) => Err(Reason::Unreachable),
         _ => Err(Reason::Unreachable),
     }
 }
@@ -46,13 +66,22 @@ pub fn fetch_chain_block(
 ) -> Result<ChainBlock, Reason> {
     match (chain_id, starport) {
         (ChainId::Gate, _) => Err(Reason::Unreachable),
-        (ChainId::Eth, ChainStarport::Eth(eth_starport_address)) => {
-            Ok(fetch_eth_block(number, &eth_starport_address).map(ChainBlock::Eth)?)
+        (ChainId::Eth, ChainStarport::Eth(starport_address)) => {
+            Ok(Ethereum::fetch_block(number, &starport_address).map(ChainBlock::Eth)?)
         }
         (ChainId::Matic, ChainStarport::Matic(starport_address)) => {
-            Ok(fetch_matic_block(number, &starport_address).map(ChainBlock::Matic)?)
+            Ok(Polygon::fetch_block(number, &starport_address).map(ChainBlock::Matic)?)
+        }
+        (ChainId::Dot, ChainStarport::Dot(starport_address)) => {
+            Ok(Polkadot::fetch_block(number, &starport_address).map(ChainBlock::Dot)?)
+        }
+        (ChainId::Evm(evm_chain_id), ChainStarport::Evm(starport_chain_id, starport_address))
+            if evm_chain_id == starport_chain_id =>
+        {
+            Ok(EthLikeStarport::new(chain_id)
+                .fetch_block(number, &starport_address)
+                .map(|block| ChainBlock::Evm(evm_chain_id, block))?)
         }
-        (ChainId::Dot, _) => Err(Reason::Unreachable),
         _ => Err(Reason::Unreachable),
     }
 }
@@ -66,133 +95,150 @@ pub fn fetch_chain_blocks(
 ) -> Result<ChainBlocks, Reason> {
     match (chain_id, starport) {
         (ChainId::Gate, _) => Err(Reason::Unreachable),
-        (ChainId::Eth, ChainStarport::Eth(eth_starport_address)) => {
-            Ok(fetch_eth_blocks(from, to, &eth_starport_address)?)
+        (ChainId::Eth, ChainStarport::Eth(starport_address)) => {
+            Ok(Ethereum::fetch_blocks(from, to, &starport_address)?)
         }
         (ChainId::Matic, ChainStarport::Matic(starport_address)) => {
-            Ok(fetch_matic_blocks(from, to, &starport_address)?)
+            Ok(Polygon::fetch_blocks(from, to, &starport_address)?)
+        }
+        (ChainId::Dot, ChainStarport::Dot(starport_address)) => {
+            Ok(Polkadot::fetch_blocks(from, to, &starport_address)?)
+        }
+        (ChainId::Evm(evm_chain_id), ChainStarport::Evm(starport_chain_id, starport_address))
+            if evm_chain_id == starport_chain_id =>
+        {
+            Ok(EthLikeStarport::new(chain_id)
+                .fetch_blocks(from, to, &starport_address)
+                .map(|blocks| ChainBlocks::Evm(evm_chain_id, blocks))?)
         }
-        (ChainId::Dot, _) => Err(Reason::Unreachable),
         _ => Err(Reason::Unreachable),
     }
 }
 
-/// Fetch a single block from the Etherum Starport by hash.
-fn fetch_eth_block_by_hash(
-    hash: <Ethereum as Chain>::Hash,
-    eth_starport_address: &[u8; 20],
-) -> Result<EthereumBlock, EventError> {
-    debug!("Fetching Eth Block {:?}", hash);
-    let eth_rpc_url = runtime_interfaces::validator_config_interface::get_eth_rpc_url()
-        .ok_or(EventError::NoRpcUrl)?;
-    let eth_block = ethereum_client::get_block(
-        &eth_rpc_url,
-        eth_starport_address,
-        EthereumBlockId::Hash(hash),
-    )
-    .map_err(EventError::EthereumClientError)?;
-    Ok(eth_block)
+/// Determine the latest block number the underlying chain has finalized.
+pub fn fetch_chain_finalized_block_number(chain_id: ChainId) -> Result<ChainBlockNumber, Reason> {
+    match chain_id {
+        ChainId::Gate => Err(Reason::Unreachable),
+        ChainId::Eth => Ok(Ethereum::fetch_finalized_block_number()?),
+        ChainId::Matic => Ok(Polygon::fetch_finalized_block_number()?),
+        ChainId::Dot => Ok(Polkadot::fetch_finalized_block_number()?),
+        ChainId::Evm(_) => Ok(EthLikeStarport::new(chain_id).fetch_finalized_block_number()?),
+    }
 }
 
-/// Fetch a single block from the Etherum Starport.
-fn fetch_eth_block(
-    number: ChainBlockNumber,
-    eth_starport_address: &[u8; 20],
-) -> Result<EthereumBlock, EventError> {
-    debug!("Fetching Eth Block {}", number);
-    let eth_rpc_url = runtime_interfaces::validator_config_interface::get_eth_rpc_url()
-        .ok_or(EventError::NoRpcUrl)?;
-    let eth_block = ethereum_client::get_block(
-        &eth_rpc_url,
-        eth_starport_address,
-        EthereumBlockId::Number(number),
-    )
-    .map_err(EventError::EthereumClientError)?;
-    Ok(eth_block)
+/// Parse the node RPC endpoints configured for an Ethereum-like Starport.
+/// The URL may be a comma-separated list, optionally with a quorum of them which must agree.
+pub fn eth_like_rpc_endpoints(
+    rpc_url: Option<String>,
+    rpc_quorum: Option<String>,
+) -> Result<RpcEndpoints, EventError> {
+    let rpc_url = rpc_url.ok_or(EventError::NoRpcUrl)?;
+    RpcEndpoints::parse(&rpc_url, rpc_quorum.as_deref()).ok_or(EventError::BadRpcConfig)
 }
 
-/// Fetch a single block from the Etherum Starport.
-fn fetch_matic_block(
-    number: ChainBlockNumber,
-    matic_starport_address: &[u8; 20],
+/// Fetch a single block from an Ethereum-like Starport, using the given node RPC URLs.
+pub fn fetch_eth_like_block(
+    rpc_url: Option<String>,
+    rpc_quorum: Option<String>,
+    starport_address: &[u8; 20],
+    block_id: EthereumBlockId,
+    client_error: fn(EthereumClientError) -> EventError,
 ) -> Result<EthereumBlock, EventError> {
-    let matic_rpc_url = runtime_interfaces::validator_config_interface::get_matic_rpc_url()
-        .ok_or(EventError::NoRpcUrl)?;
-    let block = ethereum_client::get_block(
-        &matic_rpc_url,
-        matic_starport_address,
-        EthereumBlockId::Number(number),
-    )
-    .map_err(EventError::PolygonClientError)?;
+    debug!("Fetching Block {:?}", block_id);
+    let endpoints = eth_like_rpc_endpoints(rpc_url, rpc_quorum)?;
+    let block = endpoints
+        .get_block(starport_address, block_id)
+        .map_err(client_error)?;
     Ok(block)
 }
 
-/// Fetch blocks from the Ethereum Starport, return up to `slack` blocks to add to the event queue.
-fn fetch_eth_like_blocks<
-    F: FnMut(ChainBlockNumber, &[u8; 20]) -> Result<EthereumBlock, EventError>,
-    G: FnMut(Vec<EthereumBlock>) -> ChainBlocks,
->(
-    chain_id: ChainId,
+/// Fetch a range of blocks from an Ethereum-like Starport, using the given node RPC URLs.
+/// Stops at the first block which does not exist yet, rather than erring.
+pub fn fetch_eth_like_blocks(
+    rpc_url: Option<String>,
+    rpc_quorum: Option<String>,
+    starport_address: &[u8; 20],
     from: ChainBlockNumber,
     to: ChainBlockNumber,
+    client_error: fn(EthereumClientError) -> EventError,
+) -> Result<Vec<EthereumBlock>, EventError> {
+    debug!("Fetching Blocks from_block={}, to_block={}", from, to);
+    let endpoints = eth_like_rpc_endpoints(rpc_url, rpc_quorum)?;
+    let blocks = endpoints
+        .get_blocks_range(starport_address, from, to)
+        .map_err(client_error)?;
+    Ok(blocks)
+}
+
+/// Fetch just the number of a block from an Ethereum-like chain, using the given node RPC URLs.
+pub fn fetch_eth_like_block_number(
+    rpc_url: Option<String>,
+    rpc_quorum: Option<String>,
+    block_id: EthereumBlockId,
+    client_error: fn(EthereumClientError) -> EventError,
+) -> Result<ChainBlockNumber, EventError> {
+    debug!("Fetching Block Number {:?}", block_id);
+    let endpoints = eth_like_rpc_endpoints(rpc_url, rpc_quorum)?;
+    let number = endpoints.get_block_number(block_id).map_err(client_error)?;
+    Ok(number)
+}
+
+/// The offchain storage key under which the node caches the Ethereum block with the given number.
+/// Blocks are cached by the node when it subscribes to new heads over ETH_WS_URL.
+pub fn eth_block_cache_key(number: ChainBlockNumber) -> String {
+    format!("cash::eth_block_cache::{}", number)
+}
+
+/// Read an Ethereum block cached by the node, if it was taken from the given Starport.
+pub fn get_cached_eth_block(
+    number: ChainBlockNumber,
     starport_address: &[u8; 20],
-    mut fetch_block_fn: F,
-    no_result_error: EventError,
-    mut chain_blocks_fn: G,
-) -> Result<ChainBlocks, EventError> {
-    debug!(
-        "Fetching Blocks chain_id={:?}, from_block={}, to_block={}",
-        chain_id, from, to
-    );
-    let mut acc: Vec<EthereumBlock> = vec![];
-    for block_number in from..to {
-        match fetch_block_fn(block_number, starport_address) {
-            Ok(block) => {
-                acc.push(block);
-            }
-            Err(err) => {
-                if err == no_result_error {
-                    break;
-                }
-                return Err(err);
-            }
+) -> Option<EthereumBlock> {
+    let krf = StorageValueRef::persistent(eth_block_cache_key(number).as_bytes());
+    match krf.get::<([u8; 20], EthereumBlock)>() {
+        Some(Some((cached_starport, block))) if cached_starport == *starport_address => {
+            debug!("Using cached Eth block {}", number);
+            Some(block)
         }
+        _ => None,
     }
-    Ok(chain_blocks_fn(acc))
 }
 
-/// Fetch blocks from the Ethereum Starport, return up to `slack` blocks to add to the event queue.
-fn fetch_eth_blocks(
+/// Read the consecutive run of cached Ethereum blocks numbered `from` up to (but not including) `to`.
+/// Stops at the first block which is not cached, so the result may be shorter than the range.
+/// Also stops at the first block which does not follow the one before it, as the blocks cached
+///  before a reorg may still be from the abandoned fork.
+pub fn get_cached_eth_blocks(
     from: ChainBlockNumber,
     to: ChainBlockNumber,
-    eth_starport_address: &[u8; 20],
-) -> Result<ChainBlocks, EventError> {
-    fetch_eth_like_blocks(
-        ChainId::Eth,
-        from,
-        to,
-        eth_starport_address,
-        fetch_eth_block,
-        EventError::EthereumClientError(EthereumClientError::NoResult),
-        ChainBlocks::Eth,
-    )
+    starport_address: &[u8; 20],
+) -> Vec<EthereumBlock> {
+    let mut blocks: Vec<EthereumBlock> = vec![];
+    for number in from..to {
+        match get_cached_eth_block(number, starport_address) {
+            Some(block) => {
+                if let Some(prev) = blocks.last() {
+                    if block.parent_hash != prev.hash {
+                        debug!("Cached Eth block {} is from another fork", number);
+                        break;
+                    }
+                }
+                blocks.push(block)
+            }
+            None => break,
+        }
+    }
+    blocks
 }
 
-/// Fetch blocks from the Polygon Starport, return up to `slack` blocks to add to the event queue.
-fn fetch_matic_blocks(
-    from: ChainBlockNumber,
-    to: ChainBlockNumber,
-    starport_address: &[u8; 20],
-) -> Result<ChainBlocks, EventError> {
-    fetch_eth_like_blocks(
-        ChainId::Matic,
-        from,
-        to,
-        starport_address,
-        fetch_matic_block,
-        EventError::PolygonClientError(EthereumClientError::NoResult),
-        ChainBlocks::Matic,
-    )
+/// Fetch a single block from the Polkadot Starport.
+pub fn fetch_dot_block(block_id: PolkadotBlockId) -> Result<PolkadotBlock, EventError> {
+    debug!("Fetching Dot Block {:?}", block_id);
+    let dot_rpc_url = runtime_interfaces::validator_config_interface::get_dot_rpc_url()
+        .ok_or(EventError::NoRpcUrl)?;
+    let dot_block = polkadot_client::get_block(&dot_rpc_url, block_id)
+        .map_err(EventError::PolkadotClientError)?;
+    Ok(dot_block)
 }
 
 #[cfg(test)]
@@ -200,6 +246,77 @@ mod tests {
     use crate::events::*;
     use crate::tests::*;
 
+    #[test]
+    fn test_eth_like_rpc_endpoints() {
+        assert_eq!(
+            eth_like_rpc_endpoints(None, None),
+            Err(EventError::NoRpcUrl)
+        );
+        assert_eq!(
+            eth_like_rpc_endpoints(
+                Some(String::from("http://a,http://b")),
+                Some(String::from("3"))
+            ),
+            Err(EventError::BadRpcConfig)
+        );
+        assert_eq!(
+            eth_like_rpc_endpoints(
+                Some(String::from("http://a,http://b")),
+                Some(String::from("2"))
+            )
+            .map(|endpoints| endpoints.servers.len()),
+            Ok(2)
+        );
+    }
+
+    #[test]
+    fn test_fetch_eth_blocks_from_cache() {
+        let block = ethereum_client::EthereumBlock {
+            hash: [3u8; 32],
+            parent_hash: [2u8; 32],
+            number: 3,
+            events: vec![],
+        };
+        let (mut t, _, _) = new_test_ext_with_http_calls(vec![]);
+
+        t.execute_with(|| {
+            StorageValueRef::persistent(eth_block_cache_key(3).as_bytes())
+                .set(&([1u8; 20], block.clone()));
+
+            assert_eq!(Ethereum::fetch_block(3, &[1; 20]), Ok(block.clone()));
+            assert_eq!(get_cached_eth_block(3, &[2; 20]), None);
+            assert_eq!(get_cached_eth_block(4, &[1; 20]), None);
+
+            match Ethereum::fetch_blocks(3, 5, &[1; 20]) {
+                Ok(ChainBlocks::Eth(blocks)) => assert_eq!(blocks, vec![block.clone()]),
+                _ => panic!("Expected cached Eth blocks"),
+            }
+        });
+    }
+
+    #[test]
+    fn test_get_cached_eth_blocks_stops_at_fork() {
+        let block = |number: u64, pad: u8, parent_pad: u8| ethereum_client::EthereumBlock {
+            hash: [number as u8 + pad; 32],
+            parent_hash: [number as u8 - 1 + parent_pad; 32],
+            number,
+            events: vec![],
+        };
+        // blocks 3 and 4 are from the new fork, block 5 is left over from the old one
+        let blocks = vec![block(3, 0, 0), block(4, 100, 0), block(5, 0, 0)];
+        let (mut t, _, _) = new_test_ext_with_http_calls(vec![]);
+
+        t.execute_with(|| {
+            for block in blocks.iter() {
+                StorageValueRef::persistent(eth_block_cache_key(block.number).as_bytes())
+                    .set(&([1u8; 20], block.clone()));
+            }
+
+            assert_eq!(get_cached_eth_blocks(3, 6, &[1; 20]), blocks[..2].to_vec());
+            assert_eq!(get_cached_eth_blocks(5, 6, &[1; 20]), blocks[2..].to_vec());
+        });
+    }
+
     #[test]
     fn test_fetch_chain_blocks_eth_returns_proper_blocks() -> Result<(), Reason> {
         let blocks_to_return = vec![
@@ -221,11 +338,12 @@ mod tests {
         let fetch_to = blocks_to_return[blocks_to_return.len() - 1].number + 1;
         const STARPORT_ADDR: [u8; 20] = [1; 20];
 
-        let calls = gen_mock_calls(&blocks_to_return, STARPORT_ADDR);
+        let calls = gen_mock_range_calls(&blocks_to_return, fetch_from, fetch_to, STARPORT_ADDR);
         let (mut t, _, _) = new_test_ext_with_http_calls(calls);
 
         t.execute_with(|| {
-            let fetched_blocks = fetch_eth_blocks(fetch_from, fetch_to, &STARPORT_ADDR).unwrap();
+            let fetched_blocks =
+                Ethereum::fetch_blocks(fetch_from, fetch_to, &STARPORT_ADDR).unwrap();
 
             match fetched_blocks {
                 ChainBlocks::Eth(blocks) => {
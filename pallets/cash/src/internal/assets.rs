@@ -2,13 +2,15 @@ use crate::{
     chains::ChainAsset,
     rates::{InterestRateModel, APR},
     reason::Reason,
+    require,
     types::{
         AssetAmount, AssetInfo, AssetQuantity, CashPrincipalAmount, Factor, LiquidityFactor,
         Quantity, USDQuantity, Units,
     },
-    Config, Event, GlobalCashIndex, Module, SupportedAssets, TotalBorrowAssets, TotalSupplyAssets,
+    AssetSymbols, Config, Event, GlobalCashIndex, Module, SupportedAssets, TotalBorrowAssets,
+    TotalSupplyAssets,
 };
-use frame_support::storage::{IterableStorageMap, StorageMap, StorageValue};
+use frame_support::storage::{IterableStorageMap, StorageDoubleMap, StorageMap, StorageValue};
 use pallet_oracle::types::Price;
 
 /// Set the liquidity factor for a supported asset.
@@ -38,6 +40,18 @@ pub fn set_rate_model<T: Config>(
 
 /// Support an asset by defining its metadata.
 pub fn support_asset<T: Config>(asset_info: AssetInfo) -> Result<(), Reason> {
+    let chain_id = asset_info.asset.chain_id();
+    require!(
+        AssetSymbols::get(chain_id, asset_info.symbol)
+            .map_or(true, |holder| holder == asset_info.asset),
+        Reason::AssetSymbolTaken
+    );
+    if let Some(prior) = SupportedAssets::get(asset_info.asset) {
+        if AssetSymbols::get(chain_id, prior.symbol) == Some(asset_info.asset) {
+            AssetSymbols::remove(chain_id, prior.symbol);
+        }
+    }
+    AssetSymbols::insert(chain_id, asset_info.symbol, asset_info.asset);
     SupportedAssets::insert(&asset_info.asset, asset_info);
     <Module<T>>::deposit_event(Event::AssetModified(asset_info));
     Ok(())
@@ -192,6 +206,38 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_support_asset_indexes_symbol() {
+        new_test_ext().execute_with(|| {
+            let weth = Symbol::from_str("WETH").unwrap();
+            assert_ok!(super::support_asset::<Test>(eth));
+            assert_eq!(AssetSymbols::get(ChainId::Eth, eth.symbol), Some(Eth));
+
+            assert_ok!(super::support_asset::<Test>(AssetInfo {
+                symbol: weth,
+                ..eth
+            }));
+            assert_eq!(AssetSymbols::get(ChainId::Eth, eth.symbol), None);
+            assert_eq!(AssetSymbols::get(ChainId::Eth, weth), Some(Eth));
+        })
+    }
+
+    #[test]
+    fn test_support_asset_symbol_taken() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(super::support_asset::<Test>(eth));
+            assert_eq!(
+                super::support_asset::<Test>(AssetInfo {
+                    symbol: eth.symbol,
+                    ..wbtc
+                }),
+                Err(Reason::AssetSymbolTaken)
+            );
+            assert_eq!(AssetSymbols::get(ChainId::Eth, eth.symbol), Some(Eth));
+            assert_eq!(SupportedAssets::get(Wbtc), None);
+        })
+    }
+
     #[test]
     fn test_get_utilization() -> Result<(), Reason> {
         new_test_ext().execute_with(|| {
@@ -1,29 +1,34 @@
 use crate::{
     chains::{
-        ChainAsset, ChainBlock, ChainBlockEvent, ChainBlockEvents, ChainBlockNumber,
-        ChainBlockTally, ChainBlocks, ChainHash, ChainId, ChainReorg, ChainReorgTally,
-        ChainSignature, ChainStarport,
+        ChainBlock, ChainBlockEvent, ChainBlockEvents, ChainBlockNumber, ChainBlockTally,
+        ChainBlocks, ChainHash, ChainId, ChainReorg, ChainReorgTally, ChainSignature,
+        ChainStarport,
     },
     core::{
         self, get_current_validator, get_event_queue, get_first_block, get_last_block,
         get_starport, get_validator_set, recover_validator, validator_sign,
     },
     debug, error,
-    events::{fetch_chain_block, fetch_chain_block_by_hash, fetch_chain_blocks},
-    internal::assets::{get_cash_quantity, get_quantity, get_value},
+    events::{
+        fetch_chain_block, fetch_chain_block_by_hash, fetch_chain_blocks,
+        fetch_chain_finalized_block_number,
+    },
     log,
-    params::{INGRESS_LARGE, INGRESS_QUOTA, INGRESS_SLACK, MAX_EVENT_BLOCKS, MIN_EVENT_BLOCKS},
+    params::{MAX_CHAIN_BLOCKS, MAX_CHAIN_BLOCK_EVENTS, MAX_DEAD_LETTER_EVENTS},
     reason::{MathError, Reason},
     require,
-    types::{CashPrincipalAmount, Quantity, USDQuantity, USD},
-    Call, Config, Event as EventT, IngressionQueue, LastProcessedBlock, Module, PendingChainBlocks,
-    PendingChainReorgs,
+    starport::{chain_reorg, StarportEvent},
+    types::{Quantity, USDQuantity, USD},
+    Call, Config, DeadLetterEvents, Event as EventT, EvmChains, IngressionQueue,
+    LastProcessedBlock, Module, PendingChainBlocks, PendingChainReorgs,
 };
 use codec::Encode;
-use ethereum_client::EthereumEvent;
-use frame_support::storage::StorageMap;
+use frame_support::storage::{IterableStorageMap, StorageMap};
 use frame_system::offchain::SubmitTransaction;
-use our_std::{cmp::max, convert::TryInto};
+use our_std::{
+    cmp::{max, min},
+    convert::TryInto,
+};
 use sp_core::offchain::Duration;
 use sp_runtime::offchain::{
     storage::StorageValueRef,
@@ -44,12 +49,36 @@ trait CollectRev: Iterator {
 impl<I: Iterator> CollectRev for I {}
 
 /// Determine the number of blocks which can still fit on an ingression queue.
-pub fn queue_slack(event_queue: &ChainBlockEvents) -> u32 {
+pub fn queue_slack(event_queue: &ChainBlockEvents, ingress_slack: u32) -> u32 {
     let queue_len: u32 = event_queue.len().try_into().unwrap_or(u32::MAX);
-    max(INGRESS_SLACK.saturating_sub(queue_len), 1)
+    max(ingress_slack.saturating_sub(queue_len), 1)
+}
+
+/// Determine whether the blocks may be submitted together in a single batch.
+/// A lone block cannot be split, so it fits whatever its events.
+pub fn fits_in_batch(blocks: &ChainBlocks) -> bool {
+    blocks.len() <= MAX_CHAIN_BLOCKS as usize
+        && (blocks.len() <= 1 || blocks.event_count() <= MAX_CHAIN_BLOCK_EVENTS as usize)
+}
+
+/// Keep as many of the leading blocks as fit in a single batch, which is always at least one.
+fn limit_batch(blocks: ChainBlocks) -> Result<ChainBlocks, Reason> {
+    let mut event_count = 0;
+    let batch = blocks
+        .blocks()
+        .into_iter()
+        .enumerate()
+        .take_while(|(i, block)| {
+            event_count += block.events().len();
+            *i == 0 || event_count <= MAX_CHAIN_BLOCK_EVENTS as usize
+        })
+        .map(|(_i, block)| block)
+        .collect();
+    collect_chain_blocks(batch)
 }
 
 /// Determine the risk-adjusted value of a particular event, given the current block number.
+/// The value is scaled and decayed according to the risk model for the kind of event.
 pub fn risk_adjusted_value<T: Config>(
     block_event: &ChainBlockEvent,
     block_number: ChainBlockNumber,
@@ -57,50 +86,10 @@ pub fn risk_adjusted_value<T: Config>(
     let elapsed_blocks = block_number
         .checked_sub(block_event.block_number())
         .ok_or(Reason::Unreachable)?;
-    match block_event {
-        ChainBlockEvent::Reserved => panic!("reserved"),
-        ChainBlockEvent::Eth(_block_num, eth_event) => match eth_event {
-            EthereumEvent::Lock { asset, amount, .. } => {
-                let quantity = get_quantity::<T>(ChainAsset::Eth(*asset), *amount)?;
-                let usd_quantity = get_value::<T>(quantity)?;
-                Ok(usd_quantity.decay(elapsed_blocks)?)
-            }
-
-            EthereumEvent::LockCash { principal, .. } => {
-                let quantity = get_cash_quantity::<T>(CashPrincipalAmount(*principal))?;
-                let usd_quantity = get_value::<T>(quantity)?;
-                Ok(usd_quantity.decay(elapsed_blocks)?)
-            }
-
-            EthereumEvent::ExecuteProposal { .. } => {
-                let usd_quantity = get_value::<T>(INGRESS_LARGE)?;
-                Ok(usd_quantity.decay(elapsed_blocks)?)
-            }
-
-            _ => Ok(Quantity::new(0, USD)),
-        },
-        ChainBlockEvent::Matic(_block_num, eth_event) => match eth_event {
-            EthereumEvent::Lock { asset, amount, .. } => {
-                let quantity = get_quantity::<T>(ChainAsset::Matic(*asset), *amount)?;
-                let usd_quantity = get_value::<T>(quantity)?;
-                debug!("matic lock detected usd_quantity={:?}", usd_quantity);
-                Ok(usd_quantity.decay(elapsed_blocks)?)
-            }
-
-            EthereumEvent::LockCash { principal, .. } => {
-                let quantity = get_cash_quantity::<T>(CashPrincipalAmount(*principal))?;
-                let usd_quantity = get_value::<T>(quantity)?;
-                Ok(usd_quantity.decay(elapsed_blocks)?)
-            }
-
-            EthereumEvent::ExecuteProposal { .. } => {
-                let usd_quantity = get_value::<T>(INGRESS_LARGE)?;
-                Ok(usd_quantity.decay(elapsed_blocks)?)
-            }
-
-            _ => Ok(Quantity::new(0, USD)),
-        },
-    }
+    let starport_event = StarportEvent::new(block_event);
+    let kind = starport_event.kind()?;
+    let usd_quantity = starport_event.value::<T>()?;
+    Ok(core::get_risk_model::<T>(kind).risk(usd_quantity, elapsed_blocks)?)
 }
 
 /// Detect if a starport is enabled for the given chain_id.
@@ -110,23 +99,38 @@ fn is_starport_enabled<T: Config>(chain_id: ChainId) -> bool {
 }
 
 /// Incrementally perform the next step of tracking events from all the underlying chains.
-pub fn track_chain_events<T: Config>() -> Result<(), Reason> {
+/// Each chain is tracked under its own lock, so a slow or failing chain doesn't hold up the others.
+pub fn track_chain_events<T: Config>() -> Vec<(ChainId, Reason)> {
+    let mut failures = vec![];
+    for chain_id in tracked_chain_ids::<T>() {
+        if let Err(err) = track_chain_events_locked::<T>(chain_id) {
+            failures.push((chain_id, err));
+        }
+    }
+    failures
+}
+
+/// Determine the underlying chains which have starports enabled for tracking.
+fn tracked_chain_ids<T: Config>() -> Vec<ChainId> {
+    let mut chain_ids = vec![ChainId::Eth];
+    for chain_id in [ChainId::Matic, ChainId::Dot].iter() {
+        if is_starport_enabled::<T>(*chain_id) {
+            chain_ids.push(*chain_id);
+        }
+    }
+    chain_ids.extend(EvmChains::iter_keys().map(ChainId::Evm));
+    chain_ids
+}
+
+/// Perform the next step of tracking events from an underlying chain, unless already in progress.
+pub fn track_chain_events_locked<T: Config>(chain_id: ChainId) -> Result<(), Reason> {
     // Note: The way this is written might look pointless, but its very important to the lock
     //  Do not modify lightly and without discussion / further testing.
+    let key = format!("cash::track_chain_events::{:?}", chain_id);
     let deadline = Duration::from_millis(120_000);
-    let mut lock = StorageLock::<Time>::with_deadline(b"cash::track_chain_events", deadline);
+    let mut lock = StorageLock::<Time>::with_deadline(key.as_bytes(), deadline);
     let result = match lock.try_lock() {
-        Ok(_guard) => {
-            // Note: chains could be parallelized
-            track_chain_events_on::<T>(ChainId::Eth)?;
-
-            if is_starport_enabled::<T>(ChainId::Matic) {
-                track_chain_events_on::<T>(ChainId::Matic)?;
-            }
-
-            Ok(())
-        }
-
+        Ok(_guard) => track_chain_events_on::<T>(chain_id),
         _ => Err(Reason::WorkerBusy),
     };
     result
@@ -137,10 +141,24 @@ pub fn track_chain_events_on<T: Config>(chain_id: ChainId) -> Result<(), Reason>
     let starport = get_starport::<T>(chain_id)?;
     let me = get_current_validator::<T>()?;
     let last_block = get_last_block::<T>(chain_id)?;
+    let ingress_config = core::get_ingress_config::<T>(chain_id);
     let next_block_number = last_block
         .number()
         .checked_add(1)
         .ok_or(MathError::Overflow)?;
+    let finalized_block_number = if ingress_config.finalized_only {
+        let finalized_block_number = fetch_chain_finalized_block_number(chain_id)?;
+        if next_block_number > finalized_block_number {
+            debug!(
+                "Worker waiting for finality: next={} finalized={}",
+                next_block_number, finalized_block_number
+            );
+            return Ok(());
+        }
+        Some(finalized_block_number)
+    } else {
+        None
+    };
     let next_block = fetch_chain_block(chain_id, next_block_number, starport)?;
     if last_block.hash() == next_block.parent_hash() {
         debug!(
@@ -149,23 +167,49 @@ pub fn track_chain_events_on<T: Config>(chain_id: ChainId) -> Result<(), Reason>
         );
         let pending_blocks = PendingChainBlocks::get(chain_id);
         let event_queue = get_event_queue::<T>(chain_id)?;
-        let slack = queue_slack(&event_queue) as u64;
-        let blocks = next_block
-            .concat(fetch_chain_blocks(
-                chain_id,
-                next_block_number
-                    .checked_add(1)
-                    .ok_or(MathError::Overflow)?,
-                next_block_number
+        // the next block is part of the batch too, which may not exceed the max size
+        //  only the next block is sent while the queue is full, as the chain takes one at a time
+        let slack = if event_queue.len() >= ingress_config.max_queue_events as usize {
+            0
+        } else {
+            min(
+                queue_slack(&event_queue, ingress_config.slack),
+                MAX_CHAIN_BLOCKS - 1,
+            ) as u64
+        };
+        let mut to_block_number = next_block_number
+            .checked_add(1)
+            .ok_or(MathError::Overflow)?
+            .checked_add(slack)
+            .ok_or(MathError::Overflow)?;
+        if let Some(finalized_block_number) = finalized_block_number {
+            to_block_number = min(
+                to_block_number,
+                finalized_block_number
                     .checked_add(1)
-                    .ok_or(MathError::Overflow)?
-                    .checked_add(slack)
                     .ok_or(MathError::Overflow)?,
-                starport,
-            )?)?
-            .filter_already_supported(&me.substrate_id, pending_blocks);
+            );
+        }
+        let blocks = next_block.concat(fetch_chain_blocks(
+            chain_id,
+            next_block_number
+                .checked_add(1)
+                .ok_or(MathError::Overflow)?,
+            to_block_number,
+            starport,
+        )?)?;
+        // the batch may not exceed the max events either
+        let blocks =
+            limit_batch(blocks)?.filter_already_supported(&me.substrate_id, pending_blocks);
         memorize_chain_blocks::<T>(&blocks)?;
         submit_chain_blocks::<T>(&blocks)
+    } else if finalized_block_number.is_some() {
+        error!(
+            "Worker sees a different fork below finality: next={:?} last={:?}",
+            next_block, last_block
+        );
+        // the last block was ingested as final, so this can only be a misbehaving node
+        Err(Reason::ReorgBelowFinality)
     } else {
         debug!(
             "Worker sees a different fork: next={:?} last={:?}",
@@ -186,20 +230,56 @@ pub fn track_chain_events_on<T: Config>(chain_id: ChainId) -> Result<(), Reason>
     }
 }
 
+/// Apply an event taken off the ingression queue, depositing whether it succeeded.
+fn apply_queued_event<T: Config>(event: &ChainBlockEvent) {
+    match core::apply_chain_event_internal::<T>(event) {
+        Ok(()) => {
+            <Module<T>>::deposit_event(EventT::ProcessedChainBlockEvent(event.clone()));
+        }
+
+        Err(reason) => {
+            <Module<T>>::deposit_event(EventT::FailedProcessingChainBlockEvent(
+                event.clone(),
+                reason,
+            ));
+        }
+    }
+}
+
 /// Ingress a single round (quota per underlying chain block ingested).
 pub fn ingress_queue<T: Config>(
     last_block: &ChainBlock,
     event_queue: &mut ChainBlockEvents,
 ) -> Result<(), Reason> {
-    let mut available = INGRESS_QUOTA;
+    let config = core::get_ingress_config::<T>(last_block.chain_id());
+    let mut available = config.quota();
     let block_num = last_block.number();
 
+    // Finalized blocks cannot be reorged, so there is no risk to account for
+    if config.finalized_only {
+        event_queue.retain(|event| {
+            apply_queued_event::<T>(event);
+            false // remove from queue
+        });
+        return Ok(());
+    }
+
     event_queue.retain(|event| {
         let delta_blocks = block_num.saturating_sub(event.block_number());
 
-        if delta_blocks >= MIN_EVENT_BLOCKS {
-            // If we're beyond max risk block, then simply accept event
-            let risk_result = if delta_blocks > MAX_EVENT_BLOCKS {
+        if delta_blocks >= config.min_event_blocks {
+            let risk_result = if core::is_chain_event_irreversible(event) {
+                if delta_blocks <= config.max_event_blocks {
+                    debug!(
+                        "Irreversible event not beyond reorg depth (@ {:?}) {:?}",
+                        block_num, event
+                    );
+                    return true; // retain on queue
+                }
+                // An irreversible event can never be undone, so its risk still counts against the quota
+                risk_adjusted_value::<T>(event, block_num)
+            } else if delta_blocks > config.max_event_blocks {
+                // If we're beyond max risk block, then simply accept event
                 Ok(Quantity::new(0, USD))
             } else {
                 risk_adjusted_value::<T>(event, block_num)
@@ -213,37 +293,32 @@ pub fn ingress_queue<T: Config>(
                     );
                     if value <= available {
                         available = available.sub(value).unwrap();
-                        match core::apply_chain_event_internal::<T>(event) {
-                            Ok(()) => {
-                                <Module<T>>::deposit_event(EventT::ProcessedChainBlockEvent(
-                                    event.clone(),
-                                ));
-                            }
-
-                            Err(reason) => {
-                                <Module<T>>::deposit_event(
-                                    EventT::FailedProcessingChainBlockEvent(event.clone(), reason),
-                                );
-                            }
-                        }
+                        apply_queued_event::<T>(event);
                         return false; // remove from queue
                     } else {
                         return true; // retain on queue
                     }
                 }
 
+                Err(Reason::NoPrice) => {
+                    error!(
+                        "Could not compute risk adjusted value (no price) of {:?}",
+                        event
+                    );
+                    // a missing price should be filled in by the oracle, try again next round
+                    return true; // retain on queue
+                }
+
                 Err(err) => {
                     error!(
                         "Could not compute risk adjusted value ({}) of {:?}",
                         err, event
                     );
-                    // note that we keep the event if we cannot compute the risk adjusted value,
-                    //  there's not an obviously more reasonable thing to do right now
                     // there's no reason this should fail normally but it can
                     //  e.g. if somehow someone locks an unsupported asset in the starport
-                    // we need to take separate measures to forcefully limit the queue size
-                    //  e.g. reject new blocks once the event queue reaches a certain size
-                    return true; // retain on queue
+                    // retrying will not help, so get the event out of the way of the rest
+                    dead_letter_event::<T>(event, err);
+                    return false; // remove from queue
                 }
             }
         } else {
@@ -257,6 +332,70 @@ pub fn ingress_queue<T: Config>(
     Ok(())
 }
 
+/// Add the events from a block onto the ingression queue.
+/// Events which are given as already applied are skipped, rather than queued again.
+/// The queue is kept within its limit by not accepting blocks, never by dropping their events.
+pub fn enqueue_block(
+    event_queue: &mut ChainBlockEvents,
+    block: &ChainBlock,
+    applied: &[ChainBlockEvent],
+) {
+    event_queue.push(block);
+    event_queue.retain(|event| !applied.contains(event));
+}
+
+/// Set aside an event which will never be ingressed, so it can be inspected and purged.
+/// Only the most recent dead letters are kept, and each older one is reported as it is dropped.
+fn dead_letter_event<T: Config>(event: &ChainBlockEvent, reason: Reason) {
+    error!("Dead-lettering event ({}) {:?}", reason, event);
+    let mut dead_letters = DeadLetterEvents::get(event.chain_id());
+    dead_letters.push((event.clone(), reason));
+    let excess = dead_letters
+        .len()
+        .saturating_sub(MAX_DEAD_LETTER_EVENTS as usize);
+    let dropped: Vec<(ChainBlockEvent, Reason)> = dead_letters.drain(..excess).collect();
+    DeadLetterEvents::insert(event.chain_id(), dead_letters);
+    <Module<T>>::deposit_event(EventT::DeadLetteredChainBlockEvent(event.clone(), reason));
+    for (dropped_event, dropped_reason) in dropped {
+        error!(
+            "Dropping dead letter ({}) {:?}",
+            dropped_reason, dropped_event
+        );
+        <Module<T>>::deposit_event(EventT::DroppedDeadLetterEvent(
+            dropped_event,
+            dropped_reason,
+        ));
+    }
+}
+
+/// Drop all the dead-lettered events for the given chain.
+pub fn purge_dead_letter_events<T: Config>(chain_id: ChainId) -> Result<(), Reason> {
+    let purged = DeadLetterEvents::take(chain_id);
+    let count = purged.len().try_into().unwrap_or(u32::MAX);
+    <Module<T>>::deposit_event(EventT::PurgedDeadLetterEvents(chain_id, count));
+    Ok(())
+}
+
+/// Put the dead-lettered events for the given chain back onto its ingression queue, oldest first.
+/// Only as many as the queue has room for are requeued, the rest stay dead-lettered.
+pub fn requeue_dead_letter_events<T: Config>(chain_id: ChainId) -> Result<(), Reason> {
+    let mut event_queue = get_event_queue::<T>(chain_id)?;
+    let mut dead_letters = DeadLetterEvents::get(chain_id);
+    let max_queue_events = core::get_ingress_config::<T>(chain_id).max_queue_events as usize;
+    let room = max_queue_events.saturating_sub(event_queue.len());
+    let requeued: Vec<(ChainBlockEvent, Reason)> = dead_letters
+        .drain(..min(room, dead_letters.len()))
+        .collect();
+    let count = requeued.len().try_into().unwrap_or(u32::MAX);
+    for (event, _reason) in requeued {
+        event_queue.push_event(event)?;
+    }
+    IngressionQueue::insert(chain_id, event_queue);
+    DeadLetterEvents::insert(chain_id, dead_letters);
+    <Module<T>>::deposit_event(EventT::RequeuedDeadLetterEvents(chain_id, count));
+    Ok(())
+}
+
 /// Submit the underlying chain blocks the worker calculates are needed by the chain next.
 pub fn submit_chain_blocks<T: Config>(blocks: &ChainBlocks) -> Result<(), Reason> {
     if blocks.len() > 0 {
@@ -299,6 +438,13 @@ pub fn recall_chain_block<T: Config>(
     }
 }
 
+/// Collect blocks from a single chain, in order, failing if they come from more than one.
+fn collect_chain_blocks(blocks: Vec<ChainBlock>) -> Result<ChainBlocks, Reason> {
+    let mut blocks = blocks.into_iter().rev();
+    let last = blocks.next().ok_or(Reason::InvalidChainBlock)?;
+    blocks.try_fold(ChainBlocks::from(last), |acc, block| block.concat(acc))
+}
+
 /// Try to form a path from the last block to the new true block.
 pub fn formulate_reorg<T: Config>(
     chain_id: ChainId,
@@ -316,6 +462,11 @@ pub fn formulate_reorg<T: Config>(
     drawrof_blocks.push(drawrof_block_next.clone());
 
     loop {
+        // a reorg is received all at once, so it may not exceed the max batch size
+        if reverse_blocks.len() >= MAX_CHAIN_BLOCKS as usize {
+            return Err(Reason::ReorgTooDeep);
+        }
+
         // these blocks must be at the same height, or fail
         if reverse_block_next.number() != drawrof_block_next.number() {
             return Err(Reason::BlockMismatch);
@@ -343,28 +494,13 @@ pub fn formulate_reorg<T: Config>(
         }
     }
 
-    match (last_block.hash(), true_block.hash()) {
-        (ChainHash::Eth(from_hash), ChainHash::Eth(to_hash)) => Ok(ChainReorg::Eth {
-            from_hash,
-            to_hash,
-            reverse_blocks: reverse_blocks
-                .into_iter()
-                .filter_map(|b| match b {
-                    ChainBlock::Eth(eth_block) => Some(eth_block),
-                    ChainBlock::Matic(block) => Some(block),
-                })
-                .collect(),
-            forward_blocks: drawrof_blocks
-                .into_iter()
-                .filter_map(|b| match b {
-                    ChainBlock::Eth(eth_block) => Some(eth_block),
-                    ChainBlock::Matic(block) => Some(block),
-                })
-                .collect_rev(),
-        }),
-
-        _ => return Err(Reason::Unreachable),
-    }
+    let reverse_blocks = collect_chain_blocks(reverse_blocks)?;
+    let forward_blocks = collect_chain_blocks(drawrof_blocks.collect_rev())?;
+    require!(
+        fits_in_batch(&reverse_blocks) && fits_in_batch(&forward_blocks),
+        Reason::ReorgTooDeep
+    );
+    chain_reorg(reverse_blocks, forward_blocks)
 }
 
 /// Submit a reorg message from a worker to the chain.
@@ -460,14 +596,26 @@ pub fn receive_chain_blocks<T: Config>(
         }
     }
 
+    let max_queue_events = core::get_ingress_config::<T>(chain_id).max_queue_events as usize;
+    let mut advanced = false;
     for tally in pending_blocks.clone().iter() {
         if tally.has_enough_support(&validator_set) {
+            // back-pressure: leave the block pending if its events don't fit on the queue
+            //  but always advance by one, as the queue only drains while blocks are ingested
+            if advanced && event_queue.len() + tally.block.events().len() > max_queue_events {
+                debug!(
+                    "Event queue is full, not advancing further: {:?}",
+                    last_block
+                );
+                break;
+            }
             // remove tally from block queue
             //  add events to event queue, advance the block, and process a round of events
             pending_blocks.remove(0); // note: tally is first on queue
-            event_queue.push(&tally.block);
+            enqueue_block(&mut event_queue, &tally.block, &[]);
             last_block = tally.block.clone();
             ingress_queue::<T>(&last_block, &mut event_queue)?;
+            advanced = true;
             continue;
         } else if tally.has_enough_dissent(&validator_set) {
             // remove tally and everything after from queue
@@ -500,6 +648,12 @@ pub fn receive_chain_reorg<T: Config>(
     // Note: can reject / stop propagating once this check fails
     require!(reorg.from_hash() == last_block.hash(), Reason::HashMismatch);
 
+    // blocks are only ingested once final, so there is nothing to reorg
+    require!(
+        !core::get_ingress_config::<T>(chain_id).finalized_only,
+        Reason::ReorgBelowFinality
+    );
+
     let tally = if let Some(prior) = pending_reorgs.iter_mut().find(|r| r.reorg == reorg) {
         prior.add_support(&validator);
         prior
@@ -512,24 +666,61 @@ pub fn receive_chain_reorg<T: Config>(
     //  we don't currently keep a tombstone marking that the reorg was recently processed
     if tally.has_enough_support(&validator_set) {
         // if we have enough support, perform actual reorg
+        let forward_events: Vec<ChainBlockEvent> = tally
+            .reorg
+            .forward_blocks()
+            .blocks()
+            .iter()
+            .flat_map(|block| block.events())
+            .collect();
+        let mut dead_letters = DeadLetterEvents::get(chain_id);
+        let mut reincluded = vec![];
+        let mut unapplied = vec![];
+
         // for each block going backwards
         //  remove events from queue, or unapply them if already applied
+        //  unless the same log is included again going forwards, in which case it stays applied
         for block in tally.reorg.reverse_blocks().blocks() {
             for event in block.events() {
                 // Note: this could be made significantly more efficient
                 //  at the cost of significant complexity
                 if let Some(pos) = event_queue.position(&event) {
                     event_queue.remove(pos);
+                } else if let Some(pos) = dead_letters
+                    .iter()
+                    .position(|(e, _reason)| e.is_same_block_log(&event))
+                {
+                    debug!("Removed dead-lettered event on reorg: {:?}", event);
+                    dead_letters.remove(pos);
+                } else if let Some(forward_event) =
+                    forward_events.iter().find(|e| e.is_same_log(&event))
+                {
+                    debug!("Keeping event re-included on reorg: {:?}", event);
+                    reincluded.push(forward_event.clone());
                 } else {
-                    core::unapply_chain_event_internal::<T>(&event)?
+                    unapplied.push(event);
                 }
             }
         }
 
+        // an applied event which cannot be undone halts the reorg, before any state is touched
+        //  the chain stays on the old blocks until governance intervenes
+        if let Some(event) = unapplied
+            .iter()
+            .find(|e| core::is_chain_event_irreversible(e))
+        {
+            error!("Cannot reorg irreversibly applied event: {:?}", event);
+            return Err(Reason::IrreversibleEventReorged);
+        }
+        for event in unapplied {
+            core::unapply_chain_event_internal::<T>(&event)?
+        }
+        DeadLetterEvents::insert(chain_id, dead_letters);
+
         // for each block going forwards
         //  add events to event queue, advance the block, and process a round of events
         for block in tally.reorg.forward_blocks().blocks() {
-            event_queue.push(&block);
+            enqueue_block(&mut event_queue, &block, &reincluded);
             last_block = block.clone();
             ingress_queue::<T>(&last_block, &mut event_queue)?;
         }
@@ -550,8 +741,12 @@ pub fn receive_chain_reorg<T: Config>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tests::*;
-    use ethereum_client::EthereumBlock;
+    use crate::{
+        tests::{common::*, *},
+        types::{EventKind, IngressConfig, RiskModel},
+        IngressConfigs, RiskModels,
+    };
+    use ethereum_client::{EthereumBlock, EthereumEvent, EthereumLogEvent};
 
     fn gen_blocks(start_block: u64, until_block: u64, pad: u8) -> Vec<EthereumBlock> {
         let mut hash = [0u8; 32];
@@ -608,6 +803,27 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_track_chain_events_on_waits_for_finality() {
+        let calls = vec![gen_mock_call_finalized_block(&premined_block())];
+        let (mut t, _, _) = new_test_ext_with_http_calls(calls);
+
+        t.execute_with(|| {
+            initialize_storage();
+            IngressConfigs::insert(
+                ChainId::Eth,
+                IngressConfig {
+                    finalized_only: true,
+                    ..IngressConfig::default()
+                },
+            );
+
+            // the last block is already the finalized one, so there is nothing to submit yet
+            assert_eq!(track_chain_events_on::<Test>(ChainId::Eth), Ok(()));
+            assert_eq!(PendingChainBlocks::get(ChainId::Eth), vec![]);
+        });
+    }
+
     #[test]
     fn test_formulate_reorg() {
         let old_chain: Vec<EthereumBlock> = gen_blocks(0, 10, 0);
@@ -763,21 +979,27 @@ mod tests {
             let reorg_block_hash = [3; 32];
             let real_block_hash = [5; 32];
 
-            let reorg_event = EthereumEvent::Lock {
-                asset: [238; 20],
-                sender: [3; 20],
-                chain: String::from("ETH"),
-                recipient: [4; 32],
-                amount: qty!("10", ETH).value,
-            };
+            let reorg_event = eth_log_event(
+                0,
+                EthereumEvent::Lock {
+                    asset: [238; 20],
+                    sender: [3; 20],
+                    chain: String::from("ETH"),
+                    recipient: [4; 32],
+                    amount: qty!("10", ETH).value,
+                },
+            );
 
-            let real_event = EthereumEvent::Lock {
-                sender: [3; 20],
-                chain: String::from("ETH"),
-                recipient: [5; 32],
-                amount: qty!("9", ETH).value,
-                asset: [238; 20],
-            };
+            let real_event = eth_log_event(
+                0,
+                EthereumEvent::Lock {
+                    sender: [3; 20],
+                    chain: String::from("ETH"),
+                    recipient: [5; 32],
+                    amount: qty!("9", ETH).value,
+                    asset: [238; 20],
+                },
+            );
 
             let reorg_block = ethereum_client::EthereumBlock {
                 hash: reorg_block_hash,
@@ -901,71 +1123,1179 @@ mod tests {
     }
 
     #[test]
-    fn test_collect_rev() {
-        let x = vec![1, 2, 3];
-        let y = x.iter().map(|v| v + 1).collect_rev();
-        assert_eq!(y, vec![4, 3, 2]);
-    }
-
-    #[test]
-    fn test_receive_chain_blocks_fails_for_signed_origin() {
+    fn test_receive_chain_reorg_matic() -> Result<(), Reason> {
         new_test_ext().execute_with(|| {
-            let blocks = ChainBlocks::Eth(vec![]);
-            let signature = ChainSignature::Eth([0u8; 65]);
-            assert_err!(
-                CashModule::receive_chain_blocks(
-                    Origin::signed(Default::default()),
-                    blocks,
-                    signature
-                ),
-                DispatchError::BadOrigin
+            initialize_storage_with_blocks(vec![
+                ChainBlock::Eth(premined_block()),
+                ChainBlock::Matic(premined_block()),
+            ]);
+            let matic_eth = ChainAsset::Matic([0x99; 20]);
+            CashModule::initialize_assets(vec![AssetInfo::minimal(matic_eth, ETH)]);
+            pallet_oracle::Prices::insert(
+                ETH.ticker,
+                Price::from_nominal(ETH.ticker, "2000.00").value,
             );
-        });
-    }
 
-    #[test]
-    fn test_receive_chain_blocks_fails_for_invalid_signature() {
-        new_test_ext().execute_with(|| {
-            let blocks = ChainBlocks::Eth(vec![]);
-            let signature = ChainSignature::Eth([0u8; 65]);
-            assert_err!(
-                CashModule::receive_chain_blocks(Origin::none(), blocks, signature),
-                Reason::CryptoError(gateway_crypto::CryptoError::RecoverError),
+            let lock_event = |recipient, amount| {
+                eth_log_event(
+                    0,
+                    EthereumEvent::Lock {
+                        asset: [0x99; 20],
+                        sender: [3; 20],
+                        chain: String::from("ETH"),
+                        recipient,
+                        amount,
+                    },
+                )
+            };
+            let reorg_event = lock_event([4; 32], qty!("10", ETH).value);
+            let real_event = lock_event([5; 32], qty!("9", ETH).value);
+            let mut old_chain = gen_blocks(2, 6, 2);
+            old_chain[0].parent_hash = premined_block().hash;
+            old_chain[0].events = vec![reorg_event.clone()];
+            let mut new_chain = gen_blocks(2, 6, 3);
+            new_chain[0].parent_hash = premined_block().hash;
+            new_chain[0].events = vec![real_event.clone()];
+
+            // the event is applied once the blocks after it are received
+            assert_ok!(all_receive_chain_blocks(&ChainBlocks::Matic(
+                old_chain.clone()
+            )));
+            assert_eq!(
+                AssetBalances::get(&matic_eth, ChainAccount::Eth([4; 20])),
+                bal!("10", ETH).value
             );
-        });
-    }
 
-    #[test]
-    fn test_receive_chain_blocks_fails_if_not_validator() {
-        new_test_ext().execute_with(|| {
-            let blocks = ChainBlocks::Eth(vec![]);
-            let signature = ChainId::Eth.sign(&blocks.encode()).unwrap();
-            assert_err!(
-                CashModule::receive_chain_blocks(Origin::none(), blocks, signature),
-                Reason::UnknownValidator
+            let reorg = chain_reorg(
+                ChainBlocks::Matic(old_chain.iter().rev().cloned().collect()),
+                ChainBlocks::Matic(new_chain[..1].to_vec()),
+            )?;
+            assert_eq!(
+                reorg,
+                ChainReorg::Matic {
+                    from_hash: old_chain[3].hash,
+                    to_hash: new_chain[0].hash,
+                    reverse_blocks: old_chain.iter().rev().cloned().collect(),
+                    forward_blocks: new_chain[..1].to_vec(),
+                }
             );
-        });
+
+            // the old event is reverted and the new one is queued
+            assert_ok!(a_receive_chain_reorg(&reorg), ());
+            assert_ok!(b_receive_chain_reorg(&reorg), ());
+            assert_eq!(
+                LastProcessedBlock::get(ChainId::Matic),
+                Some(ChainBlock::Matic(new_chain[0].clone()))
+            );
+            assert_eq!(
+                get_event_queue::<Test>(ChainId::Matic)?,
+                ChainBlockEvents::Matic(vec![(2, real_event)])
+            );
+            assert_eq!(
+                AssetBalances::get(&matic_eth, ChainAccount::Eth([4; 20])),
+                bal!("0", ETH).value
+            );
+
+            // and the new event is applied once the blocks after it are received
+            assert_ok!(all_receive_chain_blocks(&ChainBlocks::Matic(
+                new_chain[1..].to_vec()
+            )));
+            assert_eq!(
+                AssetBalances::get(&matic_eth, ChainAccount::Eth([5; 20])),
+                bal!("9", ETH).value
+            );
+
+            Ok(())
+        })
     }
 
     #[test]
-    fn test_receive_chain_blocks_happy_path() -> Result<(), Reason> {
-        sp_tracing::try_init_simple(); // Tip: add/remove from tests for logging
-
+    fn test_receive_chain_reorg_keeps_reincluded_event() -> Result<(), Reason> {
         new_test_ext().execute_with(|| {
             initialize_storage();
-
             pallet_oracle::Prices::insert(
                 ETH.ticker,
                 Price::from_nominal(ETH.ticker, "2000.00").value,
             );
 
-            let event = ethereum_client::EthereumEvent::Lock {
-                asset: [238; 20],
-                sender: [3; 20],
-                chain: String::from("ETH"),
-                recipient: [2; 32],
-                amount: qty!("75", ETH).value,
+            let reorg_block_hash = [3; 32];
+            let real_block_hash = [5; 32];
+
+            let event = eth_log_event(
+                0,
+                EthereumEvent::Lock {
+                    asset: [238; 20],
+                    sender: [3; 20],
+                    chain: String::from("ETH"),
+                    recipient: [4; 32],
+                    amount: qty!("10", ETH).value,
+                },
+            );
+
+            let reorg_block = ethereum_client::EthereumBlock {
+                hash: reorg_block_hash,
+                parent_hash: premined_block().hash,
+                number: 2,
+                events: vec![event.clone()],
+            };
+
+            // the transaction lands at a different log index in the new block
+            let real_block = ethereum_client::EthereumBlock {
+                hash: real_block_hash,
+                parent_hash: premined_block().hash,
+                number: 2,
+                events: vec![EthereumLogEvent {
+                    log_index: 3,
+                    ..event.clone()
+                }],
+            };
+
+            let latest_hash = [10; 32];
+            let blocks_3 = ChainBlocks::Eth(vec![
+                ethereum_client::EthereumBlock {
+                    hash: [3; 32],
+                    parent_hash: reorg_block_hash,
+                    number: 3,
+                    events: vec![],
+                },
+                ethereum_client::EthereumBlock {
+                    hash: [4; 32],
+                    parent_hash: [3; 32],
+                    number: 4,
+                    events: vec![],
+                },
+                ethereum_client::EthereumBlock {
+                    hash: latest_hash,
+                    parent_hash: [4; 32],
+                    number: 5,
+                    events: vec![],
+                },
+            ]);
+
+            let reorg = ChainReorg::Eth {
+                from_hash: latest_hash,
+                to_hash: real_block_hash,
+                reverse_blocks: vec![reorg_block.clone()],
+                forward_blocks: vec![real_block.clone()],
             };
+
+            assert_ok!(all_receive_chain_blocks(&ChainBlocks::Eth(vec![
+                reorg_block
+            ])));
+            assert_ok!(all_receive_chain_blocks(&blocks_3));
+            assert_eq!(
+                AssetBalances::get(&Eth, ChainAccount::Eth([4; 20])),
+                bal!("10", ETH).value
+            );
+
+            // the same log is included again by the new chain, so it stays applied and isn't queued
+            assert_ok!(a_receive_chain_reorg(&reorg), ());
+            assert_ok!(b_receive_chain_reorg(&reorg), ());
+            assert_eq!(
+                LastProcessedBlock::get(ChainId::Eth),
+                Some(ChainBlock::Eth(real_block))
+            );
+            assert_eq!(
+                get_event_queue::<Test>(ChainId::Eth)?,
+                ChainBlockEvents::Eth(vec![])
+            );
+            assert_eq!(
+                AssetBalances::get(&Eth, ChainAccount::Eth([4; 20])),
+                bal!("10", ETH).value
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_receive_chain_reorg_drops_held_irreversible_events() -> Result<(), Reason> {
+        new_test_ext().execute_with(|| {
+            initialize_storage();
+
+            let reorg_block = EthereumBlock {
+                hash: [3; 32],
+                parent_hash: premined_block().hash,
+                number: 2,
+                events: vec![
+                    eth_log_event(
+                        0,
+                        EthereumEvent::ExecTrxRequest {
+                            account: [3; 20],
+                            trx_request: String::from(
+                                "(Extract 100 ETH Eth:0x0303030303030303030303030303030303030303)",
+                            ),
+                        },
+                    ),
+                    eth_log_event(
+                        1,
+                        EthereumEvent::ExecuteProposal {
+                            title: String::from("Proposal"),
+                            extrinsics: vec![],
+                        },
+                    ),
+                ],
+            };
+            let real_block = EthereumBlock {
+                hash: [5; 32],
+                parent_hash: premined_block().hash,
+                number: 2,
+                events: vec![],
+            };
+
+            // irreversible events are held on the queue until beyond reorg depth
+            assert_ok!(all_receive_chain_blocks(&ChainBlocks::Eth(vec![
+                reorg_block.clone()
+            ])));
+            assert_eq!(
+                get_event_queue::<Test>(ChainId::Eth)?,
+                ChainBlockEvents::Eth(reorg_block.events.iter().map(|e| (2, e.clone())).collect())
+            );
+
+            // so reorging them just takes them off the queue
+            let reorg = ChainReorg::Eth {
+                from_hash: reorg_block.hash,
+                to_hash: real_block.hash,
+                reverse_blocks: vec![reorg_block],
+                forward_blocks: vec![real_block.clone()],
+            };
+            assert_ok!(a_receive_chain_reorg(&reorg), ());
+            assert_ok!(b_receive_chain_reorg(&reorg), ());
+            assert_eq!(
+                LastProcessedBlock::get(ChainId::Eth),
+                Some(ChainBlock::Eth(real_block))
+            );
+            assert_eq!(
+                get_event_queue::<Test>(ChainId::Eth)?,
+                ChainBlockEvents::Eth(vec![])
+            );
+            assert_eq!(DeadLetterEvents::get(ChainId::Eth), vec![]);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_receive_chain_reorg_halts_on_applied_irreversible_event() -> Result<(), Reason> {
+        new_test_ext().execute_with(|| {
+            initialize_storage();
+            pallet_oracle::Prices::insert(
+                ETH.ticker,
+                Price::from_nominal(ETH.ticker, "2000.00").value,
+            );
+
+            let reorg_block = EthereumBlock {
+                hash: [3; 32],
+                parent_hash: premined_block().hash,
+                number: 2,
+                events: vec![
+                    eth_log_event(
+                        0,
+                        EthereumEvent::Lock {
+                            asset: [238; 20],
+                            sender: [3; 20],
+                            chain: String::from("ETH"),
+                            recipient: [4; 32],
+                            amount: qty!("10", ETH).value,
+                        },
+                    ),
+                    eth_log_event(
+                        1,
+                        EthereumEvent::ExecuteProposal {
+                            title: String::from("Proposal"),
+                            extrinsics: vec![],
+                        },
+                    ),
+                ],
+            };
+            let real_block = EthereumBlock {
+                hash: [5; 32],
+                parent_hash: premined_block().hash,
+                number: 2,
+                events: vec![],
+            };
+
+            assert_ok!(all_receive_chain_blocks(&ChainBlocks::Eth(vec![
+                reorg_block.clone()
+            ])));
+            assert_eq!(
+                AssetBalances::get(&Eth, ChainAccount::Eth([4; 20])),
+                bal!("10", ETH).value
+            );
+
+            // as if the proposal had been applied once beyond reorg depth
+            IngressionQueue::insert(ChainId::Eth, ChainBlockEvents::Eth(vec![]));
+
+            let reorg = ChainReorg::Eth {
+                from_hash: reorg_block.hash,
+                to_hash: real_block.hash,
+                reverse_blocks: vec![reorg_block.clone()],
+                forward_blocks: vec![real_block],
+            };
+            assert_ok!(a_receive_chain_reorg(&reorg), ());
+            let signature = validator_b_sign(&reorg.encode())?;
+            assert_eq!(
+                receive_chain_reorg::<Test>(reorg, signature),
+                Err(Reason::IrreversibleEventReorged)
+            );
+
+            // nothing is un-applied, not even the reversible lock
+            assert_eq!(
+                LastProcessedBlock::get(ChainId::Eth),
+                Some(ChainBlock::Eth(reorg_block))
+            );
+            assert_eq!(
+                AssetBalances::get(&Eth, ChainAccount::Eth([4; 20])),
+                bal!("10", ETH).value
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_receive_chain_reorg_keeps_invoked_notice() -> Result<(), Reason> {
+        new_test_ext().execute_with(|| {
+            initialize_storage();
+            let notice_id = NoticeId(0, 1);
+            NoticeHashes::insert(ChainHash::Eth([7; 32]), notice_id);
+
+            let reorg_block = EthereumBlock {
+                hash: [3; 32],
+                parent_hash: premined_block().hash,
+                number: 2,
+                events: vec![eth_log_event(
+                    0,
+                    EthereumEvent::NoticeInvoked {
+                        era_id: 0,
+                        era_index: 1,
+                        notice_hash: [7; 32],
+                        result: vec![],
+                    },
+                )],
+            };
+            let real_block = EthereumBlock {
+                hash: [5; 32],
+                parent_hash: premined_block().hash,
+                number: 2,
+                events: vec![],
+            };
+
+            assert_ok!(all_receive_chain_blocks(&ChainBlocks::Eth(vec![
+                reorg_block.clone()
+            ])));
+            assert_eq!(
+                NoticeStates::get(ChainId::Eth, notice_id),
+                NoticeState::Executed
+            );
+
+            // the notice is expected to be invoked again on the new chain, so it stays executed
+            let reorg = ChainReorg::Eth {
+                from_hash: reorg_block.hash,
+                to_hash: real_block.hash,
+                reverse_blocks: vec![reorg_block],
+                forward_blocks: vec![real_block.clone()],
+            };
+            assert_ok!(a_receive_chain_reorg(&reorg), ());
+            assert_ok!(b_receive_chain_reorg(&reorg), ());
+            assert_eq!(
+                LastProcessedBlock::get(ChainId::Eth),
+                Some(ChainBlock::Eth(real_block))
+            );
+            assert_eq!(
+                NoticeStates::get(ChainId::Eth, notice_id),
+                NoticeState::Executed
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_queue_slack() {
+        let event_queue = ChainBlockEvents::Eth(vec![
+            (
+                1,
+                eth_log_event(
+                    0,
+                    EthereumEvent::NoticeInvoked {
+                        era_id: 0,
+                        era_index: 0,
+                        notice_hash: [0; 32],
+                        result: vec![],
+                    },
+                ),
+            ),
+            (
+                2,
+                eth_log_event(
+                    1,
+                    EthereumEvent::NoticeInvoked {
+                        era_id: 0,
+                        era_index: 1,
+                        notice_hash: [1; 32],
+                        result: vec![],
+                    },
+                ),
+            ),
+        ]);
+        assert_eq!(queue_slack(&event_queue, 50), 48);
+        assert_eq!(queue_slack(&event_queue, 2), 1);
+    }
+
+    #[test]
+    fn test_ingress_queue_uses_chain_config() -> Result<(), Reason> {
+        new_test_ext().execute_with(|| {
+            initialize_storage();
+
+            let event = eth_log_event(
+                0,
+                EthereumEvent::NoticeInvoked {
+                    era_id: 0,
+                    era_index: 0,
+                    notice_hash: [0; 32],
+                    result: vec![],
+                },
+            );
+            let last_block = ChainBlock::Eth(EthereumBlock {
+                hash: [6; 32],
+                parent_hash: [5; 32],
+                number: 6,
+                events: vec![],
+            });
+
+            // Deep enough for the default config, but not for this chain
+            IngressConfigs::insert(
+                ChainId::Eth,
+                IngressConfig {
+                    min_event_blocks: 10,
+                    ..IngressConfig::default()
+                },
+            );
+            let mut event_queue = ChainBlockEvents::Eth(vec![(2, event.clone())]);
+            ingress_queue::<Test>(&last_block, &mut event_queue)?;
+            assert_eq!(event_queue.len(), 1);
+
+            IngressConfigs::remove(ChainId::Eth);
+            ingress_queue::<Test>(&last_block, &mut event_queue)?;
+            assert_eq!(event_queue.len(), 0);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_ingress_queue_dead_letters_unprocessable() -> Result<(), Reason> {
+        new_test_ext().execute_with(|| {
+            initialize_storage();
+
+            let lock = eth_log_event(
+                0,
+                EthereumEvent::Lock {
+                    asset: [1; 20],
+                    sender: [3; 20],
+                    chain: String::from("ETH"),
+                    recipient: [2; 32],
+                    amount: 100,
+                },
+            );
+            let last_block = ChainBlock::Eth(EthereumBlock {
+                hash: [6; 32],
+                parent_hash: [5; 32],
+                number: 6,
+                events: vec![],
+            });
+
+            let mut event_queue = ChainBlockEvents::Eth(vec![(2, lock.clone())]);
+            ingress_queue::<Test>(&last_block, &mut event_queue)?;
+            assert_eq!(event_queue.len(), 0);
+            assert_eq!(
+                DeadLetterEvents::get(ChainId::Eth),
+                vec![(ChainBlockEvent::Eth(2, lock), Reason::AssetNotSupported)]
+            );
+
+            assert_eq!(purge_dead_letter_events::<Test>(ChainId::Eth), Ok(()));
+            assert_eq!(DeadLetterEvents::get(ChainId::Eth), vec![]);
+            assert_eq!(
+                System::events().into_iter().last().unwrap().event,
+                mock::Event::pallet_cash(crate::Event::PurgedDeadLetterEvents(ChainId::Eth, 1))
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_ingress_queue_holds_irreversible_events() -> Result<(), Reason> {
+        new_test_ext().execute_with(|| {
+            initialize_storage();
+
+            let notice_invoked = eth_log_event(
+                0,
+                EthereumEvent::NoticeInvoked {
+                    era_id: 0,
+                    era_index: 0,
+                    notice_hash: [0; 32],
+                    result: vec![],
+                },
+            );
+            let exec_trx_request = eth_log_event(
+                1,
+                EthereumEvent::ExecTrxRequest {
+                    account: [3; 20],
+                    trx_request: String::from(
+                        "(Extract 1 ETH Eth:0x0303030303030303030303030303030303030303)",
+                    ),
+                },
+            );
+            let execute_proposal = eth_log_event(
+                2,
+                EthereumEvent::ExecuteProposal {
+                    title: String::from("Proposal"),
+                    extrinsics: vec![],
+                },
+            );
+            let mut event_queue = ChainBlockEvents::Eth(vec![
+                (2, notice_invoked),
+                (2, exec_trx_request.clone()),
+                (2, execute_proposal.clone()),
+            ]);
+
+            // Deep enough to apply the reversible event, but not the irreversible ones
+            let last_block = ChainBlock::Eth(EthereumBlock {
+                hash: [6; 32],
+                parent_hash: [5; 32],
+                number: 6,
+                events: vec![],
+            });
+            ingress_queue::<Test>(&last_block, &mut event_queue)?;
+            assert_eq!(
+                event_queue,
+                ChainBlockEvents::Eth(vec![(2, exec_trx_request), (2, execute_proposal)])
+            );
+
+            // Beyond reorg depth
+            let last_block = ChainBlock::Eth(EthereumBlock {
+                hash: [70; 32],
+                parent_hash: [69; 32],
+                number: 70,
+                events: vec![],
+            });
+            ingress_queue::<Test>(&last_block, &mut event_queue)?;
+            assert_eq!(event_queue.len(), 0);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_ingress_queue_applies_unparseable_trx_request() -> Result<(), Reason> {
+        new_test_ext().execute_with(|| {
+            initialize_storage();
+
+            let bad_trx_request = eth_log_event(
+                0,
+                EthereumEvent::ExecTrxRequest {
+                    account: [2; 20],
+                    trx_request: String::from("(Extract)"),
+                },
+            );
+            let mut event_queue = ChainBlockEvents::Eth(vec![(2, bad_trx_request.clone())]);
+
+            // the request is applied and fails as usual, rather than being dead-lettered
+            let last_block = ChainBlock::Eth(EthereumBlock {
+                hash: [63; 32],
+                parent_hash: [62; 32],
+                number: 63,
+                events: vec![],
+            });
+            ingress_queue::<Test>(&last_block, &mut event_queue)?;
+            assert_eq!(event_queue.len(), 0);
+            assert_eq!(DeadLetterEvents::get(ChainId::Eth), vec![]);
+            match System::events().into_iter().last().unwrap().event {
+                mock::Event::pallet_cash(crate::Event::FailedProcessingChainBlockEvent(
+                    event,
+                    Reason::TrxRequestParseError(_),
+                )) => assert_eq!(event, ChainBlockEvent::Eth(2, bad_trx_request)),
+                _ => unreachable!(),
+            }
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_ingress_queue_charges_irreversible_events() -> Result<(), Reason> {
+        new_test_ext().execute_with(|| {
+            initialize_storage();
+            let asset = init_eth_asset()?;
+            let account = ChainAccount::Eth([2; 20]);
+            init_asset_balance(asset, account, Balance::from_nominal("2", ETH).value);
+
+            let exec_trx_request = eth_log_event(
+                0,
+                EthereumEvent::ExecTrxRequest {
+                    account: [2; 20],
+                    trx_request: String::from("(Extract 1000000000000000000 Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee Eth:0x0101010101010101010101010101010101010101)"),
+                },
+            );
+            let unexecutable_trx_request = eth_log_event(
+                1,
+                EthereumEvent::ExecTrxRequest {
+                    account: [2; 20],
+                    trx_request: String::from("(Extract 3000000000000000000 Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee Eth:0x0101010101010101010101010101010101010101)"),
+                },
+            );
+            let mut event_queue = ChainBlockEvents::Eth(vec![
+                (2, exec_trx_request.clone()),
+                (2, unexecutable_trx_request.clone()),
+            ]);
+
+            // $2000 moved, scaled to $20000, does not fit within the $10000 quota
+            RiskModels::insert(
+                EventKind::ExecTrxRequest,
+                RiskModel {
+                    factor: Factor::from_nominal("10"),
+                    half_life: 100,
+                },
+            );
+            let last_block = ChainBlock::Eth(EthereumBlock {
+                hash: [63; 32],
+                parent_hash: [62; 32],
+                number: 63,
+                events: vec![],
+            });
+            ingress_queue::<Test>(&last_block, &mut event_queue)?;
+            assert_eq!(
+                event_queue,
+                ChainBlockEvents::Eth(vec![(2, exec_trx_request.clone())])
+            );
+            // a request which cannot be valued moves nothing, and fails when applied
+            match System::events().into_iter().last().unwrap().event {
+                mock::Event::pallet_cash(crate::Event::FailedProcessingChainBlockEvent(
+                    event,
+                    _reason,
+                )) => assert_eq!(event, ChainBlockEvent::Eth(2, unexecutable_trx_request)),
+                _ => unreachable!(),
+            }
+            assert_eq!(DeadLetterEvents::get(ChainId::Eth), vec![]);
+
+            // Once decayed to $10000 it fits
+            let last_block = ChainBlock::Eth(EthereumBlock {
+                hash: [102; 32],
+                parent_hash: [101; 32],
+                number: 102,
+                events: vec![],
+            });
+            ingress_queue::<Test>(&last_block, &mut event_queue)?;
+            assert_eq!(event_queue.len(), 0);
+            assert_eq!(
+                System::events().into_iter().last().unwrap().event,
+                mock::Event::pallet_cash(crate::Event::ProcessedChainBlockEvent(
+                    ChainBlockEvent::Eth(2, exec_trx_request)
+                ))
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_risk_adjusted_value_uses_risk_model() -> Result<(), Reason> {
+        new_test_ext().execute_with(|| {
+            initialize_storage();
+            let asset = init_eth_asset()?;
+            let account = ChainAccount::Eth([2; 20]);
+            init_asset_balance(asset, account, Balance::from_nominal("2", ETH).value);
+
+            let lock = ChainBlockEvent::Eth(
+                2,
+                eth_log_event(
+                    0,
+                    EthereumEvent::Lock {
+                        asset: [238; 20],
+                        sender: [3; 20],
+                        chain: String::from("ETH"),
+                        recipient: [4; 32],
+                        amount: qty!("1", ETH).value,
+                    },
+                ),
+            );
+            let exec_trx_request = ChainBlockEvent::Eth(
+                2,
+                eth_log_event(
+                    1,
+                    EthereumEvent::ExecTrxRequest {
+                        account: [2; 20],
+                        trx_request: String::from("(Extract 1000000000000000000 Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee Eth:0x0101010101010101010101010101010101010101)"),
+                    },
+                ),
+            );
+
+            // The default model halves the value with every block
+            assert_eq!(risk_adjusted_value::<Test>(&lock, 3), Ok(qty!("1000", USD)));
+            assert_eq!(
+                risk_adjusted_value::<Test>(&exec_trx_request, 3),
+                Ok(qty!("1000", USD))
+            );
+
+            RiskModels::insert(
+                EventKind::Lock(asset),
+                RiskModel {
+                    factor: Factor::from_nominal("1.5"),
+                    half_life: 2,
+                },
+            );
+            assert_eq!(risk_adjusted_value::<Test>(&lock, 3), Ok(qty!("3000", USD)));
+            assert_eq!(risk_adjusted_value::<Test>(&lock, 4), Ok(qty!("1500", USD)));
+            assert_eq!(
+                risk_adjusted_value::<Test>(&exec_trx_request, 3),
+                Ok(qty!("1000", USD))
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_ingress_queue_ordered_by_risk_model() -> Result<(), Reason> {
+        new_test_ext().execute_with(|| {
+            initialize_storage();
+            let eth_asset = init_eth_asset()?;
+            init_wbtc_asset()?;
+
+            let eth_lock = eth_log_event(
+                0,
+                EthereumEvent::Lock {
+                    asset: [238; 20],
+                    sender: [3; 20],
+                    chain: String::from("ETH"),
+                    recipient: [4; 32],
+                    amount: qty!("40", ETH).value,
+                },
+            );
+            let wbtc_lock = eth_log_event(
+                1,
+                EthereumEvent::Lock {
+                    asset: [187; 20],
+                    sender: [3; 20],
+                    chain: String::from("ETH"),
+                    recipient: [4; 32],
+                    amount: qty!("1", WBTC).value,
+                },
+            );
+            let last_block = ChainBlock::Eth(EthereumBlock {
+                hash: [6; 32],
+                parent_hash: [5; 32],
+                number: 6,
+                events: vec![],
+            });
+
+            // $80000 and $60000 decayed over 4 blocks both fit within the $10000 quota
+            let mut event_queue =
+                ChainBlockEvents::Eth(vec![(2, eth_lock.clone()), (2, wbtc_lock.clone())]);
+            ingress_queue::<Test>(&last_block, &mut event_queue)?;
+            assert_eq!(event_queue.len(), 0);
+
+            // Treating ETH as more volatile leaves it waiting, while WBTC goes ahead of it
+            RiskModels::insert(
+                EventKind::Lock(eth_asset),
+                RiskModel {
+                    factor: Factor::from_nominal("3"),
+                    half_life: 1,
+                },
+            );
+            let mut event_queue =
+                ChainBlockEvents::Eth(vec![(2, eth_lock.clone()), (2, wbtc_lock.clone())]);
+            ingress_queue::<Test>(&last_block, &mut event_queue)?;
+            assert_eq!(
+                event_queue,
+                ChainBlockEvents::Eth(vec![(2, eth_lock.clone())])
+            );
+
+            // As does decaying ETH more slowly
+            RiskModels::insert(
+                EventKind::Lock(eth_asset),
+                RiskModel {
+                    factor: Factor::ONE,
+                    half_life: 2,
+                },
+            );
+            let mut event_queue =
+                ChainBlockEvents::Eth(vec![(2, eth_lock.clone()), (2, wbtc_lock)]);
+            ingress_queue::<Test>(&last_block, &mut event_queue)?;
+            assert_eq!(event_queue, ChainBlockEvents::Eth(vec![(2, eth_lock)]));
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_ingress_queue_finalized_only() -> Result<(), Reason> {
+        new_test_ext().execute_with(|| {
+            initialize_storage();
+            pallet_oracle::Prices::insert(
+                ETH.ticker,
+                Price::from_nominal(ETH.ticker, "2000.00").value,
+            );
+
+            let lock = eth_log_event(
+                0,
+                EthereumEvent::Lock {
+                    asset: [238; 20],
+                    sender: [3; 20],
+                    chain: String::from("ETH"),
+                    recipient: [4; 32],
+                    amount: qty!("1000000", ETH).value,
+                },
+            );
+            let last_block = ChainBlock::Eth(EthereumBlock {
+                hash: [6; 32],
+                parent_hash: [5; 32],
+                number: 6,
+                events: vec![],
+            });
+
+            // Neither deep enough nor within the quota, but final
+            IngressConfigs::insert(
+                ChainId::Eth,
+                IngressConfig {
+                    min_event_blocks: 10,
+                    finalized_only: true,
+                    ..IngressConfig::default()
+                },
+            );
+            let mut event_queue = ChainBlockEvents::Eth(vec![(6, lock.clone())]);
+            ingress_queue::<Test>(&last_block, &mut event_queue)?;
+            assert_eq!(event_queue.len(), 0);
+            assert_eq!(
+                AssetBalances::get(&Eth, ChainAccount::Eth([4; 20])),
+                bal!("1000000", ETH).value
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_receive_chain_reorg_below_finality() -> Result<(), Reason> {
+        new_test_ext().execute_with(|| {
+            initialize_storage();
+            IngressConfigs::insert(
+                ChainId::Eth,
+                IngressConfig {
+                    finalized_only: true,
+                    ..IngressConfig::default()
+                },
+            );
+
+            let block = EthereumBlock {
+                hash: [2; 32],
+                parent_hash: premined_block().hash,
+                number: 2,
+                events: vec![],
+            };
+            assert_ok!(all_receive_chain_blocks(&ChainBlocks::Eth(vec![
+                block.clone()
+            ])));
+
+            let reorg = ChainReorg::Eth {
+                from_hash: block.hash,
+                to_hash: [3; 32],
+                reverse_blocks: vec![block.clone()],
+                forward_blocks: vec![EthereumBlock {
+                    hash: [3; 32],
+                    parent_hash: premined_block().hash,
+                    number: 2,
+                    events: vec![],
+                }],
+            };
+            let signature = validator_a_sign(&reorg.encode())?;
+            assert_eq!(
+                receive_chain_reorg::<Test>(reorg, signature),
+                Err(Reason::ReorgBelowFinality)
+            );
+            assert_eq!(PendingChainReorgs::get(ChainId::Eth), vec![]);
+            assert_eq!(
+                LastProcessedBlock::get(ChainId::Eth),
+                Some(ChainBlock::Eth(block))
+            );
+
+            Ok(())
+        })
+    }
+
+    fn notice_invoked_events(count: u32) -> Vec<EthereumLogEvent> {
+        (0..count)
+            .map(|i| {
+                eth_log_event(
+                    i as u64,
+                    EthereumEvent::NoticeInvoked {
+                        era_id: 0,
+                        era_index: i,
+                        notice_hash: [i as u8; 32],
+                        result: vec![],
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_enqueue_block_never_dead_letters() {
+        new_test_ext().execute_with(|| {
+            IngressConfigs::insert(
+                ChainId::Eth,
+                IngressConfig {
+                    max_queue_events: 2,
+                    ..IngressConfig::default()
+                },
+            );
+
+            let events = notice_invoked_events(3);
+            let block = ChainBlock::Eth(EthereumBlock {
+                hash: [2; 32],
+                parent_hash: [1; 32],
+                number: 2,
+                events: events.clone(),
+            });
+
+            let mut event_queue = ChainBlockEvents::Eth(vec![]);
+            enqueue_block(
+                &mut event_queue,
+                &block,
+                &[ChainBlockEvent::Eth(2, events[1].clone())],
+            );
+            assert_eq!(
+                event_queue,
+                ChainBlockEvents::Eth(vec![(2, events[0].clone()), (2, events[2].clone())])
+            );
+            assert_eq!(DeadLetterEvents::get(ChainId::Eth), vec![]);
+        });
+    }
+
+    #[test]
+    fn test_receive_chain_blocks_back_pressure() -> Result<(), Reason> {
+        new_test_ext().execute_with(|| {
+            initialize_storage();
+            IngressConfigs::insert(
+                ChainId::Eth,
+                IngressConfig {
+                    max_queue_events: 3,
+                    ..IngressConfig::default()
+                },
+            );
+
+            let events = notice_invoked_events(4);
+            let blocks = ChainBlocks::Eth(vec![
+                EthereumBlock {
+                    hash: [2; 32],
+                    parent_hash: premined_block().hash,
+                    number: 2,
+                    events: events[..2].to_vec(),
+                },
+                EthereumBlock {
+                    hash: [3; 32],
+                    parent_hash: [2; 32],
+                    number: 3,
+                    events: events[2..].to_vec(),
+                },
+            ]);
+
+            // the second block does not fit on the queue, so it is left pending
+            assert_ok!(all_receive_chain_blocks(&blocks));
+            assert_eq!(get_last_block::<Test>(ChainId::Eth)?.number(), 2);
+            assert_eq!(get_event_queue::<Test>(ChainId::Eth)?.len(), 2);
+            assert_eq!(PendingChainBlocks::get(ChainId::Eth).len(), 1);
+            assert_eq!(DeadLetterEvents::get(ChainId::Eth), vec![]);
+
+            // but the next block is always taken, as the queue only drains as blocks come in
+            assert_ok!(all_receive_chain_blocks(&blocks));
+            assert_eq!(get_last_block::<Test>(ChainId::Eth)?.number(), 3);
+            assert_eq!(get_event_queue::<Test>(ChainId::Eth)?.len(), 4);
+            assert_eq!(PendingChainBlocks::get(ChainId::Eth).len(), 0);
+            assert_eq!(DeadLetterEvents::get(ChainId::Eth), vec![]);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_dead_letter_events_bounded() {
+        new_test_ext().execute_with(|| {
+            let events: Vec<ChainBlockEvent> = notice_invoked_events(MAX_DEAD_LETTER_EVENTS + 1)
+                .into_iter()
+                .map(|event| ChainBlockEvent::Eth(2, event))
+                .collect();
+            for event in events.iter() {
+                dead_letter_event::<Test>(event, Reason::AssetNotSupported);
+            }
+
+            let dead_letters = DeadLetterEvents::get(ChainId::Eth);
+            assert_eq!(dead_letters.len(), MAX_DEAD_LETTER_EVENTS as usize);
+            assert_eq!(dead_letters[0].0, events[1]);
+            assert_eq!(
+                dead_letters.last().unwrap().0,
+                events[MAX_DEAD_LETTER_EVENTS as usize]
+            );
+            assert_eq!(
+                System::events().into_iter().last().unwrap().event,
+                mock::Event::pallet_cash(crate::Event::DroppedDeadLetterEvent(
+                    events[0].clone(),
+                    Reason::AssetNotSupported
+                ))
+            );
+        });
+    }
+
+    #[test]
+    fn test_requeue_dead_letter_events() -> Result<(), Reason> {
+        new_test_ext().execute_with(|| {
+            initialize_storage();
+            IngressConfigs::insert(
+                ChainId::Eth,
+                IngressConfig {
+                    max_queue_events: 2,
+                    ..IngressConfig::default()
+                },
+            );
+            let events = notice_invoked_events(3);
+            IngressionQueue::insert(
+                ChainId::Eth,
+                ChainBlockEvents::Eth(vec![(3, events[0].clone())]),
+            );
+            dead_letter_event::<Test>(
+                &ChainBlockEvent::Eth(2, events[1].clone()),
+                Reason::AssetNotSupported,
+            );
+            dead_letter_event::<Test>(
+                &ChainBlockEvent::Eth(2, events[2].clone()),
+                Reason::AssetNotSupported,
+            );
+
+            // only the oldest dead letter fits on the queue, the other stays set aside
+            assert_eq!(requeue_dead_letter_events::<Test>(ChainId::Eth), Ok(()));
+            assert_eq!(
+                DeadLetterEvents::get(ChainId::Eth),
+                vec![(
+                    ChainBlockEvent::Eth(2, events[2].clone()),
+                    Reason::AssetNotSupported
+                )]
+            );
+            assert_eq!(
+                IngressionQueue::get(ChainId::Eth),
+                Some(ChainBlockEvents::Eth(vec![
+                    (3, events[0].clone()),
+                    (2, events[1].clone())
+                ]))
+            );
+            assert_eq!(
+                System::events().into_iter().last().unwrap().event,
+                mock::Event::pallet_cash(crate::Event::RequeuedDeadLetterEvents(ChainId::Eth, 1))
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_limit_batch() -> Result<(), Reason> {
+        let events = notice_invoked_events(MAX_CHAIN_BLOCK_EVENTS + 1);
+        let mut blocks = gen_blocks(1, 4, 0);
+        blocks[0].events = events[..1].to_vec();
+        blocks[1].events = events[1..MAX_CHAIN_BLOCK_EVENTS as usize].to_vec();
+        blocks[2].events = events[MAX_CHAIN_BLOCK_EVENTS as usize..].to_vec();
+
+        // the blocks up to the limit are kept
+        assert_eq!(
+            limit_batch(ChainBlocks::Eth(blocks.clone()))?,
+            ChainBlocks::Eth(blocks[..2].to_vec())
+        );
+
+        // but the first block always is
+        assert_eq!(
+            limit_batch(ChainBlocks::Eth(vec![EthereumBlock {
+                events: events.clone(),
+                ..blocks[0].clone()
+            }]))?,
+            ChainBlocks::Eth(vec![EthereumBlock {
+                events,
+                ..blocks[0].clone()
+            }])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_collect_rev() {
+        let x = vec![1, 2, 3];
+        let y = x.iter().map(|v| v + 1).collect_rev();
+        assert_eq!(y, vec![4, 3, 2]);
+    }
+
+    #[test]
+    fn test_receive_chain_blocks_fails_for_signed_origin() {
+        new_test_ext().execute_with(|| {
+            let blocks = ChainBlocks::Eth(vec![]);
+            let signature = ChainSignature::Eth([0u8; 65]);
+            assert_err!(
+                CashModule::receive_chain_blocks(
+                    Origin::signed(Default::default()),
+                    blocks,
+                    signature
+                ),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn test_receive_chain_blocks_fails_for_invalid_signature() {
+        new_test_ext().execute_with(|| {
+            let blocks = ChainBlocks::Eth(vec![]);
+            let signature = ChainSignature::Eth([0u8; 65]);
+            assert_err!(
+                CashModule::receive_chain_blocks(Origin::none(), blocks, signature),
+                Reason::CryptoError(gateway_crypto::CryptoError::RecoverError),
+            );
+        });
+    }
+
+    #[test]
+    fn test_receive_chain_blocks_fails_if_not_validator() {
+        new_test_ext().execute_with(|| {
+            let blocks = ChainBlocks::Eth(vec![]);
+            let signature = ChainId::Eth.sign(&blocks.encode()).unwrap();
+            assert_err!(
+                CashModule::receive_chain_blocks(Origin::none(), blocks, signature),
+                Reason::UnknownValidator
+            );
+        });
+    }
+
+    #[test]
+    fn test_receive_chain_blocks_happy_path() -> Result<(), Reason> {
+        sp_tracing::try_init_simple(); // Tip: add/remove from tests for logging
+
+        new_test_ext().execute_with(|| {
+            initialize_storage();
+
+            pallet_oracle::Prices::insert(
+                ETH.ticker,
+                Price::from_nominal(ETH.ticker, "2000.00").value,
+            );
+
+            let event = eth_log_event(
+                0,
+                ethereum_client::EthereumEvent::Lock {
+                    asset: [238; 20],
+                    sender: [3; 20],
+                    chain: String::from("ETH"),
+                    recipient: [2; 32],
+                    amount: qty!("75", ETH).value,
+                },
+            );
             let blocks_2 = ChainBlocks::Eth(vec![ethereum_client::EthereumBlock {
                 hash: [2; 32],
                 parent_hash: premined_block().hash,
@@ -1,41 +1,117 @@
 use crate::{
     chains::{ChainAccount, ChainAccountSignature},
+    core::get_recent_timestamp,
     internal::{
-        assets::get_asset,
-        extract::{extract_cash_principal_internal, extract_internal},
+        assets::{get_asset, get_cash_quantity, get_value},
+        extract::{
+            emit_extract_cash_principal_internal, emit_extract_internal,
+            prepare_extract_cash_principal_internal, prepare_extract_internal,
+        },
         liquidate::{
-            liquidate_cash_collateral_internal, liquidate_cash_principal_internal,
-            liquidate_internal,
+            emit_liquidate_cash_collateral_internal, emit_liquidate_cash_principal_internal,
+            emit_liquidate_internal, max_liquidate_cash_collateral_quantity,
+            max_liquidate_cash_principal, max_liquidate_quantity,
+            prepare_liquidate_cash_collateral_internal, prepare_liquidate_cash_principal_internal,
+            prepare_liquidate_internal,
+        },
+        transfer::{
+            emit_transfer_cash_principal_internal, emit_transfer_internal,
+            prepare_transfer_cash_principal_internal, prepare_transfer_internal,
         },
-        transfer::{transfer_cash_principal_internal, transfer_internal},
     },
     log,
-    params::TRANSFER_FEE,
+    params::{MAX_TRX_REQUEST_BATCH_DEPTH, MAX_TRX_REQUEST_BATCH_SIZE, TRANSFER_FEE},
+    pipeline::CashPipeline,
     reason::Reason,
     require,
     symbol::CASH,
-    types::{CashIndex, CashOrChainAsset, CashPrincipalAmount, Nonce, Quantity},
-    CashPrincipals, Config, GlobalCashIndex, Nonces,
+    trx_req::{get_cash_or_chain_asset, get_quantity, get_typed_data_message},
+    types::{
+        AssetInfo, AssetQuantity, CashOrChainAsset, CashPrincipalAmount, Nonce, Quantity,
+        TrxRequestDomain, USDQuantity, USD,
+    },
+    Config, GlobalCashIndex, Nonces,
 };
 use frame_support::storage::{StorageMap, StorageValue};
-use our_std::{convert::TryInto, str};
+use our_std::{convert::TryInto, str, RuntimeDebug};
+use sp_runtime::traits::Zero;
+
+/// Type for a single action of a trx request, with its assets and amounts fully resolved.
+#[derive(Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum TrxAction {
+    Extract(AssetInfo, ChainAccount, AssetQuantity),
+    ExtractCash(ChainAccount, CashPrincipalAmount),
+    Transfer(AssetInfo, ChainAccount, AssetQuantity),
+    TransferCash(ChainAccount, CashPrincipalAmount),
+    Liquidate(AssetInfo, AssetInfo, ChainAccount, AssetQuantity),
+    LiquidateCash(AssetInfo, ChainAccount, CashPrincipalAmount),
+    LiquidateCashCollateral(AssetInfo, ChainAccount, AssetQuantity),
+}
 
-pub fn prepend_nonce(payload: &Vec<u8>, nonce: Nonce) -> Vec<u8> {
+/// Return the hash of the genesis block, which identifies the network trx requests are signed for.
+pub fn get_genesis_hash<T: Config>() -> [u8; 32] {
+    let mut genesis_hash = [0u8; 32];
+    let hash = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+    genesis_hash.copy_from_slice(hash.as_ref());
+    genesis_hash
+}
+
+/// Build the payload a trx request is signed over, binding it to its domain and nonce.
+pub fn get_signed_payload(payload: &Vec<u8>, nonce: Nonce, domain: TrxRequestDomain) -> Vec<u8> {
     let mut result: Vec<u8> = Vec::new();
+    result.extend_from_slice(b"0x");
+    result.extend_from_slice(hex::encode(domain.genesis_hash).as_bytes());
+    result.extend_from_slice(b":");
+    if let Some(expiry) = domain.expiry {
+        result.extend_from_slice(expiry.to_string().as_bytes());
+    }
+    result.extend_from_slice(b":");
     result.extend_from_slice(nonce.to_string().as_bytes());
     result.extend_from_slice(b":");
     result.extend_from_slice(&payload[..]);
     result
 }
 
+/// Return the message the signature of a trx request is over, which depends on how it was signed.
+/// Typed data signatures are over the EIP-712 message, others are over the signed payload.
+pub fn get_signature_message(
+    payload: &Vec<u8>,
+    signature: ChainAccountSignature,
+    nonce: Nonce,
+    domain: TrxRequestDomain,
+) -> Result<Vec<u8>, Reason> {
+    match signature {
+        ChainAccountSignature::EthTypedData(_, _) => get_typed_data_message(payload, nonce, domain),
+        _ => Ok(get_signed_payload(payload, nonce, domain)),
+    }
+}
+
+/// Check the trx request was signed for this network and has not yet expired.
+pub fn check_domain<T: Config>(domain: TrxRequestDomain) -> Result<(), Reason> {
+    require!(
+        domain.genesis_hash == get_genesis_hash::<T>(),
+        Reason::TrxRequestWrongNetwork
+    );
+    if let Some(expiry) = domain.expiry {
+        require!(
+            get_recent_timestamp::<T>()? < expiry,
+            Reason::TrxRequestExpired
+        );
+    }
+    Ok(())
+}
+
 pub fn exec<T: Config>(
     request: Vec<u8>,
     signature: ChainAccountSignature,
     nonce: Nonce,
+    domain: TrxRequestDomain,
 ) -> Result<(), Reason> {
     log!("exec: {}", nonce);
     let request_str: &str = str::from_utf8(&request[..]).map_err(|_| Reason::InvalidUTF8)?;
-    let sender = signature.recover_account(&prepend_nonce(&request, nonce)[..])?;
+    check_domain::<T>(domain)?;
+    let message = get_signature_message(&request, signature, nonce, domain)?;
+    let sender = signature.recover_account(&message[..])?;
     exec_trx_request::<T>(request_str, sender, Some(nonce))
 }
 
@@ -43,18 +119,23 @@ pub fn is_minimally_valid_trx_request<T: Config>(
     request: Vec<u8>,
     signature: ChainAccountSignature,
     nonce: Nonce,
+    domain: TrxRequestDomain,
 ) -> Result<(ChainAccount, Nonce), Reason> {
     if request.len() > crate::params::MAX_TRX_REQUEST_LEN {
         return Err(Reason::TrxRequestTooLong);
     }
 
-    // Basic request validity checks - valid symbols and parsable request
+    // Basic request validity checks - valid symbols, parsable request and bounded batches
     let request_str: &str = str::from_utf8(&request[..]).map_err(|_| Reason::InvalidUTF8)?;
-    trx_request::parse_request(request_str)?;
+    check_trx_request_bounds(&trx_request::parse_request(request_str)?, 0)?;
+
+    // Domain check - signed for this network and not expired
+    check_domain::<T>(domain)?;
 
     // Signature check
+    let message = get_signature_message(&request, signature, nonce, domain)?;
     let sender = signature
-        .recover_account(&prepend_nonce(&request, nonce)[..])
+        .recover_account(&message[..])
         .map_err(|_| Reason::SignatureAccountMismatch)?;
 
     let current_nonce = Nonces::get(sender);
@@ -69,6 +150,7 @@ pub fn exec_trx_request<T: Config>(
     log!("exec_trx_request: {}", request_str);
     // Match TrxReq against known Transaction Requests
     let trx_request = trx_request::parse_request(request_str)?;
+    check_trx_request_bounds(&trx_request, 0)?;
 
     if let Some(nonce) = nonce_opt {
         // Read Require Nonce=Nonce_Account+1
@@ -79,83 +161,185 @@ pub fn exec_trx_request<T: Config>(
         );
     }
 
+    // Every action shares a single pipeline, so a request either commits entirely or not at all
+    let (pipeline, actions) =
+        prepare_trx_request::<T>(CashPipeline::new(), vec![], sender, trx_request)?;
+    pipeline.commit::<T>();
+
+    for action in actions {
+        emit_trx_action::<T>(sender, action)?;
+    }
+
+    if let Some(nonce) = nonce_opt {
+        // Update user nonce
+        Nonces::insert(sender, nonce + 1);
+    }
+
+    Ok(())
+}
+
+/// Check the number of requests in each batch and the nesting of batches are within limits.
+pub fn check_trx_request_bounds(
+    trx_request: &trx_request::TrxRequest,
+    depth: usize,
+) -> Result<(), Reason> {
+    match trx_request {
+        trx_request::TrxRequest::Batch(trx_requests) => {
+            require!(
+                depth < MAX_TRX_REQUEST_BATCH_DEPTH,
+                Reason::TrxRequestBatchTooDeep
+            );
+            require!(
+                trx_requests.len() <= MAX_TRX_REQUEST_BATCH_SIZE,
+                Reason::TrxRequestBatchTooLarge
+            );
+            trx_requests
+                .iter()
+                .try_for_each(|trx_request| check_trx_request_bounds(trx_request, depth + 1))
+        }
+
+        _ => Ok(()),
+    }
+}
+
+/// Determine the value (USD) moved by a trx request, were the sender to execute it now.
+pub fn trx_request_value<T: Config>(
+    request_str: &str,
+    sender: ChainAccount,
+) -> Result<USDQuantity, Reason> {
+    let trx_request = trx_request::parse_request(request_str)?;
+    let (_pipeline, actions) =
+        prepare_trx_request::<T>(CashPipeline::new(), vec![], sender, trx_request)?;
+    actions
+        .iter()
+        .try_fold(Quantity::new(0, USD), |value, action| {
+            Ok(value.add(trx_action_value::<T>(action)?)?)
+        })
+}
+
+/// Determine the value (USD) moved by a single resolved action.
+fn trx_action_value<T: Config>(action: &TrxAction) -> Result<USDQuantity, Reason> {
+    match action {
+        TrxAction::Extract(_asset, _account, quantity)
+        | TrxAction::Transfer(_asset, _account, quantity)
+        | TrxAction::Liquidate(_borrowed, _collateral, _account, quantity)
+        | TrxAction::LiquidateCashCollateral(_borrowed, _account, quantity) => {
+            get_value::<T>(*quantity)
+        }
+
+        TrxAction::ExtractCash(_account, principal)
+        | TrxAction::TransferCash(_account, principal)
+        | TrxAction::LiquidateCash(_collateral, _account, principal) => {
+            get_value::<T>(get_cash_quantity::<T>(*principal)?)
+        }
+    }
+}
+
+/// Resolve and apply each action of the trx request to the pipeline, in order.
+fn prepare_trx_request<T: Config>(
+    pipeline: CashPipeline,
+    mut actions: Vec<TrxAction>,
+    sender: ChainAccount,
+    trx_request: trx_request::TrxRequest,
+) -> Result<(CashPipeline, Vec<TrxAction>), Reason> {
+    match trx_request {
+        trx_request::TrxRequest::Batch(trx_requests) => trx_requests.into_iter().try_fold(
+            (pipeline, actions),
+            |(pipeline, actions), trx_request| {
+                prepare_trx_request::<T>(pipeline, actions, sender, trx_request)
+            },
+        ),
+
+        trx_request => {
+            let action = resolve_trx_action::<T>(&pipeline, sender, trx_request)?;
+            let pipeline = prepare_trx_action::<T>(pipeline, sender, action)?;
+            actions.push(action);
+            Ok((pipeline, actions))
+        }
+    }
+}
+
+/// Determine the action to take for a single trx request, given the state of the pipeline so far.
+fn resolve_trx_action<T: Config>(
+    pipeline: &CashPipeline,
+    sender: ChainAccount,
+    trx_request: trx_request::TrxRequest,
+) -> Result<TrxAction, Reason> {
     match trx_request {
         trx_request::TrxRequest::Extract(max_amount, asset, account) => {
-            match CashOrChainAsset::from(asset) {
+            match get_cash_or_chain_asset::<T>(asset)? {
                 CashOrChainAsset::Cash => match max_amount {
                     trx_request::MaxAmount::Max => {
-                        let principal_amount = CashPrincipals::get(sender).amount_withdrawable()?;
-                        extract_cash_principal_internal::<T>(
-                            sender,
-                            account.into(),
-                            principal_amount,
-                        )?;
+                        let principal_amount = pipeline
+                            .state
+                            .get_cash_principal::<T>(sender)
+                            .amount_withdrawable()?;
+                        Ok(TrxAction::ExtractCash(account.into(), principal_amount))
                     }
 
-                    trx_request::MaxAmount::Amount(amount) => {
+                    amount => {
                         let index = GlobalCashIndex::get();
                         let principal_amount =
-                            index.cash_principal_amount(Quantity::new(amount, CASH))?;
-                        extract_cash_principal_internal::<T>(
-                            sender,
-                            account.into(),
-                            principal_amount,
-                        )?;
+                            index.cash_principal_amount(get_quantity(amount, CASH)?)?;
+                        Ok(TrxAction::ExtractCash(account.into(), principal_amount))
                     }
                 },
 
                 CashOrChainAsset::ChainAsset(chain_asset) => match max_amount {
                     trx_request::MaxAmount::Max => {
-                        return Err(Reason::MaxForNonCashAsset);
+                        let asset = get_asset::<T>(chain_asset)?;
+                        let asset_amount = pipeline
+                            .state
+                            .get_asset_balance::<T>(asset, sender)
+                            .amount_withdrawable();
+                        Ok(TrxAction::Extract(asset, account.into(), asset_amount))
                     }
-                    trx_request::MaxAmount::Amount(amount) => {
+
+                    amount => {
                         let asset = get_asset::<T>(chain_asset)?;
-                        let asset_amount = asset.as_quantity(amount.into());
-                        extract_internal::<T>(asset, sender, account.into(), asset_amount)?;
+                        let asset_amount = get_quantity(amount, asset.units())?;
+                        Ok(TrxAction::Extract(asset, account.into(), asset_amount))
                     }
                 },
             }
         }
 
         trx_request::TrxRequest::Transfer(max_amount, asset, account) => {
-            match CashOrChainAsset::from(asset) {
+            match get_cash_or_chain_asset::<T>(asset)? {
                 CashOrChainAsset::Cash => match max_amount {
                     trx_request::MaxAmount::Max => {
-                        let index: CashIndex = GlobalCashIndex::get();
-                        let user_principal = CashPrincipals::get(sender);
+                        let index = GlobalCashIndex::get();
+                        let user_principal = pipeline.state.get_cash_principal::<T>(sender);
                         let fee_principal = index.cash_principal_amount(TRANSFER_FEE)?;
                         let transfer_principal: CashPrincipalAmount = user_principal
                             .sub_amount(fee_principal)?
                             .try_into()
                             .map_err(|_| Reason::InsufficientCashForMaxTransfer)?;
-                        transfer_cash_principal_internal::<T>(
-                            sender,
-                            account.into(),
-                            transfer_principal,
-                        )?;
+                        Ok(TrxAction::TransferCash(account.into(), transfer_principal))
                     }
 
-                    trx_request::MaxAmount::Amount(amount) => {
+                    amount => {
                         let index = GlobalCashIndex::get();
                         let principal_amount =
-                            index.cash_principal_amount(Quantity::new(amount, CASH))?;
-                        transfer_cash_principal_internal::<T>(
-                            sender,
-                            account.into(),
-                            principal_amount,
-                        )?;
+                            index.cash_principal_amount(get_quantity(amount, CASH)?)?;
+                        Ok(TrxAction::TransferCash(account.into(), principal_amount))
                     }
                 },
 
                 CashOrChainAsset::ChainAsset(chain_asset) => match max_amount {
                     trx_request::MaxAmount::Max => {
-                        return Err(Reason::MaxForNonCashAsset);
+                        let asset = get_asset::<T>(chain_asset)?;
+                        let asset_amount = pipeline
+                            .state
+                            .get_asset_balance::<T>(asset, sender)
+                            .amount_withdrawable();
+                        Ok(TrxAction::Transfer(asset, account.into(), asset_amount))
                     }
 
-                    trx_request::MaxAmount::Amount(amount) => {
+                    amount => {
                         let asset = get_asset::<T>(chain_asset)?;
-                        let asset_amount = asset.as_quantity(amount.into());
-                        transfer_internal::<T>(asset, sender, account.into(), asset_amount)?;
+                        let asset_amount = get_quantity(amount, asset.units())?;
+                        Ok(TrxAction::Transfer(asset, account.into(), asset_amount))
                     }
                 },
             }
@@ -167,74 +351,157 @@ pub fn exec_trx_request<T: Config>(
             trx_collateral_asset,
             borrower,
         ) => match (
-            CashOrChainAsset::from(trx_borrowed_asset),
-            CashOrChainAsset::from(trx_collateral_asset),
+            get_cash_or_chain_asset::<T>(trx_borrowed_asset)?,
+            get_cash_or_chain_asset::<T>(trx_collateral_asset)?,
         ) {
-            (x, y) if x == y => return Err(Reason::InKindLiquidation),
+            (x, y) if x == y => Err(Reason::InKindLiquidation),
 
             (CashOrChainAsset::Cash, CashOrChainAsset::ChainAsset(collateral)) => {
                 let collateral_asset = get_asset::<T>(collateral)?;
                 let cash_principal_amount = match max_amount {
-                    trx_request::MaxAmount::Max => panic!("Not supported"), // TODO
-                    trx_request::MaxAmount::Amount(amount) => {
+                    trx_request::MaxAmount::Max => max_liquidate_cash_principal::<T>(
+                        pipeline,
+                        collateral_asset,
+                        sender,
+                        borrower.into(),
+                    )?,
+                    amount => {
                         let index = GlobalCashIndex::get();
-                        index.cash_principal_amount(Quantity::new(amount, CASH))?
+                        index.cash_principal_amount(get_quantity(amount, CASH)?)?
                     }
                 };
 
-                liquidate_cash_principal_internal::<T>(
+                Ok(TrxAction::LiquidateCash(
                     collateral_asset,
-                    sender,
                     borrower.into(),
                     cash_principal_amount,
-                )?;
+                ))
             }
 
             (CashOrChainAsset::ChainAsset(borrowed), CashOrChainAsset::Cash) => {
                 let borrowed_asset = get_asset::<T>(borrowed)?;
                 let borrowed_asset_amount = match max_amount {
-                    trx_request::MaxAmount::Max => panic!("Not supported"), // TODO
-                    trx_request::MaxAmount::Amount(amount) => {
-                        borrowed_asset.as_quantity(amount.into())
-                    }
+                    trx_request::MaxAmount::Max => max_liquidate_cash_collateral_quantity::<T>(
+                        pipeline,
+                        borrowed_asset,
+                        sender,
+                        borrower.into(),
+                    )?,
+                    amount => get_quantity(amount, borrowed_asset.units())?,
                 };
 
-                liquidate_cash_collateral_internal::<T>(
+                Ok(TrxAction::LiquidateCashCollateral(
                     borrowed_asset,
-                    sender,
                     borrower.into(),
                     borrowed_asset_amount,
-                )?;
+                ))
             }
 
             (CashOrChainAsset::ChainAsset(borrowed), CashOrChainAsset::ChainAsset(collateral)) => {
                 let borrowed_asset = get_asset::<T>(borrowed)?;
                 let collateral_asset = get_asset::<T>(collateral)?;
                 let borrowed_asset_amount = match max_amount {
-                    trx_request::MaxAmount::Max => panic!("Not supported"), // TODO
-                    trx_request::MaxAmount::Amount(amount) => {
-                        borrowed_asset.as_quantity(amount.into())
-                    }
+                    trx_request::MaxAmount::Max => max_liquidate_quantity::<T>(
+                        pipeline,
+                        borrowed_asset,
+                        collateral_asset,
+                        sender,
+                        borrower.into(),
+                    )?,
+                    amount => get_quantity(amount, borrowed_asset.units())?,
                 };
 
-                liquidate_internal::<T>(
+                Ok(TrxAction::Liquidate(
                     borrowed_asset,
                     collateral_asset,
-                    sender,
                     borrower.into(),
                     borrowed_asset_amount,
-                )?;
+                ))
             }
 
-            _ => return Err(Reason::InvalidLiquidation), // Probably isn't possible
+            _ => Err(Reason::InvalidLiquidation), // Probably isn't possible
         },
+
+        trx_request::TrxRequest::Batch(_) => Err(Reason::Unreachable),
     }
+}
 
-    if let Some(nonce) = nonce_opt {
-        // Update user nonce
-        Nonces::insert(sender, nonce + 1);
+/// Apply the effects of a resolved action to the pipeline, without committing it.
+fn prepare_trx_action<T: Config>(
+    pipeline: CashPipeline,
+    sender: ChainAccount,
+    action: TrxAction,
+) -> Result<CashPipeline, Reason> {
+    match action {
+        TrxAction::Extract(asset, _recipient, quantity) => {
+            prepare_extract_internal::<T>(pipeline, asset, sender, quantity)
+        }
+        TrxAction::ExtractCash(_recipient, principal) => {
+            prepare_extract_cash_principal_internal::<T>(pipeline, sender, principal)
+        }
+        TrxAction::Transfer(asset, recipient, quantity) => {
+            prepare_transfer_internal::<T>(pipeline, asset, sender, recipient, quantity)
+        }
+        TrxAction::TransferCash(recipient, principal) => {
+            prepare_transfer_cash_principal_internal::<T>(pipeline, sender, recipient, principal)
+        }
+        TrxAction::Liquidate(asset, collateral_asset, borrower, quantity) => {
+            prepare_liquidate_internal::<T>(
+                pipeline,
+                asset,
+                collateral_asset,
+                sender,
+                borrower,
+                quantity,
+            )
+        }
+        TrxAction::LiquidateCash(collateral_asset, borrower, principal) => {
+            prepare_liquidate_cash_principal_internal::<T>(
+                pipeline,
+                collateral_asset,
+                sender,
+                borrower,
+                principal,
+            )
+        }
+        TrxAction::LiquidateCashCollateral(asset, borrower, quantity) => {
+            prepare_liquidate_cash_collateral_internal::<T>(
+                pipeline, asset, sender, borrower, quantity,
+            )
+        }
     }
+}
 
+/// Dispatch the notices and events for an action, once its pipeline has been committed.
+fn emit_trx_action<T: Config>(sender: ChainAccount, action: TrxAction) -> Result<(), Reason> {
+    match action {
+        TrxAction::Extract(asset, recipient, quantity) => {
+            emit_extract_internal::<T>(asset, sender, recipient, quantity)
+        }
+        TrxAction::ExtractCash(recipient, principal) => {
+            emit_extract_cash_principal_internal::<T>(sender, recipient, principal)
+        }
+        TrxAction::Transfer(asset, recipient, quantity) => {
+            emit_transfer_internal::<T>(asset, sender, recipient, quantity)?
+        }
+        TrxAction::TransferCash(recipient, principal) => {
+            emit_transfer_cash_principal_internal::<T>(sender, recipient, principal)?
+        }
+        TrxAction::Liquidate(asset, collateral_asset, borrower, quantity) => {
+            emit_liquidate_internal::<T>(asset, collateral_asset, sender, borrower, quantity)
+        }
+        TrxAction::LiquidateCash(collateral_asset, borrower, principal) => {
+            emit_liquidate_cash_principal_internal::<T>(
+                collateral_asset,
+                sender,
+                borrower,
+                principal,
+            )
+        }
+        TrxAction::LiquidateCashCollateral(asset, borrower, quantity) => {
+            emit_liquidate_cash_collateral_internal::<T>(asset, sender, borrower, quantity)
+        }
+    }
     Ok(())
 }
 
@@ -243,7 +510,7 @@ mod tests {
     use super::*;
     use crate::{
         chains::*,
-        reason::TrxReqParseError,
+        reason::{SpannedTrxReqParseError, TrxReqExpected, TrxReqParseError},
         tests::mock::*,
         tests::{common::*, *},
         types::*,
@@ -254,13 +521,262 @@ mod tests {
         ChainAccountSignature::Eth([0u8; 20], [0u8; 65])
     }
 
+    fn get_empty_domain() -> TrxRequestDomain {
+        TrxRequestDomain {
+            genesis_hash: [0u8; 32],
+            expiry: None,
+        }
+    }
+
     #[test]
     fn test_is_minimally_valid_trx_request_fails_when_too_long() {
         let request = [0; crate::params::MAX_TRX_REQUEST_LEN + 1].into();
-        let result = is_minimally_valid_trx_request::<Test>(request, get_empty_signature(), 0);
+        let result = is_minimally_valid_trx_request::<Test>(
+            request,
+            get_empty_signature(),
+            0,
+            get_empty_domain(),
+        );
         assert_eq!(result, Err(Reason::TrxRequestTooLong));
     }
 
+    #[test]
+    fn test_is_minimally_valid_trx_request_fails_when_batch_too_large() {
+        let extract = " (Extract 3 Cash Eth:0x0101010101010101010101010101010101010101)";
+        let request = format!(
+            "(Batch{})",
+            extract.repeat(crate::params::MAX_TRX_REQUEST_BATCH_SIZE + 1)
+        );
+        let result = is_minimally_valid_trx_request::<Test>(
+            request.into(),
+            get_empty_signature(),
+            0,
+            get_empty_domain(),
+        );
+        assert_eq!(result, Err(Reason::TrxRequestBatchTooLarge));
+    }
+
+    #[test]
+    fn test_check_trx_request_bounds() {
+        let extract = trx_request::TrxRequest::Extract(
+            trx_request::MaxAmount::Max,
+            trx_request::Asset::Cash,
+            trx_request::Account::Eth([1; 20]),
+        );
+        let batch = trx_request::TrxRequest::Batch(vec![
+            extract.clone();
+            crate::params::MAX_TRX_REQUEST_BATCH_SIZE
+        ]);
+        assert_eq!(check_trx_request_bounds(&extract, 0), Ok(()));
+        assert_eq!(check_trx_request_bounds(&batch, 0), Ok(()));
+        assert_eq!(
+            check_trx_request_bounds(&trx_request::TrxRequest::Batch(vec![extract, batch]), 0),
+            Err(Reason::TrxRequestBatchTooDeep)
+        );
+    }
+
+    #[test]
+    fn test_get_signed_payload() {
+        let request: Vec<u8> =
+            "(Extract 3 Cash Eth:0x0101010101010101010101010101010101010101)".into();
+        let domain = TrxRequestDomain {
+            genesis_hash: [0xab; 32],
+            expiry: Some(1000),
+        };
+        assert_eq!(
+            str::from_utf8(&get_signed_payload(&request, 5, domain)[..]),
+            Ok("0xabababababababababababababababababababababababababababababababab:1000:5:(Extract 3 Cash Eth:0x0101010101010101010101010101010101010101)")
+        );
+        assert_eq!(
+            str::from_utf8(&get_signed_payload(&request, 5, get_empty_domain())[..]),
+            Ok("0x0000000000000000000000000000000000000000000000000000000000000000::5:(Extract 3 Cash Eth:0x0101010101010101010101010101010101010101)")
+        );
+    }
+
+    #[test]
+    fn test_exec_signed_request() {
+        new_test_ext().execute_with(|| {
+            <pallet_timestamp::Pallet<Test>>::set_timestamp(500);
+            let request: Vec<u8> =
+                "(Extract 3000000 CASH Eth:0x0101010101010101010101010101010101010101)".into();
+            let account = ChainAccount::Eth(<Ethereum as Chain>::signer_address().unwrap());
+            let domain = trx_request_domain(Some(1000));
+            let signature = sign_trx_request(&request, 0, domain);
+            init_cash(account, CashPrincipal::from_nominal("5"));
+
+            assert_eq!(exec::<Test>(request, signature, 0, domain), Ok(()));
+            assert_eq!(Nonces::get(account), 1);
+        });
+    }
+
+    #[test]
+    fn test_exec_typed_data_signed_request() {
+        new_test_ext().execute_with(|| {
+            let request: Vec<u8> =
+                "(Extract 3000000 CASH Eth:0x0101010101010101010101010101010101010101)".into();
+            let account = ChainAccount::Eth(<Ethereum as Chain>::signer_address().unwrap());
+            let domain = trx_request_domain(None);
+            let signature = sign_typed_trx_request(&request, 0, domain);
+            init_cash(account, CashPrincipal::from_nominal("5"));
+
+            assert_eq!(exec::<Test>(request, signature, 0, domain), Ok(()));
+            assert_eq!(Nonces::get(account), 1);
+        });
+    }
+
+    #[test]
+    fn test_exec_typed_data_signature_over_wrong_message() {
+        new_test_ext().execute_with(|| {
+            let request: Vec<u8> =
+                "(Extract 3000000 CASH Eth:0x0101010101010101010101010101010101010101)".into();
+            let eth_address = <Ethereum as Chain>::signer_address().unwrap();
+            let account = ChainAccount::Eth(eth_address);
+            let domain = trx_request_domain(None);
+            let signature = match sign_trx_request(&request, 0, domain) {
+                ChainAccountSignature::Eth(_, sig) => {
+                    ChainAccountSignature::EthTypedData(eth_address, sig)
+                }
+                _ => panic!("expected an eth signature"),
+            };
+            init_cash(account, CashPrincipal::from_nominal("5"));
+
+            assert_eq!(
+                exec::<Test>(request, signature, 0, domain),
+                Err(Reason::SignatureAccountMismatch)
+            );
+            assert_eq!(Nonces::get(account), 0);
+        });
+    }
+
+    #[test]
+    fn test_exec_sr25519_signed_request() {
+        new_test_ext().execute_with(|| {
+            let request: Vec<u8> =
+                "(Transfer 3000000 CASH Eth:0x0101010101010101010101010101010101010101)".into();
+            let pair = <sp_core::sr25519::Pair as sp_core::Pair>::from_seed(&[7; 32]);
+            let domain = trx_request_domain(None);
+            let signature = sign_sr25519_trx_request(&pair, &request, 0, domain);
+            let account = signature.recover_account(&get_signed_payload(&request, 0, domain));
+            assert_eq!(account, Ok(ChainAccount::Gate(pair.public().0)));
+            init_cash(account.unwrap(), CashPrincipal::from_nominal("4"));
+            Miner::put(ChainAccount::Eth([3; 20]));
+
+            assert_eq!(exec::<Test>(request, signature, 0, domain), Ok(()));
+            assert_eq!(Nonces::get(account.unwrap()), 1);
+            assert_eq!(
+                CashPrincipals::get(ChainAccount::Eth([1; 20])),
+                CashPrincipal::from_nominal("3")
+            );
+        });
+    }
+
+    #[test]
+    fn test_exec_ed25519_signed_request() {
+        new_test_ext().execute_with(|| {
+            let request: Vec<u8> =
+                "(Transfer 3000000 CASH Eth:0x0101010101010101010101010101010101010101)".into();
+            let pair = <sp_core::ed25519::Pair as sp_core::Pair>::from_seed(&[7; 32]);
+            let account = ChainAccount::Gate(pair.public().0);
+            let domain = trx_request_domain(None);
+            let signature = sign_ed25519_trx_request(&pair, &request, 0, domain);
+            init_cash(account, CashPrincipal::from_nominal("4"));
+            Miner::put(ChainAccount::Eth([3; 20]));
+
+            assert_eq!(exec::<Test>(request, signature, 0, domain), Ok(()));
+            assert_eq!(Nonces::get(account), 1);
+        });
+    }
+
+    #[test]
+    fn test_exec_wrapped_bytes_signed_request() {
+        new_test_ext().execute_with(|| {
+            let request: Vec<u8> =
+                "(Transfer 3000000 CASH Eth:0x0101010101010101010101010101010101010101)".into();
+            let pair = <sp_core::sr25519::Pair as sp_core::Pair>::from_seed(&[7; 32]);
+            let account = ChainAccount::Gate(pair.public().0);
+            let domain = trx_request_domain(None);
+            let wrapped = [
+                &b"<Bytes>"[..],
+                &get_signed_payload(&request, 0, domain)[..],
+                &b"</Bytes>"[..],
+            ]
+            .concat();
+            let signature = ChainAccountSignature::Gate(
+                pair.public().0,
+                GatewaySignature::Sr25519(sp_core::Pair::sign(&pair, &wrapped[..]).0),
+            );
+            init_cash(account, CashPrincipal::from_nominal("4"));
+            Miner::put(ChainAccount::Eth([3; 20]));
+
+            assert_eq!(exec::<Test>(request, signature, 0, domain), Ok(()));
+            assert_eq!(Nonces::get(account), 1);
+        });
+    }
+
+    #[test]
+    fn test_exec_gate_signature_wrong_account() {
+        new_test_ext().execute_with(|| {
+            let request: Vec<u8> =
+                "(Transfer 3000000 CASH Eth:0x0101010101010101010101010101010101010101)".into();
+            let pair = <sp_core::sr25519::Pair as sp_core::Pair>::from_seed(&[7; 32]);
+            let domain = trx_request_domain(None);
+            let signature = match sign_sr25519_trx_request(&pair, &request, 0, domain) {
+                ChainAccountSignature::Gate(_, sig) => ChainAccountSignature::Gate([8; 32], sig),
+                _ => panic!("expected a gate signature"),
+            };
+            init_cash(
+                ChainAccount::Gate([8; 32]),
+                CashPrincipal::from_nominal("4"),
+            );
+
+            assert_eq!(
+                exec::<Test>(request, signature, 0, domain),
+                Err(Reason::SignatureAccountMismatch)
+            );
+            assert_eq!(Nonces::get(ChainAccount::Gate([8; 32])), 0);
+        });
+    }
+
+    #[test]
+    fn test_exec_expired_request() {
+        new_test_ext().execute_with(|| {
+            <pallet_timestamp::Pallet<Test>>::set_timestamp(1000);
+            let request: Vec<u8> =
+                "(Extract 3000000 CASH Eth:0x0101010101010101010101010101010101010101)".into();
+            let account = ChainAccount::Eth(<Ethereum as Chain>::signer_address().unwrap());
+            let domain = trx_request_domain(Some(1000));
+            let signature = sign_trx_request(&request, 0, domain);
+            init_cash(account, CashPrincipal::from_nominal("5"));
+
+            assert_eq!(
+                exec::<Test>(request, signature, 0, domain),
+                Err(Reason::TrxRequestExpired)
+            );
+            assert_eq!(Nonces::get(account), 0);
+        });
+    }
+
+    #[test]
+    fn test_exec_wrong_network_request() {
+        new_test_ext().execute_with(|| {
+            let request: Vec<u8> =
+                "(Extract 3000000 CASH Eth:0x0101010101010101010101010101010101010101)".into();
+            let account = ChainAccount::Eth(<Ethereum as Chain>::signer_address().unwrap());
+            let domain = TrxRequestDomain {
+                genesis_hash: [1; 32],
+                expiry: None,
+            };
+            let signature = sign_trx_request(&request, 0, domain);
+            init_cash(account, CashPrincipal::from_nominal("5"));
+
+            assert_eq!(
+                exec::<Test>(request, signature, 0, domain),
+                Err(Reason::TrxRequestWrongNetwork)
+            );
+            assert_eq!(Nonces::get(account), 0);
+        });
+    }
+
     #[test]
     fn exec_trx_request_extract_cash_principal_internal() {
         new_test_ext().execute_with(|| {
@@ -485,6 +1001,117 @@ mod tests {
         });
     }
 
+    #[test]
+    fn exec_trx_request_extract_max_internal() {
+        new_test_ext().execute_with(|| {
+            let asset = init_eth_asset().unwrap();
+            let account = ChainAccount::Eth([20; 20]);
+            init_asset_balance(asset, account, Balance::from_nominal("3", ETH).value);
+            let req_str = "(Extract Max Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee \
+                Eth:0x0101010101010101010101010101010101010101)";
+            let nonce = Some(0);
+
+            let res = exec_trx_request::<Test>(req_str, account, nonce);
+            assert_eq!(res, Ok(()));
+
+            assert_eq!(
+                AssetBalances::get(asset, account),
+                Balance::from_nominal("0", ETH).value
+            );
+            assert_eq!(Nonces::get(account), 1);
+
+            // Check emitted `Extract` event
+            let extract_event = System::events().into_iter().nth(1).unwrap();
+            assert_eq!(
+                mock::Event::pallet_cash(crate::Event::Extract(
+                    asset,
+                    account,
+                    ChainAccount::Eth([1; 20]),
+                    3000000000000000000
+                )),
+                extract_event.event
+            );
+        });
+    }
+
+    #[test]
+    fn exec_trx_request_extract_max_internal_undercollateralized() {
+        new_test_ext().execute_with(|| {
+            let asset = init_eth_asset().unwrap();
+            let account = ChainAccount::Eth([20; 20]);
+            init_asset_balance(asset, account, Balance::from_nominal("3", ETH).value);
+            init_cash(account, CashPrincipal::from_nominal("-1000"));
+            let req_str = "(Extract Max Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee \
+                Eth:0x0101010101010101010101010101010101010101)";
+            let nonce = Some(0);
+
+            let res = exec_trx_request::<Test>(req_str, account, nonce);
+            assert_eq!(res, Err(Reason::InsufficientLiquidity));
+
+            assert_eq!(
+                AssetBalances::get(asset, account),
+                Balance::from_nominal("3", ETH).value
+            );
+            assert_eq!(Nonces::get(account), 0);
+        });
+    }
+
+    #[test]
+    fn exec_trx_request_extract_max_internal_borrowed() {
+        new_test_ext().execute_with(|| {
+            let asset = init_eth_asset().unwrap();
+            let account = ChainAccount::Eth([20; 20]);
+            init_asset_balance(asset, account, Balance::from_nominal("-3", ETH).value);
+            init_cash(account, CashPrincipal::from_nominal("100000"));
+            let req_str = "(Extract Max Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee \
+                Eth:0x0101010101010101010101010101010101010101)";
+            let nonce = Some(0);
+
+            let res = exec_trx_request::<Test>(req_str, account, nonce);
+            assert_eq!(res, Err(Reason::MinTxValueNotMet));
+
+            assert_eq!(
+                AssetBalances::get(asset, account),
+                Balance::from_nominal("-3", ETH).value
+            );
+        });
+    }
+
+    #[test]
+    fn exec_trx_request_extract_decimal_symbol_internal() {
+        new_test_ext().execute_with(|| {
+            let asset = init_eth_asset().unwrap();
+            crate::internal::assets::support_asset::<Test>(eth).unwrap();
+            let account = ChainAccount::Eth([20; 20]);
+            init_asset_balance(asset, account, Balance::from_nominal("3", ETH).value);
+            let req_str = "(Extract 1.5 Eth:ETH Eth:0x0101010101010101010101010101010101010101)";
+            let nonce = Some(0);
+
+            let res = exec_trx_request::<Test>(req_str, account, nonce);
+            assert_eq!(res, Ok(()));
+
+            assert_eq!(
+                AssetBalances::get(asset, account),
+                Balance::from_nominal("1.5", ETH).value
+            );
+            assert_eq!(Nonces::get(account), 1);
+        });
+    }
+
+    #[test]
+    fn exec_trx_request_extract_decimal_excessive_precision() {
+        new_test_ext().execute_with(|| {
+            let account = ChainAccount::Eth([20; 20]);
+            init_cash(account, CashPrincipal::from_nominal("4"));
+            let req_str = "(Extract 1.0000001 CASH Eth:0x0101010101010101010101010101010101010101)";
+            let nonce = Some(0);
+
+            let res = exec_trx_request::<Test>(req_str, account, nonce);
+            assert_eq!(res, Err(Reason::ExcessivePrecision));
+            assert_eq!(Nonces::get(account), 0);
+        });
+    }
+
     #[test]
     fn exec_trx_transfer_internal() {
         new_test_ext().execute_with(|| {
@@ -558,6 +1185,41 @@ mod tests {
         });
     }
 
+    #[test]
+    fn exec_trx_transfer_max_internal() {
+        new_test_ext().execute_with(|| {
+            let asset = init_eth_asset().unwrap();
+            let account = ChainAccount::Eth([20; 20]);
+            init_asset_balance(asset, account, Balance::from_nominal("3", ETH).value);
+            init_cash(account, CashPrincipal::from_nominal("1"));
+            let req_str = "(Transfer Max Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee \
+              Eth:0x0101010101010101010101010101010101010101)";
+            let to_account = ChainAccount::Eth([1; 20]);
+            let nonce = 0;
+
+            let miner = ChainAccount::Eth([3; 20]);
+            Miner::put(miner);
+
+            let res = exec_trx_request::<Test>(req_str, account, Some(nonce));
+            assert_eq!(res, Ok(()));
+
+            assert_eq!(
+                AssetBalances::get(asset, account),
+                Balance::from_nominal("0", ETH).value
+            );
+            assert_eq!(
+                AssetBalances::get(asset, to_account),
+                Balance::from_nominal("3", ETH).value
+            );
+            // Trx fee
+            assert_eq!(
+                CashPrincipals::get(account),
+                CashPrincipal::from_nominal("0.99")
+            );
+            assert_eq!(Nonces::get(account), nonce + 1);
+        });
+    }
+
     #[test]
     fn exec_trx_transfer_principal_cash_internal() {
         new_test_ext().execute_with(|| {
@@ -754,6 +1416,103 @@ mod tests {
         });
     }
 
+    #[test]
+    fn exec_trx_request_batch_success() {
+        new_test_ext().execute_with(|| {
+            let req_str = "(Batch \
+                (Transfer 1000000 CASH Eth:0x0101010101010101010101010101010101010101) \
+                (Extract Max CASH Eth:0x0202020202020202020202020202020202020202))";
+            let account = ChainAccount::Eth([20; 20]);
+            let to_account = ChainAccount::Eth([1; 20]);
+            init_cash(account, CashPrincipal::from_nominal("4"));
+            let nonce = Some(0);
+
+            let miner = ChainAccount::Eth([3; 20]);
+            Miner::put(miner);
+
+            let res = exec_trx_request::<Test>(req_str, account, nonce);
+            assert_eq!(res, Ok(()));
+
+            assert_eq!(
+                CashPrincipals::get(account),
+                CashPrincipal::from_nominal("0")
+            );
+            assert_eq!(
+                CashPrincipals::get(to_account),
+                CashPrincipal::from_nominal("1")
+            );
+            assert_eq!(
+                CashPrincipals::get(miner),
+                CashPrincipal::from_nominal("0.01")
+            );
+            assert_eq!(Nonces::get(account), 1);
+
+            let expected_notice = Notice::CashExtractionNotice(CashExtractionNotice::Eth {
+                id: NoticeId(0, 1),
+                parent: [0u8; 32],
+                account: [2; 20],
+                principal: 2990000,
+            });
+            let (_, actual_notice) = Notices::iter_prefix(ChainId::Eth).next().unwrap();
+            assert_eq!(actual_notice, expected_notice);
+
+            // Check emitted events are in the order of the batch
+            let index = GlobalCashIndex::get();
+            let events: Vec<_> = System::events().into_iter().map(|e| e.event).collect();
+            assert_eq!(events.len(), 5);
+            assert_eq!(
+                events[0],
+                mock::Event::pallet_cash(crate::Event::TransferCash(
+                    account,
+                    to_account,
+                    index
+                        .cash_principal_amount(Quantity::from_nominal("1", CASH))
+                        .unwrap(),
+                    index
+                ))
+            );
+            assert_eq!(
+                events[4],
+                mock::Event::pallet_cash(crate::Event::ExtractCash(
+                    account,
+                    ChainAccount::Eth([2; 20]),
+                    index
+                        .cash_principal_amount(Quantity::from_nominal("2.99", CASH))
+                        .unwrap(),
+                    index
+                ))
+            );
+        });
+    }
+
+    #[test]
+    fn exec_trx_request_batch_is_atomic() {
+        new_test_ext().execute_with(|| {
+            let req_str = "(Batch \
+                (Transfer 1000000 CASH Eth:0x0101010101010101010101010101010101010101) \
+                (Extract 5000000 CASH Eth:0x0202020202020202020202020202020202020202))";
+            let account = ChainAccount::Eth([20; 20]);
+            let to_account = ChainAccount::Eth([1; 20]);
+            init_cash(account, CashPrincipal::from_nominal("4"));
+            let nonce = Some(0);
+
+            let res = exec_trx_request::<Test>(req_str, account, nonce);
+            assert_eq!(res, Err(Reason::InsufficientLiquidity));
+
+            assert_eq!(
+                CashPrincipals::get(account),
+                CashPrincipal::from_nominal("4")
+            );
+            assert_eq!(
+                CashPrincipals::get(to_account),
+                CashPrincipal::from_nominal("0")
+            );
+            assert_eq!(Nonces::get(account), 0);
+            assert_eq!(Notices::iter_prefix(ChainId::Eth).count(), 0);
+            assert_eq!(System::events().len(), 0);
+        });
+    }
+
     // TODO: Liquidation Unit Tests
 
     #[test]
@@ -858,28 +1617,82 @@ mod tests {
         });
     }
 
-    // TODO: Implement max
     #[test]
-    #[should_panic(expected = "Not supported")]
     fn exec_trx_liquidate_cash_collateral_max() {
         new_test_ext().execute_with(|| {
             let eth_asset = init_eth_asset().unwrap();
-            let _borrower_account = ChainAccount::Eth([1; 20]);
+            let borrower_account = ChainAccount::Eth([1; 20]);
             let liquidator_account = ChainAccount::Eth([2; 20]);
+            init_cash(borrower_account, CashPrincipal::from_nominal("2160"));
+            init_asset_balance(
+                eth_asset,
+                borrower_account,
+                Balance::from_nominal("-10", ETH).value,
+            );
             init_asset_balance(
                 eth_asset,
                 liquidator_account,
                 Balance::from_nominal("3", ETH).value,
             );
 
-            // Liquidate 1e18 Eth
+            // Max is capped by the borrower's CASH collateral, i.e. 1e18 Eth
             let req_str = "(Liquidate Max Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee \
                 CASH Eth:0x0101010101010101010101010101010101010101)";
             let nonce = 0;
 
-            let _ = exec_trx_request::<Test>(req_str, liquidator_account, Some(nonce));
+            assert_ok!(exec_trx_request::<Test>(
+                req_str,
+                liquidator_account,
+                Some(nonce)
+            ));
 
-            // TODO: Check balances
+            assert_eq!(
+                AssetBalances::get(eth_asset, liquidator_account),
+                Balance::from_nominal("2", ETH).value
+            );
+            assert_eq!(
+                AssetBalances::get(eth_asset, borrower_account),
+                Balance::from_nominal("-9", ETH).value
+            );
+            assert_eq!(
+                CashPrincipals::get(liquidator_account),
+                CashPrincipal::from_nominal("2160")
+            );
+            assert_eq!(
+                CashPrincipals::get(borrower_account),
+                CashPrincipal::from_nominal("0")
+            );
+            assert_eq!(Nonces::get(liquidator_account), nonce + 1);
+        });
+    }
+
+    #[test]
+    fn exec_trx_liquidate_cash_collateral_max_nothing_to_liquidate() {
+        new_test_ext().execute_with(|| {
+            let eth_asset = init_eth_asset().unwrap();
+            let borrower_account = ChainAccount::Eth([1; 20]);
+            let liquidator_account = ChainAccount::Eth([2; 20]);
+            init_cash(borrower_account, CashPrincipal::from_nominal("2160"));
+            init_asset_balance(
+                eth_asset,
+                borrower_account,
+                Balance::from_nominal("-10", ETH).value,
+            );
+
+            // The liquidator has no Eth to repay with
+            let req_str = "(Liquidate Max Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee \
+                CASH Eth:0x0101010101010101010101010101010101010101)";
+            let nonce = 0;
+
+            assert_eq!(
+                exec_trx_request::<Test>(req_str, liquidator_account, Some(nonce)),
+                Err(Reason::NothingToLiquidate)
+            );
+            assert_eq!(
+                AssetBalances::get(eth_asset, borrower_account),
+                Balance::from_nominal("-10", ETH).value
+            );
+            assert_eq!(Nonces::get(liquidator_account), nonce);
         });
     }
 
@@ -946,28 +1759,48 @@ mod tests {
         });
     }
 
-    // TODO: Implement max
     #[test]
-    #[should_panic(expected = "Not supported")]
     fn exec_trx_liquidate_cash_borrowed_max() {
         new_test_ext().execute_with(|| {
             let eth_asset = init_eth_asset().unwrap();
-            let _borrower_account = ChainAccount::Eth([1; 20]);
+            let borrower_account = ChainAccount::Eth([1; 20]);
             let liquidator_account = ChainAccount::Eth([2; 20]);
             init_asset_balance(
                 eth_asset,
-                liquidator_account,
-                Balance::from_nominal("3", ETH).value,
+                borrower_account,
+                Balance::from_nominal("0.54", ETH).value,
             );
+            init_cash(borrower_account, CashPrincipal::from_nominal("-10000"));
+            init_cash(liquidator_account, CashPrincipal::from_nominal("4000"));
 
-            // Liquidate 1e18 Eth
+            // Max is capped by the borrower's Eth collateral, i.e. 1000 Cash
             let req_str = "(Liquidate Max CASH Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee \
                 Eth:0x0101010101010101010101010101010101010101)";
             let nonce = 0;
 
-            let _ = exec_trx_request::<Test>(req_str, liquidator_account, Some(nonce));
+            assert_ok!(exec_trx_request::<Test>(
+                req_str,
+                liquidator_account,
+                Some(nonce)
+            ));
 
-            // TODO: Check balances
+            assert_eq!(
+                AssetBalances::get(eth_asset, liquidator_account),
+                Balance::from_nominal("0.54", ETH).value
+            );
+            assert_eq!(
+                AssetBalances::get(eth_asset, borrower_account),
+                Balance::from_nominal("0", ETH).value
+            );
+            assert_eq!(
+                CashPrincipals::get(liquidator_account),
+                CashPrincipal::from_nominal("3000")
+            );
+            assert_eq!(
+                CashPrincipals::get(borrower_account),
+                CashPrincipal::from_nominal("-9000")
+            );
+            assert_eq!(Nonces::get(liquidator_account), nonce + 1);
         });
     }
 
@@ -1093,30 +1926,58 @@ mod tests {
         });
     }
 
-    // TODO: Implement max
     #[test]
-    #[should_panic(expected = "Not supported")]
     fn exec_trx_liquidate_asset_for_asset_max() {
         new_test_ext().execute_with(|| {
-            let _wbtc_asset = init_wbtc_asset().unwrap();
+            let wbtc_asset = init_wbtc_asset().unwrap();
             let eth_asset = init_eth_asset().unwrap();
-            let _borrower_account = ChainAccount::Eth([1; 20]);
+            let borrower_account = ChainAccount::Eth([1; 20]);
             let liquidator_account = ChainAccount::Eth([2; 20]);
             init_asset_balance(
                 eth_asset,
+                borrower_account,
+                Balance::from_nominal("129.6", ETH).value,
+            );
+            init_asset_balance(
+                wbtc_asset,
+                borrower_account,
+                Balance::from_nominal("-5", WBTC).value,
+            );
+            init_asset_balance(
+                wbtc_asset,
                 liquidator_account,
-                Balance::from_nominal("3", ETH).value,
+                Balance::from_nominal("3", WBTC).value,
             );
 
-            // Liquidate 1e18 Eth
+            // Max is capped by the liquidator's WBTC balance, i.e. 3 WBTC
             let req_str = "(Liquidate Max Eth:0xbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb \
                 Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee \
                 Eth:0x0101010101010101010101010101010101010101)";
             let nonce = 0;
 
-            let _ = exec_trx_request::<Test>(req_str, liquidator_account, Some(nonce));
+            assert_ok!(exec_trx_request::<Test>(
+                req_str,
+                liquidator_account,
+                Some(nonce)
+            ));
 
-            // TODO: Check balances
+            assert_eq!(
+                AssetBalances::get(eth_asset, liquidator_account),
+                Balance::from_nominal("97.2", ETH).value
+            );
+            assert_eq!(
+                AssetBalances::get(eth_asset, borrower_account),
+                Balance::from_nominal("32.4", ETH).value
+            );
+            assert_eq!(
+                AssetBalances::get(wbtc_asset, liquidator_account),
+                Balance::from_nominal("0", WBTC).value
+            );
+            assert_eq!(
+                AssetBalances::get(wbtc_asset, borrower_account),
+                Balance::from_nominal("-2", WBTC).value
+            );
+            assert_eq!(Nonces::get(liquidator_account), nonce + 1);
         });
     }
 
@@ -1142,7 +2003,12 @@ mod tests {
 
             assert_eq!(
                 exec_trx_request::<Test>(req_str, account, nonce),
-                Err(Reason::TrxRequestParseError(TrxReqParseError::LexError))
+                Err(Reason::TrxRequestParseError(SpannedTrxReqParseError {
+                    error: TrxReqParseError::LexError,
+                    start: 8,
+                    end: 9,
+                    expected: TrxReqExpected::Token,
+                }))
             );
         });
     }
@@ -1157,10 +2023,43 @@ mod tests {
             let res = exec_trx_request::<Test>(req_str, account, nonce);
             assert_eq!(
                 res,
-                Err(Reason::TrxRequestParseError(
-                    TrxReqParseError::InvalidChainAccount
-                ))
+                Err(Reason::TrxRequestParseError(SpannedTrxReqParseError {
+                    error: TrxReqParseError::InvalidChainAccount(ChainId::Eth),
+                    start: 22,
+                    end: 27,
+                    expected: TrxReqExpected::Account,
+                }))
             );
         });
     }
+
+    #[test]
+    fn test_trx_request_value() {
+        new_test_ext().execute_with(|| {
+            let account = ChainAccount::Eth([2; 20]);
+            let asset = init_eth_asset().unwrap();
+            init_asset_balance(asset, account, Balance::from_nominal("2", ETH).value);
+            init_cash(account, CashPrincipal::from_nominal("5"));
+
+            assert_eq!(
+                trx_request_value::<Test>(
+                    "(Extract 1000000000000000000 Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee Eth:0x0101010101010101010101010101010101010101)",
+                    account
+                ),
+                Ok(Quantity::from_nominal("2000", USD))
+            );
+            assert_eq!(
+                trx_request_value::<Test>(
+                    "(Extract 3000000 CASH Eth:0x0101010101010101010101010101010101010101)",
+                    account
+                ),
+                Ok(Quantity::from_nominal("3", USD))
+            );
+            assert!(trx_request_value::<Test>(
+                "(Extract 3000000000000000000 Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee Eth:0x0101010101010101010101010101010101010101)",
+                account
+            )
+            .is_err());
+        });
+    }
 }
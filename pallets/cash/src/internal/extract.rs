@@ -18,14 +18,33 @@ pub fn extract_internal<T: Config>(
     quantity: AssetQuantity,
 ) -> Result<(), Reason> {
     log!("extract_cash_principal_internal");
+    prepare_extract_internal::<T>(CashPipeline::new(), asset, sender, quantity)?.commit::<T>();
+    emit_extract_internal::<T>(asset, sender, recipient, quantity);
+    Ok(())
+}
+
+/// Apply an asset extraction to the pipeline, without committing it.
+pub fn prepare_extract_internal<T: Config>(
+    pipeline: CashPipeline,
+    asset: AssetInfo,
+    sender: ChainAccount,
+    quantity: AssetQuantity,
+) -> Result<CashPipeline, Reason> {
     require_min_tx_value!(internal::assets::get_value::<T>(quantity)?);
 
-    CashPipeline::new()
+    pipeline
         .extract_asset::<T>(sender, asset.asset, quantity)?
         .check_collateralized::<T>(sender)?
-        .check_sufficient_total_funds::<T>(asset)?
-        .commit::<T>();
+        .check_sufficient_total_funds::<T>(asset)
+}
 
+/// Dispatch the notice and event for an asset extraction, once it has been committed.
+pub fn emit_extract_internal<T: Config>(
+    asset: AssetInfo,
+    sender: ChainAccount,
+    recipient: ChainAccount,
+    quantity: AssetQuantity,
+) {
     internal::notices::dispatch_extraction_notice::<T>(asset.asset, recipient, quantity);
 
     <Module<T>>::deposit_event(Event::Extract(
@@ -34,8 +53,6 @@ pub fn extract_internal<T: Config>(
         recipient,
         quantity.value,
     ));
-
-    Ok(())
 }
 
 pub fn extract_cash_principal_internal<T: Config>(
@@ -43,20 +60,38 @@ pub fn extract_cash_principal_internal<T: Config>(
     recipient: ChainAccount,
     principal: CashPrincipalAmount,
 ) -> Result<(), Reason> {
+    prepare_extract_cash_principal_internal::<T>(CashPipeline::new(), sender, principal)?
+        .commit::<T>();
+    emit_extract_cash_principal_internal::<T>(sender, recipient, principal);
+    Ok(())
+}
+
+/// Apply a CASH extraction to the pipeline, without committing it.
+pub fn prepare_extract_cash_principal_internal<T: Config>(
+    pipeline: CashPipeline,
+    sender: ChainAccount,
+    principal: CashPrincipalAmount,
+) -> Result<CashPipeline, Reason> {
     let index: CashIndex = GlobalCashIndex::get();
     let amount = index.cash_quantity(principal)?;
     require_min_tx_value!(internal::assets::get_value::<T>(amount)?);
 
-    CashPipeline::new()
+    pipeline
         .extract_cash::<T>(sender, principal)?
-        .check_collateralized::<T>(sender)?
-        .commit::<T>();
+        .check_collateralized::<T>(sender)
+}
+
+/// Dispatch the notice and event for a CASH extraction, once it has been committed.
+pub fn emit_extract_cash_principal_internal<T: Config>(
+    sender: ChainAccount,
+    recipient: ChainAccount,
+    principal: CashPrincipalAmount,
+) {
+    let index: CashIndex = GlobalCashIndex::get();
 
     internal::notices::dispatch_cash_extraction_notice::<T>(recipient, principal);
 
     <Module<T>>::deposit_event(Event::ExtractCash(sender, recipient, principal, index));
-
-    Ok(())
 }
 
 #[cfg(test)]
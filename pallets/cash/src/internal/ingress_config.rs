@@ -0,0 +1,149 @@
+use crate::{
+    chains::ChainId, core::get_ingress_config, reason::Reason, require, types::IngressConfig,
+    Config, Event, IngressConfigs, IngressionQueue, Pallet,
+};
+use frame_support::storage::StorageMap;
+
+pub fn set_ingress_config<T: Config>(
+    chain_id: ChainId,
+    config: IngressConfig,
+) -> Result<(), Reason> {
+    require!(config.is_valid(), Reason::BadIngressConfig);
+
+    // queued events were admitted under the other policy, so they must drain before it changes
+    if config.finalized_only != get_ingress_config::<T>(chain_id).finalized_only {
+        require!(
+            IngressionQueue::get(chain_id).map_or(true, |queue| queue.len() == 0),
+            Reason::IngressQueueNotEmpty
+        );
+    }
+
+    IngressConfigs::insert(chain_id, config);
+
+    <Pallet<T>>::deposit_event(Event::IngressConfigSet(chain_id, config));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_set_ingress_config() {
+        new_test_ext().execute_with(|| {
+            let config = IngressConfig {
+                quota: 5_000_000_000,
+                min_event_blocks: 128,
+                max_event_blocks: 512,
+                slack: 200,
+                max_queue_events: 5000,
+                finalized_only: false,
+            };
+            assert_eq!(
+                get_ingress_config::<Test>(ChainId::Matic),
+                IngressConfig::default()
+            );
+            assert_eq!(set_ingress_config::<Test>(ChainId::Matic, config), Ok(()));
+            assert_eq!(get_ingress_config::<Test>(ChainId::Matic), config);
+            assert_eq!(
+                get_ingress_config::<Test>(ChainId::Eth),
+                IngressConfig::default()
+            );
+
+            let set_ingress_config_event = System::events().into_iter().last().unwrap();
+            assert_eq!(
+                mock::Event::pallet_cash(crate::Event::IngressConfigSet(ChainId::Matic, config)),
+                set_ingress_config_event.event
+            );
+        });
+    }
+
+    #[test]
+    fn test_set_ingress_config_invalid() {
+        new_test_ext().execute_with(|| {
+            let config = IngressConfig {
+                min_event_blocks: 100,
+                max_event_blocks: 10,
+                ..IngressConfig::default()
+            };
+            assert_eq!(
+                set_ingress_config::<Test>(ChainId::Matic, config),
+                Err(Reason::BadIngressConfig)
+            );
+            assert_eq!(
+                set_ingress_config::<Test>(
+                    ChainId::Matic,
+                    IngressConfig {
+                        slack: 0,
+                        ..IngressConfig::default()
+                    }
+                ),
+                Err(Reason::BadIngressConfig)
+            );
+            assert_eq!(IngressConfigs::get(ChainId::Matic), None);
+        });
+    }
+
+    #[test]
+    fn test_set_ingress_config_finalized_only_with_queued_events() {
+        new_test_ext().execute_with(|| {
+            IngressionQueue::insert(
+                ChainId::Eth,
+                ChainBlockEvents::Eth(vec![(
+                    1,
+                    eth_log_event(
+                        0,
+                        ethereum_client::EthereumEvent::NoticeInvoked {
+                            era_id: 0,
+                            era_index: 0,
+                            notice_hash: [0; 32],
+                            result: vec![],
+                        },
+                    ),
+                )]),
+            );
+            let finalized_only = IngressConfig {
+                finalized_only: true,
+                ..IngressConfig::default()
+            };
+            assert_eq!(
+                set_ingress_config::<Test>(ChainId::Eth, finalized_only),
+                Err(Reason::IngressQueueNotEmpty)
+            );
+            assert_eq!(IngressConfigs::get(ChainId::Eth), None);
+
+            // the rest of the config can still change
+            let config = IngressConfig {
+                quota: 1_000,
+                ..IngressConfig::default()
+            };
+            assert_eq!(set_ingress_config::<Test>(ChainId::Eth, config), Ok(()));
+
+            IngressionQueue::insert(ChainId::Eth, ChainBlockEvents::Eth(vec![]));
+            assert_eq!(
+                set_ingress_config::<Test>(ChainId::Eth, finalized_only),
+                Ok(())
+            );
+            assert_eq!(get_ingress_config::<Test>(ChainId::Eth), finalized_only);
+        });
+    }
+
+    #[test]
+    fn test_get_ingress_config_evm_default() {
+        new_test_ext().execute_with(|| {
+            crate::EvmChains::insert(
+                42161,
+                crate::chains::EvmChainConfig {
+                    starport: [1; 20],
+                    rpc_url_key: String::from("ARB_RPC_URL"),
+                    confirmation_depth: 20,
+                },
+            );
+            let config = get_ingress_config::<Test>(ChainId::Evm(42161));
+            assert_eq!(config.min_event_blocks, 20);
+            assert_eq!(config.quota, IngressConfig::default().quota);
+        });
+    }
+}
@@ -9,17 +9,18 @@ use crate::{
     reason::Reason,
     require, require_min_tx_value,
     symbol::Units,
-    types::{AssetInfo, AssetQuantity, CashPrincipalAmount, Quantity, CASH},
+    types::{AssetAmount, AssetInfo, AssetQuantity, Balance, CashPrincipalAmount, Quantity, CASH},
     Config, Event, GlobalCashIndex, Module,
 };
 use frame_support::storage::StorageValue;
-use our_std::result::Result;
+use our_std::{cmp::min, result::Result};
+
+const LIQUIDATION_INCENTIVE: Factor = Factor::from_nominal("1.08"); // XXX spec first
 
 fn calculate_seize_quantity<T: Config>(
     quantity: AssetQuantity,
     collateral_units: Units,
 ) -> Result<Quantity, Reason> {
-    let liquidation_incentive = Factor::from_nominal("1.08"); // XXX spec first
     let asset_price = get_price::<T>(quantity.units)?;
     let collateral_price = get_price::<T>(collateral_units)?;
 
@@ -28,11 +29,112 @@ fn calculate_seize_quantity<T: Config>(
     }
 
     Ok(quantity
-        .mul_factor(liquidation_incentive)?
+        .mul_factor(LIQUIDATION_INCENTIVE)?
         .mul_price(asset_price)?
         .div_price(collateral_price, collateral_units)?)
 }
 
+/// Calculate the largest amount which may be repaid in exchange for the given collateral.
+/// This is the inverse of `calculate_seize_quantity`, rounded down so as never to seize too much.
+fn calculate_repay_amount<T: Config>(
+    collateral_balance: Balance,
+    units: Units,
+) -> Result<AssetAmount, Reason> {
+    if collateral_balance.value <= 0 {
+        return Ok(0);
+    }
+
+    let asset_price = get_price::<T>(units)?;
+    let collateral_price = get_price::<T>(collateral_balance.units)?;
+
+    if asset_price.value == 0 || collateral_price.value == 0 {
+        Err(Reason::NoPrice)?
+    }
+
+    Ok(Quantity::new(
+        collateral_balance.value as AssetAmount,
+        collateral_balance.units,
+    )
+    .mul_price(collateral_price)?
+    .div_price(asset_price, units)?
+    .div_factor(LIQUIDATION_INCENTIVE)?
+    .value)
+}
+
+/// Calculate the maximum quantity of an asset the liquidator can repay in exchange for collateral.
+/// This is the borrower's debt, capped by the liquidator's balance and the borrower's collateral.
+pub fn max_liquidate_quantity<T: Config>(
+    pipeline: &CashPipeline,
+    asset: AssetInfo,
+    collateral_asset: AssetInfo,
+    liquidator: ChainAccount,
+    borrower: ChainAccount,
+) -> Result<AssetQuantity, Reason> {
+    let portfolio = pipeline.state.build_portfolio::<T>(borrower)?;
+    let debt = portfolio.get_asset_balance(asset).amount_repayable().value;
+    let held = pipeline
+        .state
+        .get_asset_balance::<T>(asset, liquidator)
+        .amount_withdrawable()
+        .value;
+    let repayable =
+        calculate_repay_amount::<T>(portfolio.get_asset_balance(collateral_asset), asset.units())?;
+
+    let amount = min(debt, min(held, repayable));
+    require!(amount > 0, Reason::NothingToLiquidate);
+    Ok(asset.as_quantity(amount))
+}
+
+/// Calculate the maximum CASH principal the liquidator can repay in exchange for collateral.
+/// This is the borrower's debt, capped by the liquidator's balance and the borrower's collateral.
+pub fn max_liquidate_cash_principal<T: Config>(
+    pipeline: &CashPipeline,
+    collateral_asset: AssetInfo,
+    liquidator: ChainAccount,
+    borrower: ChainAccount,
+) -> Result<CashPrincipalAmount, Reason> {
+    let index = GlobalCashIndex::get();
+    let portfolio = pipeline.state.build_portfolio::<T>(borrower)?;
+    let debt = pipeline
+        .state
+        .get_cash_principal::<T>(borrower)
+        .amount_repayable()?;
+    let held = pipeline
+        .state
+        .get_cash_principal::<T>(liquidator)
+        .amount_withdrawable()?;
+    let repayable = index.cash_principal_amount(Quantity::new(
+        calculate_repay_amount::<T>(portfolio.get_asset_balance(collateral_asset), CASH)?,
+        CASH,
+    ))?;
+
+    let principal = min(debt.0, min(held.0, repayable.0));
+    require!(principal > 0, Reason::NothingToLiquidate);
+    Ok(CashPrincipalAmount(principal))
+}
+
+/// Calculate the maximum quantity of an asset the liquidator can repay in exchange for CASH collateral.
+/// This is the borrower's debt, capped by the liquidator's balance and the borrower's CASH.
+pub fn max_liquidate_cash_collateral_quantity<T: Config>(
+    pipeline: &CashPipeline,
+    asset: AssetInfo,
+    liquidator: ChainAccount,
+    borrower: ChainAccount,
+) -> Result<AssetQuantity, Reason> {
+    let portfolio = pipeline.state.build_portfolio::<T>(borrower)?;
+    let debt = portfolio.get_asset_balance(asset).amount_repayable().value;
+    let held = pipeline
+        .state
+        .get_asset_balance::<T>(asset, liquidator)
+        .amount_withdrawable()
+        .value;
+    let repayable = calculate_repay_amount::<T>(portfolio.cash, asset.units())?;
+
+    let amount = min(debt, min(held, repayable));
+    require!(amount > 0, Reason::NothingToLiquidate);
+    Ok(asset.as_quantity(amount))
+}
+
 pub fn liquidate_internal<T: Config>(
     asset: AssetInfo,
     collateral_asset: AssetInfo,
@@ -40,11 +142,33 @@ pub fn liquidate_internal<T: Config>(
     borrower: ChainAccount,
     quantity: AssetQuantity,
 ) -> Result<(), Reason> {
+    prepare_liquidate_internal::<T>(
+        CashPipeline::new(),
+        asset,
+        collateral_asset,
+        liquidator,
+        borrower,
+        quantity,
+    )?
+    .commit::<T>();
+    emit_liquidate_internal::<T>(asset, collateral_asset, liquidator, borrower, quantity);
+    Ok(())
+}
+
+/// Apply an asset for asset liquidation to the pipeline, without committing it.
+pub fn prepare_liquidate_internal<T: Config>(
+    pipeline: CashPipeline,
+    asset: AssetInfo,
+    collateral_asset: AssetInfo,
+    liquidator: ChainAccount,
+    borrower: ChainAccount,
+    quantity: AssetQuantity,
+) -> Result<CashPipeline, Reason> {
     require!(asset != collateral_asset, Reason::InKindLiquidation);
     require_min_tx_value!(get_value::<T>(quantity)?);
     let seize_quantity = calculate_seize_quantity::<T>(quantity, collateral_asset.units())?;
 
-    CashPipeline::new()
+    pipeline
         .check_underwater::<T>(borrower)?
         .transfer_asset::<T>(liquidator, borrower, asset.asset, quantity)?
         .transfer_asset::<T>(borrower, liquidator, collateral_asset.asset, seize_quantity)?
@@ -54,9 +178,17 @@ pub fn liquidate_internal<T: Config>(
         .check_asset_balance::<T, _>(borrower, collateral_asset, |collateral_balance| {
             must!(collateral_balance.gte(0), Reason::InsufficientCollateral)
         })?
-        .check_collateralized::<T>(liquidator)?
-        .commit::<T>();
+        .check_collateralized::<T>(liquidator)
+}
 
+/// Deposit the event for an asset for asset liquidation, once it has been committed.
+pub fn emit_liquidate_internal<T: Config>(
+    asset: AssetInfo,
+    collateral_asset: AssetInfo,
+    liquidator: ChainAccount,
+    borrower: ChainAccount,
+    quantity: AssetQuantity,
+) {
     <Module<T>>::deposit_event(Event::Liquidate(
         asset.asset,
         collateral_asset.asset,
@@ -64,8 +196,6 @@ pub fn liquidate_internal<T: Config>(
         borrower,
         quantity.value,
     ));
-
-    Ok(())
 }
 
 pub fn liquidate_cash_principal_internal<T: Config>(
@@ -74,13 +204,33 @@ pub fn liquidate_cash_principal_internal<T: Config>(
     borrower: ChainAccount,
     principal: CashPrincipalAmount,
 ) -> Result<(), Reason> {
+    prepare_liquidate_cash_principal_internal::<T>(
+        CashPipeline::new(),
+        collateral_asset,
+        liquidator,
+        borrower,
+        principal,
+    )?
+    .commit::<T>();
+    emit_liquidate_cash_principal_internal::<T>(collateral_asset, liquidator, borrower, principal);
+    Ok(())
+}
+
+/// Apply a liquidation of borrowed CASH to the pipeline, without committing it.
+pub fn prepare_liquidate_cash_principal_internal<T: Config>(
+    pipeline: CashPipeline,
+    collateral_asset: AssetInfo,
+    liquidator: ChainAccount,
+    borrower: ChainAccount,
+    principal: CashPrincipalAmount,
+) -> Result<CashPipeline, Reason> {
     let index = GlobalCashIndex::get();
     let quantity = index.cash_quantity(principal)?;
 
     require_min_tx_value!(get_value::<T>(quantity)?);
     let seize_quantity = calculate_seize_quantity::<T>(quantity, collateral_asset.units())?;
 
-    CashPipeline::new()
+    pipeline
         .check_underwater::<T>(borrower)?
         .transfer_cash::<T>(liquidator, borrower, principal)?
         .transfer_asset::<T>(borrower, liquidator, collateral_asset.asset, seize_quantity)?
@@ -90,8 +240,17 @@ pub fn liquidate_cash_principal_internal<T: Config>(
         .check_asset_balance::<T, _>(borrower, collateral_asset, |collateral_balance| {
             must!(collateral_balance.gte(0), Reason::InsufficientCollateral)
         })?
-        .check_collateralized::<T>(liquidator)?
-        .commit::<T>();
+        .check_collateralized::<T>(liquidator)
+}
+
+/// Deposit the event for a liquidation of borrowed CASH, once it has been committed.
+pub fn emit_liquidate_cash_principal_internal<T: Config>(
+    collateral_asset: AssetInfo,
+    liquidator: ChainAccount,
+    borrower: ChainAccount,
+    principal: CashPrincipalAmount,
+) {
+    let index = GlobalCashIndex::get();
 
     <Module<T>>::deposit_event(Event::LiquidateCash(
         collateral_asset.asset,
@@ -100,8 +259,6 @@ pub fn liquidate_cash_principal_internal<T: Config>(
         principal,
         index,
     ));
-
-    Ok(())
 }
 
 pub fn liquidate_cash_collateral_internal<T: Config>(
@@ -110,13 +267,33 @@ pub fn liquidate_cash_collateral_internal<T: Config>(
     borrower: ChainAccount,
     quantity: AssetQuantity,
 ) -> Result<(), Reason> {
+    prepare_liquidate_cash_collateral_internal::<T>(
+        CashPipeline::new(),
+        asset,
+        liquidator,
+        borrower,
+        quantity,
+    )?
+    .commit::<T>();
+    emit_liquidate_cash_collateral_internal::<T>(asset, liquidator, borrower, quantity);
+    Ok(())
+}
+
+/// Apply a liquidation of CASH collateral to the pipeline, without committing it.
+pub fn prepare_liquidate_cash_collateral_internal<T: Config>(
+    pipeline: CashPipeline,
+    asset: AssetInfo,
+    liquidator: ChainAccount,
+    borrower: ChainAccount,
+    quantity: AssetQuantity,
+) -> Result<CashPipeline, Reason> {
     let index = GlobalCashIndex::get();
 
     require_min_tx_value!(get_value::<T>(quantity)?);
     let seize_quantity = calculate_seize_quantity::<T>(quantity, CASH)?;
     let seize_principal = index.cash_principal_amount(seize_quantity)?;
 
-    CashPipeline::new()
+    pipeline
         .check_underwater::<T>(borrower)?
         .transfer_asset::<T>(liquidator, borrower, asset.asset, quantity)?
         .transfer_cash::<T>(borrower, liquidator, seize_principal)?
@@ -126,17 +303,22 @@ pub fn liquidate_cash_collateral_internal<T: Config>(
         .check_cash_principal::<T, _>(borrower, |cash_principal| {
             must!(cash_principal.gte(0), Reason::InsufficientCollateral)
         })?
-        .check_collateralized::<T>(liquidator)?
-        .commit::<T>();
+        .check_collateralized::<T>(liquidator)
+}
 
+/// Deposit the event for a liquidation of CASH collateral, once it has been committed.
+pub fn emit_liquidate_cash_collateral_internal<T: Config>(
+    asset: AssetInfo,
+    liquidator: ChainAccount,
+    borrower: ChainAccount,
+    quantity: AssetQuantity,
+) {
     <Module<T>>::deposit_event(Event::LiquidateCashCollateral(
         asset.asset,
         liquidator,
         borrower,
         quantity.value,
     ));
-
-    Ok(())
 }
 
 #[cfg(test)]
@@ -254,6 +436,45 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_calculate_repay_amount_ok() {
+        new_test_ext().execute_with(|| {
+            init_eth_asset().unwrap();
+            init_wbtc_asset().unwrap();
+            pallet_oracle::Prices::insert(
+                ETH.ticker,
+                Price::from_nominal(ETH.ticker, "2000").value,
+            );
+            pallet_oracle::Prices::insert(
+                WBTC.ticker,
+                Price::from_nominal(WBTC.ticker, "60000").value,
+            );
+
+            assert_eq!(
+                calculate_repay_amount::<Test>(wbtc.as_balance_nominal("0.036"), ETH),
+                Ok(1000000000000000000) // 0.036 * 60000 / 2000 / 1.08 = 1e18
+            );
+            assert_eq!(
+                calculate_repay_amount::<Test>(wbtc.as_balance_nominal("-1"), ETH),
+                Ok(0)
+            );
+        })
+    }
+
+    #[test]
+    fn test_calculate_repay_amount_zero_price() {
+        new_test_ext().execute_with(|| {
+            init_eth_asset().unwrap();
+            init_wbtc_asset().unwrap();
+            pallet_oracle::Prices::insert(WBTC.ticker, Price::from_nominal(WBTC.ticker, "0").value);
+
+            assert_eq!(
+                calculate_repay_amount::<Test>(wbtc.as_balance_nominal("1"), ETH),
+                Err(Reason::NoPrice)
+            );
+        })
+    }
+
     #[test]
     fn test_calculate_seize_quantity_ok() {
         new_test_ext().execute_with(|| {
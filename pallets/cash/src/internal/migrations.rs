@@ -0,0 +1,306 @@
+use crate::{
+    chains::{
+        ChainBlock, ChainBlockEvent, ChainBlockEvents, ChainBlockNumber, UNKNOWN_TRANSACTION_HASH,
+    },
+    reason::Reason,
+    AssetSymbols, Config, DeadLetterEvents, FirstBlock, IngressionQueue, LastProcessedBlock,
+    PendingChainBlocks, PendingChainReorgs, StorageVersion, SupportedAssets,
+};
+use ethereum_client::{EthereumBlock, EthereumEvent, EthereumLogEvent};
+use frame_support::{
+    storage::{IterableStorageMap, StorageDoubleMap, StoragePrefixedMap, StorageValue},
+    traits::Get,
+    weights::Weight,
+};
+use our_std::vec::Vec;
+
+/// The version of the storage layout, which is bumped by each migration.
+pub const STORAGE_VERSION: u32 = 2;
+
+/// Runtime upgrade hook, running each migration the storage has not yet been through.
+pub fn on_runtime_upgrade<T: Config>() -> Weight {
+    let version = StorageVersion::get();
+    let mut weight = T::DbWeight::get().reads(1);
+
+    if version < 1 {
+        weight = weight.saturating_add(index_asset_symbols::<T>());
+    }
+
+    if version < 2 {
+        weight = weight.saturating_add(migrate_eth_log_events::<T>());
+    }
+
+    if version < STORAGE_VERSION {
+        StorageVersion::put(STORAGE_VERSION);
+        weight = weight.saturating_add(T::DbWeight::get().writes(1));
+    }
+
+    weight
+}
+
+/// Version 1: index the supported assets by chain and symbol.
+fn index_asset_symbols<T: Config>() -> Weight {
+    let mut count = 0;
+    for asset_info in SupportedAssets::iter_values() {
+        AssetSymbols::insert(
+            asset_info.asset.chain_id(),
+            asset_info.symbol,
+            asset_info.asset,
+        );
+        count += 1;
+    }
+    T::DbWeight::get().reads_writes(count, count)
+}
+
+/// Version 2: re-encode the stored Ethereum-like events, which now carry the log that emitted them.
+/// The transaction and log index of events already stored are unknown, and left zeroed.
+/// Pending block and reorg tallies are dropped rather than migrated, workers just submit them again.
+fn migrate_eth_log_events<T: Config>() -> Weight {
+    let mut count = 0;
+    FirstBlock::translate::<v1::ChainBlock, _>(|_chain_id, block| {
+        count += 1;
+        Some(block.migrate())
+    });
+    LastProcessedBlock::translate::<v1::ChainBlock, _>(|_chain_id, block| {
+        count += 1;
+        Some(block.migrate())
+    });
+    IngressionQueue::translate::<v1::ChainBlockEvents, _>(|_chain_id, events| {
+        count += 1;
+        Some(events.migrate())
+    });
+    DeadLetterEvents::translate::<Vec<(v1::ChainBlockEvent, Reason)>, _>(
+        |_chain_id, dead_letters| {
+            count += 1;
+            Some(
+                dead_letters
+                    .into_iter()
+                    .map(|(event, reason)| (event.migrate(), reason))
+                    .collect(),
+            )
+        },
+    );
+    PendingChainBlocks::remove_all();
+    PendingChainReorgs::remove_all();
+    T::DbWeight::get().reads_writes(count, count + 2)
+}
+
+fn unknown_log_event(event: EthereumEvent) -> EthereumLogEvent {
+    EthereumLogEvent {
+        transaction_hash: UNKNOWN_TRANSACTION_HASH,
+        log_index: 0,
+        event,
+    }
+}
+
+fn unknown_log_events(
+    events: Vec<(ChainBlockNumber, EthereumEvent)>,
+) -> Vec<(ChainBlockNumber, EthereumLogEvent)> {
+    events
+        .into_iter()
+        .map(|(block_num, event)| (block_num, unknown_log_event(event)))
+        .collect()
+}
+
+/// The storage encoding of chain blocks and events, before Ethereum-like events carried their log.
+mod v1 {
+    use super::*;
+    use crate::chains::EvmChainId;
+    use codec::{Decode, Encode};
+    use ethereum_client::{EthereumBlockNumber, EthereumHash};
+    use polkadot_client::{PolkadotBlock, PolkadotEvent};
+
+    #[derive(Encode, Decode)]
+    pub struct EthereumBlock {
+        pub hash: EthereumHash,
+        pub parent_hash: EthereumHash,
+        pub number: EthereumBlockNumber,
+        pub events: Vec<EthereumEvent>,
+    }
+
+    impl EthereumBlock {
+        pub fn migrate(self) -> super::EthereumBlock {
+            super::EthereumBlock {
+                hash: self.hash,
+                parent_hash: self.parent_hash,
+                number: self.number,
+                events: self.events.into_iter().map(unknown_log_event).collect(),
+            }
+        }
+    }
+
+    #[derive(Encode, Decode)]
+    pub enum ChainBlock {
+        Eth(EthereumBlock),
+        Matic(EthereumBlock),
+        Dot(PolkadotBlock),
+        Evm(EvmChainId, EthereumBlock),
+    }
+
+    impl ChainBlock {
+        pub fn migrate(self) -> super::ChainBlock {
+            match self {
+                ChainBlock::Eth(block) => super::ChainBlock::Eth(block.migrate()),
+                ChainBlock::Matic(block) => super::ChainBlock::Matic(block.migrate()),
+                ChainBlock::Dot(block) => super::ChainBlock::Dot(block),
+                ChainBlock::Evm(evm_chain_id, block) => {
+                    super::ChainBlock::Evm(evm_chain_id, block.migrate())
+                }
+            }
+        }
+    }
+
+    #[derive(Encode, Decode)]
+    pub enum ChainBlockEvent {
+        Reserved,
+        Eth(ChainBlockNumber, EthereumEvent),
+        Matic(ChainBlockNumber, EthereumEvent),
+        Dot(ChainBlockNumber, PolkadotEvent),
+        Evm(EvmChainId, ChainBlockNumber, EthereumEvent),
+    }
+
+    impl ChainBlockEvent {
+        pub fn migrate(self) -> super::ChainBlockEvent {
+            match self {
+                ChainBlockEvent::Reserved => super::ChainBlockEvent::Reserved,
+                ChainBlockEvent::Eth(block_num, event) => {
+                    super::ChainBlockEvent::Eth(block_num, unknown_log_event(event))
+                }
+                ChainBlockEvent::Matic(block_num, event) => {
+                    super::ChainBlockEvent::Matic(block_num, unknown_log_event(event))
+                }
+                ChainBlockEvent::Dot(block_num, event) => {
+                    super::ChainBlockEvent::Dot(block_num, event)
+                }
+                ChainBlockEvent::Evm(evm_chain_id, block_num, event) => {
+                    super::ChainBlockEvent::Evm(evm_chain_id, block_num, unknown_log_event(event))
+                }
+            }
+        }
+    }
+
+    #[derive(Encode, Decode)]
+    pub enum ChainBlockEvents {
+        Reserved,
+        Eth(Vec<(ChainBlockNumber, EthereumEvent)>),
+        Matic(Vec<(ChainBlockNumber, EthereumEvent)>),
+        Dot(Vec<(ChainBlockNumber, PolkadotEvent)>),
+        Evm(EvmChainId, Vec<(ChainBlockNumber, EthereumEvent)>),
+    }
+
+    impl ChainBlockEvents {
+        pub fn migrate(self) -> super::ChainBlockEvents {
+            match self {
+                ChainBlockEvents::Reserved => super::ChainBlockEvents::Reserved,
+                ChainBlockEvents::Eth(events) => {
+                    super::ChainBlockEvents::Eth(unknown_log_events(events))
+                }
+                ChainBlockEvents::Matic(events) => {
+                    super::ChainBlockEvents::Matic(unknown_log_events(events))
+                }
+                ChainBlockEvents::Dot(events) => super::ChainBlockEvents::Dot(events),
+                ChainBlockEvents::Evm(evm_chain_id, events) => {
+                    super::ChainBlockEvents::Evm(evm_chain_id, unknown_log_events(events))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        chains::ChainId,
+        tests::{assets::*, mock::*},
+    };
+    use frame_support::storage::{unhashed, StorageMap};
+
+    fn lock_event() -> EthereumEvent {
+        EthereumEvent::Lock {
+            asset: [4u8; 20],
+            sender: [5u8; 20],
+            chain: String::from("ETH"),
+            recipient: [6u8; 32],
+            amount: 100,
+        }
+    }
+
+    #[test]
+    fn test_on_runtime_upgrade_indexes_asset_symbols() {
+        new_test_ext().execute_with(|| {
+            SupportedAssets::insert(&Eth, eth);
+            SupportedAssets::insert(&Wbtc, wbtc);
+
+            on_runtime_upgrade::<Test>();
+            assert_eq!(StorageVersion::get(), STORAGE_VERSION);
+            assert_eq!(AssetSymbols::get(ChainId::Eth, eth.symbol), Some(Eth));
+            assert_eq!(AssetSymbols::get(ChainId::Eth, wbtc.symbol), Some(Wbtc));
+        });
+    }
+
+    #[test]
+    fn test_on_runtime_upgrade_runs_once() {
+        new_test_ext().execute_with(|| {
+            StorageVersion::put(STORAGE_VERSION);
+            SupportedAssets::insert(&Eth, eth);
+
+            on_runtime_upgrade::<Test>();
+            assert_eq!(AssetSymbols::get(ChainId::Eth, eth.symbol), None);
+        });
+    }
+
+    #[test]
+    fn test_on_runtime_upgrade_migrates_eth_log_events() {
+        new_test_ext().execute_with(|| {
+            StorageVersion::put(1);
+            let block = v1::ChainBlock::Eth(v1::EthereumBlock {
+                hash: [1u8; 32],
+                parent_hash: [0u8; 32],
+                number: 2,
+                events: vec![lock_event()],
+            });
+            unhashed::put(&LastProcessedBlock::hashed_key_for(ChainId::Eth), &block);
+            unhashed::put(
+                &IngressionQueue::hashed_key_for(ChainId::Eth),
+                &v1::ChainBlockEvents::Eth(vec![(2, lock_event())]),
+            );
+            unhashed::put(
+                &DeadLetterEvents::hashed_key_for(ChainId::Eth),
+                &vec![(
+                    v1::ChainBlockEvent::Eth(1, lock_event()),
+                    Reason::InsufficientChainCash,
+                )],
+            );
+            let pending_key = PendingChainBlocks::hashed_key_for(ChainId::Eth);
+            unhashed::put(&pending_key, &vec![block]);
+
+            on_runtime_upgrade::<Test>();
+            assert_eq!(StorageVersion::get(), STORAGE_VERSION);
+            assert_eq!(
+                LastProcessedBlock::get(ChainId::Eth),
+                Some(ChainBlock::Eth(EthereumBlock {
+                    hash: [1u8; 32],
+                    parent_hash: [0u8; 32],
+                    number: 2,
+                    events: vec![unknown_log_event(lock_event())],
+                }))
+            );
+            assert_eq!(
+                IngressionQueue::get(ChainId::Eth),
+                Some(ChainBlockEvents::Eth(vec![(
+                    2,
+                    unknown_log_event(lock_event())
+                )]))
+            );
+            assert_eq!(
+                DeadLetterEvents::get(ChainId::Eth),
+                vec![(
+                    ChainBlockEvent::Eth(1, unknown_log_event(lock_event())),
+                    Reason::InsufficientChainCash
+                )]
+            );
+            assert!(!unhashed::exists(&pending_key));
+        });
+    }
+}
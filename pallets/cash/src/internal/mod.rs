@@ -4,12 +4,15 @@ pub mod change_validators;
 pub mod events;
 pub mod exec_trx_request;
 pub mod extract;
+pub mod ingress_config;
 pub mod initialize;
 pub mod liquidate;
 pub mod lock;
+pub mod migrations;
 pub mod miner;
 pub mod next_code;
 pub mod notices;
+pub mod risk_model;
 pub mod set_yield_next;
 pub mod supply_cap;
 pub mod transfer;
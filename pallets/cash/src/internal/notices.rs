@@ -1,6 +1,6 @@
 use crate::{
     chains::{ChainAccount, ChainAsset, ChainHash, ChainId, ChainSignature},
-    core::recover_validator,
+    core::{get_starport, recover_validator},
     log,
     notices::{
         CashExtractionNotice, ChangeAuthorityNotice, EncodeNotice, ExtractionNotice,
@@ -11,10 +11,12 @@ use crate::{
         AssetAmount, AssetQuantity, CashIndex, CashPrincipalAmount, Reason, Timestamp,
         ValidatorKeys, APR,
     },
-    AccountNotices, Call, Config, Event, LatestNotice, Module, NoticeHashes, NoticeHolds,
-    NoticeStates, Notices,
+    AccountNotices, Call, Config, Event, EvmChains, LatestNotice, Module, NoticeHashes,
+    NoticeHolds, NoticeStates, Notices,
+};
+use frame_support::storage::{
+    IterableStorageDoubleMap, IterableStorageMap, StorageDoubleMap, StorageMap,
 };
-use frame_support::storage::{IterableStorageDoubleMap, StorageDoubleMap, StorageMap};
 use frame_system::offchain::SubmitTransaction;
 
 pub fn dispatch_extraction_notice<T: Config>(
@@ -50,6 +52,29 @@ pub fn dispatch_extraction_notice<T: Config>(
                     account: eth_account,
                     amount: amount.value,
                 },
+                (
+                    ChainAsset::Dot(dot_asset),
+                    ChainAccount::Dot(dot_account),
+                    ChainHash::Dot(dot_parent_hash),
+                ) => ExtractionNotice::Dot {
+                    id: notice_id,
+                    parent: dot_parent_hash,
+                    asset: dot_asset,
+                    account: dot_account,
+                    amount: amount.value,
+                },
+                (
+                    ChainAsset::Evm(evm_chain_id, eth_asset),
+                    ChainAccount::Evm(_, eth_account),
+                    ChainHash::Evm(_, eth_parent_hash),
+                ) => ExtractionNotice::Evm {
+                    evm_chain_id,
+                    id: notice_id,
+                    parent: eth_parent_hash,
+                    asset: eth_asset,
+                    account: eth_account,
+                    amount: amount.value,
+                },
 
                 _ => panic!("XXX not implemented"), // generate these w/ macros?
             })
@@ -83,6 +108,24 @@ pub fn dispatch_cash_extraction_notice<T: Config>(
                         principal: principal.0,
                     }
                 }
+                (ChainAccount::Dot(dot_account), ChainHash::Dot(dot_parent_hash)) => {
+                    CashExtractionNotice::Dot {
+                        id: notice_id,
+                        parent: dot_parent_hash,
+                        account: dot_account,
+                        principal: principal.0,
+                    }
+                }
+                (
+                    ChainAccount::Evm(evm_chain_id, eth_account),
+                    ChainHash::Evm(_, eth_parent_hash),
+                ) => CashExtractionNotice::Evm {
+                    evm_chain_id,
+                    id: notice_id,
+                    parent: eth_parent_hash,
+                    account: eth_account,
+                    principal: principal.0,
+                },
 
                 _ => panic!("XXX not implemented"), // generate these w/ macros?
             })
@@ -113,6 +156,23 @@ pub fn dispatch_supply_cap_notice<T: Config>(chain_asset: ChainAsset, cap: Asset
                         cap,
                     }
                 }
+                (ChainAsset::Dot(dot_asset), ChainHash::Dot(dot_parent_hash)) => {
+                    SetSupplyCapNotice::Dot {
+                        id: notice_id,
+                        parent: dot_parent_hash,
+                        asset: dot_asset,
+                        cap,
+                    }
+                }
+                (ChainAsset::Evm(evm_chain_id, eth_asset), ChainHash::Evm(_, eth_parent_hash)) => {
+                    SetSupplyCapNotice::Evm {
+                        evm_chain_id,
+                        id: notice_id,
+                        parent: eth_parent_hash,
+                        asset: eth_asset,
+                        cap,
+                    }
+                }
 
                 _ => panic!("XXX not implemented"), // generate these w/ macros?
             })
@@ -154,7 +214,43 @@ pub fn dispatch_future_yield_notice<T: Config>(
 
             _ => panic!("XXX not implemented"), // generate these w/ macros?
         })
-    })
+    });
+
+    // the Dot starport is optional, only notify it once it has been set
+    let chain_id = ChainId::Dot;
+    if get_starport::<T>(chain_id).is_ok() {
+        dispatch_notice::<T>(chain_id, None, true, &|notice_id, parent_hash| {
+            Notice::FutureYieldNotice(match parent_hash {
+                ChainHash::Dot(dot_parent_hash) => FutureYieldNotice::Dot {
+                    id: notice_id,
+                    parent: dot_parent_hash,
+                    next_cash_yield: next_yield.0,
+                    next_cash_index: next_yield_index.0,
+                    next_cash_yield_start: next_yield_start,
+                },
+
+                _ => panic!("XXX not implemented"), // generate these w/ macros?
+            })
+        });
+    }
+
+    for evm_chain_id in EvmChains::iter_keys() {
+        let chain_id = ChainId::Evm(evm_chain_id);
+        dispatch_notice::<T>(chain_id, None, true, &|notice_id, parent_hash| {
+            Notice::FutureYieldNotice(match parent_hash {
+                ChainHash::Evm(_, eth_parent_hash) => FutureYieldNotice::Evm {
+                    evm_chain_id,
+                    id: notice_id,
+                    parent: eth_parent_hash,
+                    next_cash_yield: next_yield.0,
+                    next_cash_index: next_yield_index.0,
+                    next_cash_yield_start: next_yield_start,
+                },
+
+                _ => panic!("XXX not implemented"), // generate these w/ macros?
+            })
+        });
+    }
 }
 
 pub fn dispatch_change_authority_notice<T: Config>(validators: Vec<ValidatorKeys>) {
@@ -184,6 +280,38 @@ pub fn dispatch_change_authority_notice<T: Config>(validators: Vec<ValidatorKeys
             _ => panic!("XXX not implemented"), // generate these w/ macros?
         })
     });
+
+    // the Dot starport is optional, only notify it once it has been set
+    let chain_id = ChainId::Dot;
+    if get_starport::<T>(chain_id).is_ok() {
+        dispatch_notice::<T>(chain_id, None, true, &|notice_id, parent_hash| {
+            Notice::ChangeAuthorityNotice(match parent_hash {
+                ChainHash::Dot(dot_parent_hash) => ChangeAuthorityNotice::Dot {
+                    id: notice_id,
+                    parent: dot_parent_hash,
+                    new_authorities: validators.iter().map(|x| x.eth_address).collect::<Vec<_>>(),
+                },
+
+                _ => panic!("XXX not implemented"), // generate these w/ macros?
+            })
+        });
+    }
+
+    for evm_chain_id in EvmChains::iter_keys() {
+        let chain_id = ChainId::Evm(evm_chain_id);
+        dispatch_notice::<T>(chain_id, None, true, &|notice_id, parent_hash| {
+            Notice::ChangeAuthorityNotice(match parent_hash {
+                ChainHash::Evm(_, eth_parent_hash) => ChangeAuthorityNotice::Evm {
+                    evm_chain_id,
+                    id: notice_id,
+                    parent: eth_parent_hash,
+                    new_authorities: validators.iter().map(|x| x.eth_address).collect::<Vec<_>>(),
+                },
+
+                _ => panic!("XXX not implemented"), // generate these w/ macros?
+            })
+        });
+    }
 }
 
 /// Add a notice to the queue and all the secondary indices.
@@ -772,7 +900,7 @@ mod tests {
                 _ => panic!("invalid signature"),
             };
             let notice_state = NoticeState::Pending {
-                signature_pairs: ChainSignatureList::Dot(vec![([0; 32], eth_signature)]),
+                signature_pairs: ChainSignatureList::Dot(vec![([0; 20], eth_signature)]),
             };
             NoticeStates::insert(chain_id, notice_id, notice_state);
             Notices::insert(chain_id, notice_id, notice);
@@ -0,0 +1,93 @@
+use crate::{
+    reason::Reason,
+    require,
+    types::{EventKind, RiskModel},
+    Config, Event, Pallet, RiskModels,
+};
+use frame_support::storage::StorageMap;
+
+pub fn set_risk_model<T: Config>(kind: EventKind, model: RiskModel) -> Result<(), Reason> {
+    require!(model.is_valid(), Reason::BadRiskModel);
+
+    RiskModels::insert(kind, model);
+
+    <Pallet<T>>::deposit_event(Event::RiskModelSet(kind, model));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        chains::ChainAsset,
+        core::get_risk_model,
+        tests::*,
+        types::{Factor, Quantity, USD},
+    };
+
+    #[test]
+    fn test_set_risk_model() {
+        new_test_ext().execute_with(|| {
+            let kind = EventKind::Lock(ChainAsset::Eth([238; 20]));
+            let model = RiskModel {
+                factor: Factor::from_nominal("1.5"),
+                half_life: 4,
+            };
+            assert_eq!(get_risk_model::<Test>(kind), RiskModel::default());
+            assert_eq!(set_risk_model::<Test>(kind, model), Ok(()));
+            assert_eq!(get_risk_model::<Test>(kind), model);
+            assert_eq!(
+                get_risk_model::<Test>(EventKind::Lock(ChainAsset::Eth([1; 20]))),
+                RiskModel::default()
+            );
+
+            let set_risk_model_event = System::events().into_iter().last().unwrap();
+            assert_eq!(
+                mock::Event::pallet_cash(crate::Event::RiskModelSet(kind, model)),
+                set_risk_model_event.event
+            );
+        });
+    }
+
+    #[test]
+    fn test_set_risk_model_invalid() {
+        new_test_ext().execute_with(|| {
+            let model = RiskModel {
+                half_life: 0,
+                ..RiskModel::default()
+            };
+            assert_eq!(
+                set_risk_model::<Test>(EventKind::LockCash, model),
+                Err(Reason::BadRiskModel)
+            );
+            assert_eq!(RiskModels::get(EventKind::LockCash), None);
+        });
+    }
+
+    #[test]
+    fn test_risk_model_risk() {
+        let value = Quantity::from_nominal("1000", USD);
+        assert_eq!(
+            RiskModel::default().risk(value, 0),
+            Ok(Quantity::from_nominal("1000", USD))
+        );
+        assert_eq!(
+            RiskModel::default().risk(value, 2),
+            Ok(Quantity::from_nominal("250", USD))
+        );
+        let model = RiskModel {
+            factor: Factor::from_nominal("2"),
+            half_life: 3,
+        };
+        assert_eq!(
+            model.risk(value, 2),
+            Ok(Quantity::from_nominal("2000", USD))
+        );
+        assert_eq!(
+            model.risk(value, 3),
+            Ok(Quantity::from_nominal("1000", USD))
+        );
+        assert_eq!(model.risk(value, 7), Ok(Quantity::from_nominal("500", USD)));
+    }
+}
@@ -16,17 +16,41 @@ pub fn transfer_internal<T: Config>(
     recipient: ChainAccount,
     amount: AssetQuantity,
 ) -> Result<(), Reason> {
+    prepare_transfer_internal::<T>(CashPipeline::new(), asset, sender, recipient, amount)?
+        .commit::<T>();
+    emit_transfer_internal::<T>(asset, sender, recipient, amount)
+}
+
+/// Apply an asset transfer and its fee to the pipeline, without committing it.
+pub fn prepare_transfer_internal<T: Config>(
+    pipeline: CashPipeline,
+    asset: AssetInfo,
+    sender: ChainAccount,
+    recipient: ChainAccount,
+    amount: AssetQuantity,
+) -> Result<CashPipeline, Reason> {
     let miner = get_some_miner::<T>();
     let index = GlobalCashIndex::get();
     let fee_principal = index.cash_principal_amount(TRANSFER_FEE)?;
 
     require_min_tx_value!(get_value::<T>(amount)?);
 
-    CashPipeline::new()
+    pipeline
         .transfer_asset::<T>(sender, recipient, asset.asset, amount)?
         .transfer_cash::<T>(sender, miner, fee_principal)?
-        .check_collateralized::<T>(sender)?
-        .commit::<T>();
+        .check_collateralized::<T>(sender)
+}
+
+/// Deposit the events for an asset transfer and its fee, once it has been committed.
+pub fn emit_transfer_internal<T: Config>(
+    asset: AssetInfo,
+    sender: ChainAccount,
+    recipient: ChainAccount,
+    amount: AssetQuantity,
+) -> Result<(), Reason> {
+    let miner = get_some_miner::<T>();
+    let index = GlobalCashIndex::get();
+    let fee_principal = index.cash_principal_amount(TRANSFER_FEE)?;
 
     <Module<T>>::deposit_event(Event::Transfer(
         asset.asset,
@@ -45,6 +69,23 @@ pub fn transfer_cash_principal_internal<T: Config>(
     recipient: ChainAccount,
     principal: CashPrincipalAmount,
 ) -> Result<(), Reason> {
+    prepare_transfer_cash_principal_internal::<T>(
+        CashPipeline::new(),
+        sender,
+        recipient,
+        principal,
+    )?
+    .commit::<T>();
+    emit_transfer_cash_principal_internal::<T>(sender, recipient, principal)
+}
+
+/// Apply a CASH transfer and its fee to the pipeline, without committing it.
+pub fn prepare_transfer_cash_principal_internal<T: Config>(
+    pipeline: CashPipeline,
+    sender: ChainAccount,
+    recipient: ChainAccount,
+    principal: CashPrincipalAmount,
+) -> Result<CashPipeline, Reason> {
     let miner = get_some_miner::<T>();
     let index = GlobalCashIndex::get();
     let fee_principal = index.cash_principal_amount(TRANSFER_FEE)?;
@@ -52,11 +93,21 @@ pub fn transfer_cash_principal_internal<T: Config>(
 
     require_min_tx_value!(get_value::<T>(amount)?);
 
-    CashPipeline::new()
+    pipeline
         .transfer_cash::<T>(sender, recipient, principal)?
         .transfer_cash::<T>(sender, miner, fee_principal)?
-        .check_collateralized::<T>(sender)?
-        .commit::<T>();
+        .check_collateralized::<T>(sender)
+}
+
+/// Deposit the events for a CASH transfer and its fee, once it has been committed.
+pub fn emit_transfer_cash_principal_internal<T: Config>(
+    sender: ChainAccount,
+    recipient: ChainAccount,
+    principal: CashPrincipalAmount,
+) -> Result<(), Reason> {
+    let miner = get_some_miner::<T>();
+    let index = GlobalCashIndex::get();
+    let fee_principal = index.cash_principal_amount(TRANSFER_FEE)?;
 
     <Module<T>>::deposit_event(Event::TransferCash(sender, recipient, principal, index));
     <Module<T>>::deposit_event(Event::TransferCash(sender, miner, fee_principal, index));
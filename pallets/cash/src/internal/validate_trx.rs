@@ -1,7 +1,7 @@
 use crate::{
     chains::{Chain, Ethereum},
     core::recover_validator,
-    internal,
+    internal::{self, events::fits_in_batch},
     notices::EncodeNotice,
     params::{UNSIGNED_TXS_LONGEVITY, UNSIGNED_TXS_PRIORITY},
     reason::Reason,
@@ -22,6 +22,7 @@ pub enum ValidationError {
     InvalidPrice(Reason),
     UnknownNotice,
     InvalidTrxRequest(Reason),
+    InvalidBatchSize,
 }
 
 pub fn check_validation_failure<T: Config>(
@@ -84,6 +85,10 @@ pub fn validate_unsigned<T: Config>(
         .build()),
 
         Call::receive_chain_blocks(blocks, signature) => {
+            if !fits_in_batch(blocks) {
+                return Err(ValidationError::InvalidBatchSize);
+            }
+
             let chain_id = blocks.chain_id();
 
             let validator = recover_validator::<T>(&blocks.encode(), *signature)
@@ -103,6 +108,10 @@ pub fn validate_unsigned<T: Config>(
         }
 
         Call::receive_chain_reorg(reorg, signature) => {
+            if !fits_in_batch(&reorg.reverse_blocks()) || !fits_in_batch(&reorg.forward_blocks()) {
+                return Err(ValidationError::InvalidBatchSize);
+            }
+
             let _validator = recover_validator::<T>(&reorg.encode(), *signature)
                 .map_err(|_| ValidationError::InvalidValidator)?;
             Ok(
@@ -115,11 +124,12 @@ pub fn validate_unsigned<T: Config>(
             )
         }
 
-        Call::exec_trx_request(request, signature, nonce) => {
+        Call::exec_trx_request(request, signature, nonce, domain) => {
             let signer_res = internal::exec_trx_request::is_minimally_valid_trx_request::<T>(
                 request.to_vec(),
                 *signature,
                 *nonce,
+                *domain,
             );
 
             match (signer_res, nonce) {
@@ -178,8 +188,12 @@ pub fn validate_unsigned<T: Config>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{core::validator_sign, tests::*, Call};
-    use ethereum_client::EthereumBlock;
+    use crate::{
+        core::validator_sign,
+        tests::{common::*, *},
+        Call,
+    };
+    use ethereum_client::{EthereumBlock, EthereumEvent, EthereumLogEvent};
 
     #[test]
     fn test_set_miner_external() {
@@ -298,6 +312,109 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_receive_chain_blocks_exceeds_max_batch_size() {
+        new_test_ext().execute_with(|| {
+            let blocks = ChainBlocks::Eth(
+                (1..=MAX_CHAIN_BLOCKS as u64 + 1)
+                    .map(|number| EthereumBlock {
+                        hash: [number as u8; 32],
+                        parent_hash: [number as u8 - 1; 32],
+                        number,
+                        events: vec![],
+                    })
+                    .collect(),
+            );
+            let signature = validator_sign::<Test>(&blocks.encode()).unwrap();
+
+            assert_eq!(
+                validate_unsigned(
+                    TransactionSource::InBlock {},
+                    &Call::receive_chain_blocks::<Test>(blocks, signature)
+                ),
+                Err(ValidationError::InvalidBatchSize)
+            );
+        });
+    }
+
+    #[test]
+    fn test_receive_chain_blocks_exceeds_max_batch_events() {
+        new_test_ext().execute_with(|| {
+            let events: Vec<EthereumLogEvent> = (0..=MAX_CHAIN_BLOCK_EVENTS)
+                .map(|i| {
+                    eth_log_event(
+                        i as u64,
+                        EthereumEvent::NoticeInvoked {
+                            era_id: 0,
+                            era_index: i,
+                            notice_hash: [0; 32],
+                            result: vec![],
+                        },
+                    )
+                })
+                .collect();
+            let block = |number: u64, events: Vec<EthereumLogEvent>| EthereumBlock {
+                hash: [number as u8; 32],
+                parent_hash: [number as u8 - 1; 32],
+                number,
+                events,
+            };
+
+            let blocks = ChainBlocks::Eth(vec![
+                block(1, events[..1].to_vec()),
+                block(2, events[1..].to_vec()),
+            ]);
+            let signature = validator_sign::<Test>(&blocks.encode()).unwrap();
+            assert_eq!(
+                validate_unsigned(
+                    TransactionSource::InBlock {},
+                    &Call::receive_chain_blocks::<Test>(blocks, signature)
+                ),
+                Err(ValidationError::InvalidBatchSize)
+            );
+
+            // a lone block cannot be split, so it is not held to the limit
+            let blocks = ChainBlocks::Eth(vec![block(1, events)]);
+            let signature = validator_sign::<Test>(&blocks.encode()).unwrap();
+            assert_eq!(
+                validate_unsigned(
+                    TransactionSource::InBlock {},
+                    &Call::receive_chain_blocks::<Test>(blocks, signature)
+                ),
+                Err(ValidationError::InvalidValidator)
+            );
+        });
+    }
+
+    #[test]
+    fn test_receive_chain_reorg_exceeds_max_batch_size() {
+        new_test_ext().execute_with(|| {
+            let blocks: Vec<EthereumBlock> = (1..=MAX_CHAIN_BLOCKS as u64 + 1)
+                .map(|number| EthereumBlock {
+                    hash: [number as u8; 32],
+                    parent_hash: [number as u8 - 1; 32],
+                    number,
+                    events: vec![],
+                })
+                .collect();
+            let reorg = ChainReorg::Eth {
+                from_hash: blocks.last().unwrap().hash,
+                to_hash: [0xff; 32],
+                reverse_blocks: blocks.iter().rev().cloned().collect(),
+                forward_blocks: vec![],
+            };
+            let signature = validator_sign::<Test>(&reorg.encode()).unwrap();
+
+            assert_eq!(
+                validate_unsigned(
+                    TransactionSource::InBlock {},
+                    &Call::receive_chain_reorg::<Test>(reorg, signature)
+                ),
+                Err(ValidationError::InvalidBatchSize)
+            );
+        });
+    }
+
     #[test]
     fn test_receive_chain_blocks_is_validator() {
         new_test_ext().execute_with(|| {
@@ -356,16 +473,9 @@ mod tests {
         new_test_ext().execute_with(|| {
             let request: Vec<u8> = String::from("(Extract 50000000 Cash Eth:0xfc04833Ca66b7D6B4F540d4C2544228f64a25ac2)").as_bytes().into();
             let nonce = 0;
-            let full_request: Vec<u8> = format!("\x19Ethereum Signed Message:\n720:(Extract 50000000 Cash Eth:0xfc04833Ca66b7D6B4F540d4C2544228f64a25ac2)")
-                .as_bytes()
-                .into();
+            let domain = trx_request_domain(None);
             let eth_address = <Ethereum as Chain>::signer_address().unwrap();
-            let eth_key_id =
-                runtime_interfaces::validator_config_interface::get_eth_key_id().unwrap();
-            let signature_raw =
-                runtime_interfaces::keyring_interface::sign_one(full_request, eth_key_id).unwrap();
-
-            let signature = ChainAccountSignature::Eth(eth_address, signature_raw);
+            let signature = sign_trx_request(&request, nonce, domain);
 
             let exp = ValidTransaction::with_tag_prefix("Gateway::exec_trx_request")
                 .priority(100)
@@ -378,7 +488,7 @@ mod tests {
             assert_eq!(
                 validate_unsigned(
                     TransactionSource::InBlock {},
-                    &Call::exec_trx_request::<Test>(request, signature, nonce),
+                    &Call::exec_trx_request::<Test>(request, signature, nonce, domain),
                 ),
                 Ok(exp)
             );
@@ -394,16 +504,9 @@ mod tests {
             .as_bytes()
             .into();
             let nonce = 5;
-            let full_request: Vec<u8> = format!("\x19Ethereum Signed Message:\n725:(Extract 50000000 Cash Eth:0xfc04833Ca66b7D6B4F540d4C2544228f64a25ac2)")
-                .as_bytes()
-                .into();
+            let domain = trx_request_domain(None);
             let eth_address = <Ethereum as Chain>::signer_address().unwrap();
-            let eth_key_id =
-                runtime_interfaces::validator_config_interface::get_eth_key_id().unwrap();
-            let signature_raw =
-                runtime_interfaces::keyring_interface::sign_one(full_request, eth_key_id).unwrap();
-
-            let signature = ChainAccountSignature::Eth(eth_address, signature_raw);
+            let signature = sign_trx_request(&request, nonce, domain);
 
             Nonces::insert(ChainAccount::Eth(eth_address), nonce);
 
@@ -418,7 +521,7 @@ mod tests {
             assert_eq!(
                 validate_unsigned(
                     TransactionSource::InBlock {},
-                    &Call::exec_trx_request::<Test>(request, signature, nonce),
+                    &Call::exec_trx_request::<Test>(request, signature, nonce, domain),
                 ),
                 Ok(exp)
             );
@@ -434,15 +537,9 @@ mod tests {
             .as_bytes()
             .into();
             let nonce = 5;
-            let full_request: Vec<u8> = format!("\x19Ethereum Signed Message:\n725:(Extract 50000000 Cash Eth:0xfc04833Ca66b7D6B4F540d4C2544228f64a25ac2)")
-                .as_bytes()
-                .into();
+            let domain = trx_request_domain(None);
             let eth_address = <Ethereum as Chain>::signer_address().unwrap();
-            let eth_key_id =
-                runtime_interfaces::validator_config_interface::get_eth_key_id().unwrap();
-            let signature_raw =
-                runtime_interfaces::keyring_interface::sign_one(full_request, eth_key_id).unwrap();
-            let signature = ChainAccountSignature::Eth(eth_address, signature_raw);
+            let signature = sign_trx_request(&request, nonce, domain);
 
             Nonces::insert(ChainAccount::Eth(eth_address), nonce - 1);
 
@@ -459,7 +556,7 @@ mod tests {
             assert_eq!(
                 validate_unsigned(
                     TransactionSource::InBlock {},
-                    &Call::exec_trx_request::<Test>(request, signature, nonce),
+                    &Call::exec_trx_request::<Test>(request, signature, nonce, domain),
                 ),
                 Ok(exp)
             );
@@ -471,23 +568,21 @@ mod tests {
         new_test_ext().execute_with(|| {
             let request: Vec<u8> = String::from("Parse Error").as_bytes().into();
             let nonce = 5;
-            let full_request: Vec<u8> = format!("\x19Ethereum Signed Message:\n135:Parse Error")
-                .as_bytes()
-                .into();
-            let eth_address = <Ethereum as Chain>::signer_address().unwrap();
-            let eth_key_id =
-                runtime_interfaces::validator_config_interface::get_eth_key_id().unwrap();
-            let signature_raw =
-                runtime_interfaces::keyring_interface::sign_one(full_request, eth_key_id).unwrap();
-            let signature = ChainAccountSignature::Eth(eth_address, signature_raw);
+            let domain = trx_request_domain(None);
+            let signature = sign_trx_request(&request, nonce, domain);
 
             assert_eq!(
                 validate_unsigned(
                     TransactionSource::InBlock {},
-                    &Call::exec_trx_request::<Test>(request, signature, nonce),
+                    &Call::exec_trx_request::<Test>(request, signature, nonce, domain),
                 ),
                 Err(ValidationError::InvalidTrxRequest(
-                    Reason::TrxRequestParseError(TrxReqParseError::InvalidExpression)
+                    Reason::TrxRequestParseError(SpannedTrxReqParseError {
+                        error: TrxReqParseError::InvalidExpression,
+                        start: 0,
+                        end: 11,
+                        expected: TrxReqExpected::Expression,
+                    })
                 ))
             );
         });
@@ -502,20 +597,13 @@ mod tests {
             .as_bytes()
             .into();
             let nonce = 5;
-            let full_request: Vec<u8> = format!("\x19Ethereum Signed Message:\n45:(Extract 50000000 Cash Eth:0xfc04833Ca66b7D6B4F540d4C2544228f64a25ac2)")
-                .as_bytes()
-                .into();
-            let eth_address = <Ethereum as Chain>::signer_address().unwrap();
-            let eth_key_id =
-                runtime_interfaces::validator_config_interface::get_eth_key_id().unwrap();
-            let signature_raw =
-                runtime_interfaces::keyring_interface::sign_one(full_request, eth_key_id).unwrap();
-            let signature = ChainAccountSignature::Eth(eth_address, signature_raw);
+            let domain = trx_request_domain(None);
+            let signature = sign_trx_request(&request, nonce + 1, domain);
 
             assert_eq!(
                 validate_unsigned(
                     TransactionSource::InBlock {},
-                    &Call::exec_trx_request::<Test>(request, signature, nonce),
+                    &Call::exec_trx_request::<Test>(request, signature, nonce, domain),
                 ),
                 Err(ValidationError::InvalidTrxRequest(
                     Reason::SignatureAccountMismatch
@@ -524,6 +612,90 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_exec_trx_request_wrong_network() {
+        new_test_ext().execute_with(|| {
+            let request: Vec<u8> = String::from(
+                "(Extract 50000000 Cash Eth:0xfc04833Ca66b7D6B4F540d4C2544228f64a25ac2)",
+            )
+            .as_bytes()
+            .into();
+            let nonce = 0;
+            let domain = TrxRequestDomain {
+                genesis_hash: [1; 32],
+                expiry: None,
+            };
+            let signature = sign_trx_request(&request, nonce, domain);
+
+            assert_eq!(
+                validate_unsigned(
+                    TransactionSource::InBlock {},
+                    &Call::exec_trx_request::<Test>(request, signature, nonce, domain),
+                ),
+                Err(ValidationError::InvalidTrxRequest(
+                    Reason::TrxRequestWrongNetwork
+                ))
+            );
+        });
+    }
+
+    #[test]
+    fn test_exec_trx_request_expired() {
+        new_test_ext().execute_with(|| {
+            <pallet_timestamp::Pallet<Test>>::set_timestamp(500);
+            let request: Vec<u8> = String::from(
+                "(Extract 50000000 Cash Eth:0xfc04833Ca66b7D6B4F540d4C2544228f64a25ac2)",
+            )
+            .as_bytes()
+            .into();
+            let nonce = 0;
+            let domain = trx_request_domain(Some(500));
+            let signature = sign_trx_request(&request, nonce, domain);
+
+            assert_eq!(
+                validate_unsigned(
+                    TransactionSource::InBlock {},
+                    &Call::exec_trx_request::<Test>(request, signature, nonce, domain),
+                ),
+                Err(ValidationError::InvalidTrxRequest(
+                    Reason::TrxRequestExpired
+                ))
+            );
+        });
+    }
+
+    #[test]
+    fn test_exec_trx_request_not_yet_expired() {
+        new_test_ext().execute_with(|| {
+            <pallet_timestamp::Pallet<Test>>::set_timestamp(500);
+            let request: Vec<u8> = String::from(
+                "(Extract 50000000 Cash Eth:0xfc04833Ca66b7D6B4F540d4C2544228f64a25ac2)",
+            )
+            .as_bytes()
+            .into();
+            let nonce = 0;
+            let domain = trx_request_domain(Some(501));
+            let eth_address = <Ethereum as Chain>::signer_address().unwrap();
+            let signature = sign_trx_request(&request, nonce, domain);
+
+            let exp = ValidTransaction::with_tag_prefix("Gateway::exec_trx_request")
+                .priority(UNSIGNED_TXS_PRIORITY)
+                .longevity(UNSIGNED_TXS_LONGEVITY)
+                .and_provides((ChainAccount::Eth(eth_address), 0))
+                .and_provides(request.clone())
+                .propagate(true)
+                .build();
+
+            assert_eq!(
+                validate_unsigned(
+                    TransactionSource::InBlock {},
+                    &Call::exec_trx_request::<Test>(request, signature, nonce, domain),
+                ),
+                Ok(exp)
+            );
+        });
+    }
+
     #[test]
     fn test_publish_signature_invalid_signature() {
         new_test_ext().execute_with(|| {
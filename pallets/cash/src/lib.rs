@@ -14,21 +14,23 @@ use crate::{
     chains::{
         ChainAccount, ChainAccountSignature, ChainAsset, ChainBlock, ChainBlockEvent,
         ChainBlockEvents, ChainBlockTally, ChainBlocks, ChainHash, ChainId, ChainReorg,
-        ChainReorgTally, ChainSignature, ChainSignatureList, ChainStarport,
+        ChainReorgTally, ChainSignature, ChainSignatureList, ChainStarport, EvmChainConfig,
+        EvmChainId,
     },
     notices::{Notice, NoticeId, NoticeState},
     portfolio::Portfolio,
-    symbol::CASH,
+    symbol::{Symbol, CASH},
     types::{
         AssetAmount, AssetBalance, AssetIndex, AssetInfo, Balance, Bips, CashIndex, CashPrincipal,
-        CashPrincipalAmount, CodeHash, EncodedNotice, GovernanceResult, InterestRateModel,
-        LiquidityFactor, Nonce, Reason, SessionIndex, Timestamp, ValidatorKeys, APR,
+        CashPrincipalAmount, CodeHash, EncodedNotice, EventKind, GovernanceResult, IngressConfig,
+        InterestRateModel, LiquidityFactor, Nonce, Reason, RiskModel, SessionIndex, SignersSet,
+        Timestamp, TrxRequestDomain, ValidatorKeys, APR,
     },
 };
 use codec::{alloc::string::String, Encode};
 use frame_support::{
     decl_event, decl_module, decl_storage, dispatch,
-    traits::{StoredMap, UnfilteredDispatchable},
+    traits::{Get, StoredMap, UnfilteredDispatchable},
     weights::{DispatchClass, GetDispatchInfo, Pays, Weight},
     Parameter,
 };
@@ -66,12 +68,12 @@ pub mod rates;
 pub mod reason;
 pub mod require;
 pub mod serdes;
+pub mod starport;
 pub mod symbol;
 pub mod trx_req;
 pub mod types;
 
 pub mod weights;
-use ethereum_client::EthereumBlock;
 pub use weights::WeightInfo;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -203,6 +205,9 @@ decl_storage! {
         /// The asset metadata for each supported asset, which will also be synced with the starports.
         SupportedAssets get(fn asset): map hasher(blake2_128_concat) ChainAsset => Option<AssetInfo>;
 
+        /// Index of supported assets by chain and symbol, for resolving symbols in trx requests.
+        AssetSymbols get(fn asset_symbol): double_map hasher(blake2_128_concat) ChainId, hasher(blake2_128_concat) Symbol => Option<ChainAsset>;
+
         /// Miner of the current block.
         Miner get(fn miner): Option<ChainAccount>;
 
@@ -221,6 +226,9 @@ decl_storage! {
         /// The mapping of ingression queue events, by chain.
         IngressionQueue get(fn ingression_queue): map hasher(blake2_128_concat) ChainId => Option<ChainBlockEvents>;
 
+        /// The mapping of the most recent events which could not be ingressed, until purged or requeued, by chain.
+        DeadLetterEvents get(fn dead_letter_events): map hasher(blake2_128_concat) ChainId => Vec<(ChainBlockEvent, Reason)>;
+
         /// The mapping of first blocks for which validators are to begin reading events from.
         FirstBlock get(fn first_block): map hasher(blake2_128_concat) ChainId => Option<ChainBlock>;
 
@@ -235,6 +243,18 @@ decl_storage! {
 
         /// Mapping of chain to the relevant Starport address.
         Starports get(fn starports): map hasher(blake2_128_concat) ChainId => Option<ChainStarport>;
+
+        /// Mapping of EVM chains registered by governance to their starport and ingression config.
+        EvmChains get(fn evm_chains): map hasher(blake2_128_concat) EvmChainId => Option<EvmChainConfig>;
+
+        /// Mapping of chain to its ingression quota, event depths and queue slack, if not the defaults.
+        IngressConfigs get(fn ingress_config): map hasher(blake2_128_concat) ChainId => Option<IngressConfig>;
+
+        /// Mapping of event kind to the model of the risk it poses to ingression, if not the default.
+        RiskModels get(fn risk_model): map hasher(blake2_128_concat) EventKind => Option<RiskModel>;
+
+        /// The version of the storage layout, for running migrations upon runtime upgrades.
+        StorageVersion get(fn storage_version): u32;
     }
 
     add_extra_genesis {
@@ -242,11 +262,14 @@ decl_storage! {
         config(validators): Vec<ValidatorKeys>;
         config(starports): Vec<ChainAccount>;
         config(genesis_blocks): Vec<ChainBlock>;
+        config(ingress_configs): Vec<(ChainId, IngressConfig)>;
         build(|config| {
             Pallet::<T>::initialize_assets(config.assets.clone());
             Pallet::<T>::initialize_validators(config.validators.clone());
             Pallet::<T>::initialize_starports(config.starports.clone());
             Pallet::<T>::initialize_genesis_blocks(config.genesis_blocks.clone());
+            Pallet::<T>::initialize_ingress_configs(config.ingress_configs.clone());
+            StorageVersion::put(internal::migrations::STORAGE_VERSION);
         })
     }
 }
@@ -315,6 +338,18 @@ decl_event!(
         /// An Ethereum event failed during processing. [event_id, reason]
         FailedProcessingChainBlockEvent(ChainBlockEvent, Reason),
 
+        /// An event was moved off the ingression queue without being processed. [event_id, reason]
+        DeadLetteredChainBlockEvent(ChainBlockEvent, Reason),
+
+        /// A dead-lettered event was dropped to make room for a newer one. [event_id, reason]
+        DroppedDeadLetterEvent(ChainBlockEvent, Reason),
+
+        /// The dead-lettered events for a chain have been purged. [chain_id, count]
+        PurgedDeadLetterEvents(ChainId, u32),
+
+        /// The dead-lettered events for a chain have been put back on its ingression queue. [chain_id, count]
+        RequeuedDeadLetterEvents(ChainId, u32),
+
         /// A new notice is generated by the chain. [notice_id, notice, encoded_notice]
         Notice(NoticeId, Notice, EncodedNotice),
 
@@ -330,6 +365,15 @@ decl_event!(
         /// A new yield rate has been chosen. [next_rate, next_start_at]
         SetYieldNext(APR, Timestamp),
 
+        /// An EVM chain has been registered or reconfigured. [evm_chain_id, config]
+        EvmChainSet(EvmChainId, EvmChainConfig),
+
+        /// The ingress config for a chain has been changed. [chain_id, config]
+        IngressConfigSet(ChainId, IngressConfig),
+
+        /// The risk model for a kind of event has been changed. [kind, model]
+        RiskModelSet(EventKind, RiskModel),
+
         /// Failed to process a given extrinsic. [reason]
         Failure(Reason),
     }
@@ -499,95 +543,90 @@ fn get_exec_req_weights<T: Config>(request: Vec<u8>) -> frame_support::weights::
         Ok(f) => f,
     };
     match trx_request::parse_request(request_str) {
-        Ok(trx_request::TrxRequest::Extract(_max_amount, _asset, _account)) => {
+        Ok(trx_request) => get_trx_request_weights::<T>(&trx_request),
+        _ => params::ERROR_WEIGHT,
+    }
+}
+
+fn get_trx_request_weights<T: Config>(
+    trx_request: &trx_request::TrxRequest,
+) -> frame_support::weights::Weight {
+    match trx_request {
+        trx_request::TrxRequest::Extract(_max_amount, _asset, _account) => {
             <T as Config>::WeightInfo::exec_trx_request_extract()
         }
 
-        Ok(trx_request::TrxRequest::Transfer(_max_amount, _asset, _account)) => {
+        trx_request::TrxRequest::Transfer(_max_amount, _asset, _account) => {
             <T as Config>::WeightInfo::exec_trx_request_transfer()
         }
 
-        Ok(trx_request::TrxRequest::Liquidate(_max_amount, _borrowed, _collat, _account)) => {
+        trx_request::TrxRequest::Liquidate(_max_amount, _borrowed, _collat, _account) => {
             <T as Config>::WeightInfo::exec_trx_request_liquidate()
         }
 
-        _ => params::ERROR_WEIGHT,
+        trx_request::TrxRequest::Batch(trx_requests) => {
+            trx_requests.iter().fold(0, |acc, trx_request| {
+                acc.saturating_add(get_trx_request_weights::<T>(trx_request))
+            })
+        }
     }
 }
 
-fn get_chain_reorg_weights_eth_like<T: Config>(
+fn get_chain_blocks_weights<T: Config>(blocks: &ChainBlocks) -> frame_support::weights::Weight {
+    // each block ingested processes a round of the queue, which may apply any of its backlog too
+    let backlog = core::get_event_queue::<T>(blocks.chain_id())
+        .map(|event_queue| event_queue.len())
+        .unwrap_or(0);
+    <T as Config>::WeightInfo::receive_chain_blocks(
+        blocks.len().try_into().unwrap_or(u32::MAX),
+        blocks
+            .event_count()
+            .saturating_add(backlog)
+            .try_into()
+            .unwrap_or(u32::MAX),
+    )
+}
+
+fn get_chain_reorg_weights<T: Config>(
     reorg: &ChainReorg,
     signature: &ChainSignature,
-    forward_blocks: &Vec<EthereumBlock>,
-    reverse_blocks: &Vec<EthereumBlock>,
-    chain_id: ChainId,
 ) -> Result<frame_support::weights::Weight, Reason> {
-    let forward_event_count: u64 = forward_blocks
-        .iter()
-        .fold(0usize, |acc, x| acc.checked_add(x.events.len()).unwrap())
-        .try_into()
-        .unwrap();
-    let reverse_event_count: u64 = reverse_blocks
-        .iter()
-        .fold(0usize, |acc, x| acc.checked_add(x.events.len()).unwrap())
-        .try_into()
-        .unwrap();
-    let event_count = forward_event_count + reverse_event_count;
-
-    if let Some(prior) = PendingChainReorgs::get(chain_id)
-        .iter_mut()
+    let chain_id = reorg.chain_id();
+    let reverse_blocks = reorg.reverse_blocks();
+    let forward_blocks = reorg.forward_blocks();
+    let event_count = reverse_blocks
+        .event_count()
+        .saturating_add(forward_blocks.event_count());
+    let validator_set = core::get_validator_set::<T>()?;
+    let validator = core::recover_validator::<T>(&reorg.encode(), *signature)?;
+    let tally = PendingChainReorgs::get(chain_id)
+        .into_iter()
         .find(|r| r.reorg == *reorg)
-    {
-        let validator = core::recover_validator::<T>(&reorg.encode(), *signature)?;
-        // if reorg would get applied, just estimate gas by counting the number of reorged events
-        if prior.would_have_enough_support(&prior.support, &validator) {
-            let avg_weight = <T as Config>::WeightInfo::exec_trx_request_extract();
-            // TODO: only count forward weight if we have passed than min_event_blocks
-            Ok(event_count * avg_weight)
-        } else {
-            Ok(<T as Config>::WeightInfo::receive_chain_reorg_pending(
-                event_count.try_into().unwrap(),
-            ))
-        }
+        .unwrap_or_else(|| ChainReorgTally {
+            reorg: reorg.clone(),
+            support: SignersSet::new(),
+        });
+    if tally.would_have_enough_support(&validator_set, &validator) {
+        // the reorg gets applied, each forward block also processing a round of the queue
+        let backlog = core::get_event_queue::<T>(chain_id)?.len();
+        Ok(<T as Config>::WeightInfo::receive_chain_reorg(
+            reverse_blocks
+                .len()
+                .saturating_add(forward_blocks.len())
+                .try_into()
+                .unwrap_or(u32::MAX),
+            event_count
+                .saturating_add(backlog)
+                .try_into()
+                .unwrap_or(u32::MAX),
+        ))
     } else {
         Ok(<T as Config>::WeightInfo::receive_chain_reorg_pending(
-            event_count.try_into().unwrap(),
+            event_count.try_into().unwrap_or(u32::MAX),
         ))
     }
 }
 
-fn get_chain_reorg_weights<T: Config>(
-    reorg: &ChainReorg,
-    signature: &ChainSignature,
-) -> Result<frame_support::weights::Weight, Reason> {
-    match reorg {
-        ChainReorg::Eth {
-            from_hash: _,
-            to_hash: _,
-            forward_blocks,
-            reverse_blocks,
-        } => get_chain_reorg_weights_eth_like::<T>(
-            reorg,
-            signature,
-            forward_blocks,
-            reverse_blocks,
-            ChainId::Eth,
-        ),
-        ChainReorg::Matic {
-            from_hash: _,
-            to_hash: _,
-            forward_blocks,
-            reverse_blocks,
-        } => get_chain_reorg_weights_eth_like::<T>(
-            reorg,
-            signature,
-            forward_blocks,
-            reverse_blocks,
-            ChainId::Matic,
-        ),
-    }
-}
-
 /* ::MODULE:: */
 /* ::EXTRINSICS:: */
 
@@ -602,7 +641,7 @@ decl_module! {
         fn deposit_event() = default;
 
         fn on_runtime_upgrade() -> Weight {
-            0 // XXX
+            internal::migrations::on_runtime_upgrade::<T>()
         }
 
         /// Called by substrate on block initialization.
@@ -620,13 +659,14 @@ decl_module! {
 
         /// Offchain Worker entry point.
         fn offchain_worker(block_number: T::BlockNumber) {
-            match internal::events::track_chain_events::<T>() {
-                Ok(()) => (),
-                Err(Reason::WorkerBusy) => {
-                    debug!("offchain_worker is still busy in track_chain_events");
-                }
-                Err(err) => {
-                    error!("offchain_worker error during track_chain_events: {:?}", err);
+            for (chain_id, failure) in internal::events::track_chain_events::<T>() {
+                match failure {
+                    Reason::WorkerBusy => {
+                        debug!("offchain_worker is still busy in track_chain_events on {:?}", chain_id);
+                    }
+                    err => {
+                        error!("offchain_worker error during track_chain_events on {:?}: {:?}", chain_id, err);
+                    }
                 }
             }
 
@@ -677,7 +717,7 @@ decl_module! {
             Ok(res?)
         }
 
-        #[weight = (0, DispatchClass::Operational, Pays::No)]
+        #[weight = (T::DbWeight::get().writes(1), DispatchClass::Operational, Pays::No)]
         pub fn set_starport(origin, starport: ChainStarport) -> dispatch::DispatchResult {
             ensure_root(origin)?;
             log!("Setting Starport to {:?}", starport);
@@ -685,7 +725,45 @@ decl_module! {
             Ok(())
         }
 
-        #[weight = (0, DispatchClass::Operational, Pays::No)]
+        /// Registers or reconfigures an EVM chain, along with its starport. [Root]
+        #[weight = (T::DbWeight::get().writes(1), DispatchClass::Operational, Pays::No)]
+        pub fn set_evm_chain(origin, evm_chain_id: EvmChainId, config: EvmChainConfig) -> dispatch::DispatchResult {
+            ensure_root(origin)?;
+            log!("Setting EVM chain {} to {:?}", evm_chain_id, config);
+            EvmChains::insert(evm_chain_id, config.clone());
+            Self::deposit_event(Event::EvmChainSet(evm_chain_id, config));
+            Ok(())
+        }
+
+        /// Sets the ingression quota, event depths and queue slack for a chain. [Root]
+        #[weight = (T::DbWeight::get().reads_writes(2, 1), DispatchClass::Operational, Pays::No)]
+        pub fn set_ingress_config(origin, chain_id: ChainId, config: IngressConfig) -> dispatch::DispatchResult {
+            ensure_root(origin)?;
+            Ok(check_failure::<T>(internal::ingress_config::set_ingress_config::<T>(chain_id, config))?)
+        }
+
+        /// Sets the factor and half-life of the risk a kind of event poses to ingression. [Root]
+        #[weight = (T::DbWeight::get().writes(1), DispatchClass::Operational, Pays::No)]
+        pub fn set_risk_model(origin, kind: EventKind, model: RiskModel) -> dispatch::DispatchResult {
+            ensure_root(origin)?;
+            Ok(check_failure::<T>(internal::risk_model::set_risk_model::<T>(kind, model))?)
+        }
+
+        /// Drops the events which could not be ingressed for a chain. [Root]
+        #[weight = (T::DbWeight::get().reads_writes(1, 1), DispatchClass::Operational, Pays::No)]
+        pub fn purge_dead_letter_events(origin, chain_id: ChainId) -> dispatch::DispatchResult {
+            ensure_root(origin)?;
+            Ok(check_failure::<T>(internal::events::purge_dead_letter_events::<T>(chain_id))?)
+        }
+
+        /// Puts the events which could not be ingressed for a chain back on its ingression queue. [Root]
+        #[weight = (T::DbWeight::get().reads_writes(2, 2), DispatchClass::Operational, Pays::No)]
+        pub fn requeue_dead_letter_events(origin, chain_id: ChainId) -> dispatch::DispatchResult {
+            ensure_root(origin)?;
+            Ok(check_failure::<T>(internal::events::requeue_dead_letter_events::<T>(chain_id))?)
+        }
+
+        #[weight = (T::DbWeight::get().writes(2), DispatchClass::Operational, Pays::No)]
         pub fn set_genesis_block(origin, chain_block: ChainBlock) -> dispatch::DispatchResult {
             ensure_root(origin)?;
             log!("Setting last processed block to {:?}", chain_block);
@@ -730,7 +808,7 @@ decl_module! {
         }
 
         /// Receive the chain blocks message from the worker to make progress on event ingression. [Root]
-        #[weight = (0, DispatchClass::Operational, Pays::No)]
+        #[weight = (get_chain_blocks_weights::<T>(blocks), DispatchClass::Operational, Pays::No)]
         pub fn receive_chain_blocks(origin, blocks: ChainBlocks, signature: ChainSignature) -> dispatch::DispatchResult {
             log!("receive_chain_blocks(origin, blocks, signature): {:?} {:?}", blocks, signature);
             ensure_none(origin)?;
@@ -753,9 +831,9 @@ decl_module! {
 
         /// Execute a transaction request on behalf of a user
         #[weight = (get_exec_req_weights::<T>(request.to_vec()), DispatchClass::Normal, Pays::No)]
-        pub fn exec_trx_request(origin, request: Vec<u8>, signature: ChainAccountSignature, nonce: Nonce) -> dispatch::DispatchResult {
+        pub fn exec_trx_request(origin, request: Vec<u8>, signature: ChainAccountSignature, nonce: Nonce, domain: TrxRequestDomain) -> dispatch::DispatchResult {
             ensure_none(origin)?;
-            Ok(check_failure::<T>(internal::exec_trx_request::exec::<T>(request, signature, nonce))?)
+            Ok(check_failure::<T>(internal::exec_trx_request::exec::<T>(request, signature, nonce, domain))?)
         }
     }
 }
@@ -771,6 +849,7 @@ impl<T: Config> Module<T> {
                 "Duplicate asset in genesis config"
             );
             SupportedAssets::insert(&asset.asset, asset);
+            AssetSymbols::insert(asset.asset.chain_id(), asset.symbol, asset.asset);
         }
     }
 
@@ -819,6 +898,22 @@ impl<T: Config> Module<T> {
         }
     }
 
+    /// Set the initial ingress configs from the genesis config.
+    fn initialize_ingress_configs(ingress_configs: Vec<(ChainId, IngressConfig)>) {
+        for (chain_id, config) in ingress_configs {
+            log!("Adding Ingress Config {:?} {:?}", chain_id, config);
+            assert!(
+                config.is_valid(),
+                "Invalid ingress config in genesis config"
+            );
+            assert!(
+                IngressConfigs::get(chain_id) == None,
+                "Duplicate ingress config in genesis config"
+            );
+            IngressConfigs::insert(chain_id, config);
+        }
+    }
+
     // ** API / View Functions ** //
 
     /// Get the asset balance for the given account.
@@ -918,6 +1013,23 @@ impl<T: Config> Module<T> {
             .collect();
         Ok((validator_keys, miner_earnings))
     }
+
+    /// Get the risk models set for each kind of event
+    pub fn get_risk_models() -> Result<Vec<(EventKind, RiskModel)>, Reason> {
+        Ok(RiskModels::iter().collect())
+    }
+
+    /// Get the starport for the given chain
+    pub fn get_starport(chain_id: ChainId) -> Result<ChainStarport, Reason> {
+        Ok(core::get_starport::<T>(chain_id)?)
+    }
+
+    /// Get the events which could not be ingressed for the given chain
+    pub fn get_dead_letter_events(
+        chain_id: ChainId,
+    ) -> Result<Vec<(ChainBlockEvent, Reason)>, Reason> {
+        Ok(DeadLetterEvents::get(chain_id))
+    }
 }
 
 impl<T: Config> frame_support::unsigned::ValidateUnsigned for Module<T> {
@@ -1,10 +1,15 @@
 use crate::{
-    chains::{Chain, ChainHash, ChainId, ChainSignature, ChainSignatureList, Ethereum, Polygon},
+    chains::{
+        Chain, ChainHash, ChainId, ChainSignature, ChainSignatureList, Ethereum, EvmChainId,
+        Polkadot, Polygon,
+    },
     reason::Reason,
+    starport::{evm_notice_header, StarportChain},
 };
 use codec::{Decode, Encode};
 use ethabi::Token;
 use our_std::{vec::Vec, RuntimeDebug};
+use polkadot_client::PolkadotAssetId;
 
 use types_derive::{type_alias, Types};
 
@@ -70,6 +75,21 @@ pub enum ExtractionNotice {
         account: <Polygon as Chain>::Address,
         amount: <Polygon as Chain>::Amount,
     },
+    Dot {
+        id: NoticeId,
+        parent: <Polkadot as Chain>::Hash,
+        asset: PolkadotAssetId,
+        account: <Polkadot as Chain>::Address,
+        amount: <Polkadot as Chain>::Amount,
+    },
+    Evm {
+        evm_chain_id: EvmChainId,
+        id: NoticeId,
+        parent: <Ethereum as Chain>::Hash,
+        asset: <Ethereum as Chain>::Address,
+        account: <Ethereum as Chain>::Address,
+        amount: <Ethereum as Chain>::Amount,
+    },
 }
 
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
@@ -86,6 +106,19 @@ pub enum CashExtractionNotice {
         account: <Polygon as Chain>::Address,
         principal: <Polygon as Chain>::Amount,
     },
+    Dot {
+        id: NoticeId,
+        parent: <Polkadot as Chain>::Hash,
+        account: <Polkadot as Chain>::Address,
+        principal: <Polkadot as Chain>::Amount,
+    },
+    Evm {
+        evm_chain_id: EvmChainId,
+        id: NoticeId,
+        parent: <Ethereum as Chain>::Hash,
+        account: <Ethereum as Chain>::Address,
+        principal: <Ethereum as Chain>::Amount,
+    },
 }
 
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
@@ -104,6 +137,21 @@ pub enum FutureYieldNotice {
         next_cash_index: <Polygon as Chain>::CashIndex,
         next_cash_yield_start: <Polygon as Chain>::Timestamp,
     },
+    Dot {
+        id: NoticeId,
+        parent: <Polkadot as Chain>::Hash,
+        next_cash_yield: <Polkadot as Chain>::Rate,
+        next_cash_index: <Polkadot as Chain>::CashIndex,
+        next_cash_yield_start: <Polkadot as Chain>::Timestamp,
+    },
+    Evm {
+        evm_chain_id: EvmChainId,
+        id: NoticeId,
+        parent: <Ethereum as Chain>::Hash,
+        next_cash_yield: <Ethereum as Chain>::Rate,
+        next_cash_index: <Ethereum as Chain>::CashIndex,
+        next_cash_yield_start: <Ethereum as Chain>::Timestamp,
+    },
 }
 
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
@@ -120,6 +168,19 @@ pub enum SetSupplyCapNotice {
         asset: <Polygon as Chain>::Address,
         cap: <Polygon as Chain>::Amount,
     },
+    Dot {
+        id: NoticeId,
+        parent: <Polkadot as Chain>::Hash,
+        asset: PolkadotAssetId,
+        cap: <Polkadot as Chain>::Amount,
+    },
+    Evm {
+        evm_chain_id: EvmChainId,
+        id: NoticeId,
+        parent: <Ethereum as Chain>::Hash,
+        asset: <Ethereum as Chain>::Address,
+        cap: <Ethereum as Chain>::Amount,
+    },
 }
 
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
@@ -134,6 +195,18 @@ pub enum ChangeAuthorityNotice {
         parent: <Polygon as Chain>::Hash,
         new_authorities: Vec<<Polygon as Chain>::Address>,
     },
+    Dot {
+        id: NoticeId,
+        parent: <Polkadot as Chain>::Hash,
+        /// The Dot starport is governed by the validators' Ethereum keys
+        new_authorities: Vec<<Ethereum as Chain>::Address>,
+    },
+    Evm {
+        evm_chain_id: EvmChainId,
+        id: NoticeId,
+        parent: <Ethereum as Chain>::Hash,
+        new_authorities: Vec<<Ethereum as Chain>::Address>,
+    },
 }
 
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
@@ -155,22 +228,32 @@ impl Notice {
             Notice::ExtractionNotice(n) => match n {
                 ExtractionNotice::Eth { .. } => ChainId::Eth,
                 ExtractionNotice::Matic { .. } => ChainId::Matic,
+                ExtractionNotice::Dot { .. } => ChainId::Dot,
+                ExtractionNotice::Evm { evm_chain_id, .. } => ChainId::Evm(*evm_chain_id),
             },
             Notice::CashExtractionNotice(n) => match n {
                 CashExtractionNotice::Eth { .. } => ChainId::Eth,
                 CashExtractionNotice::Matic { .. } => ChainId::Matic,
+                CashExtractionNotice::Dot { .. } => ChainId::Dot,
+                CashExtractionNotice::Evm { evm_chain_id, .. } => ChainId::Evm(*evm_chain_id),
             },
             Notice::FutureYieldNotice(n) => match n {
                 FutureYieldNotice::Eth { .. } => ChainId::Eth,
                 FutureYieldNotice::Matic { .. } => ChainId::Matic,
+                FutureYieldNotice::Dot { .. } => ChainId::Dot,
+                FutureYieldNotice::Evm { evm_chain_id, .. } => ChainId::Evm(*evm_chain_id),
             },
             Notice::SetSupplyCapNotice(n) => match n {
                 SetSupplyCapNotice::Eth { .. } => ChainId::Eth,
                 SetSupplyCapNotice::Matic { .. } => ChainId::Matic,
+                SetSupplyCapNotice::Dot { .. } => ChainId::Dot,
+                SetSupplyCapNotice::Evm { evm_chain_id, .. } => ChainId::Evm(*evm_chain_id),
             },
             Notice::ChangeAuthorityNotice(n) => match n {
                 ChangeAuthorityNotice::Eth { .. } => ChainId::Eth,
                 ChangeAuthorityNotice::Matic { .. } => ChainId::Matic,
+                ChangeAuthorityNotice::Dot { .. } => ChainId::Dot,
+                ChangeAuthorityNotice::Evm { evm_chain_id, .. } => ChainId::Evm(*evm_chain_id),
             },
         }
     }
@@ -184,8 +267,12 @@ pub trait EncodeNotice {
     fn encode_notice(&self) -> EncodedNotice;
 }
 
-const ETH_CHAIN_IDENT: &'static [u8] = b"ETH:";
-const MATIC_CHAIN_IDENT: &'static [u8] = b"MAT:";
+/// The indices of the calls on the Dot starport which accept each type of notice.
+const DOT_UNLOCK_CALL: u8 = 0;
+const DOT_UNLOCK_CASH_CALL: u8 = 1;
+const DOT_SET_FUTURE_YIELD_CALL: u8 = 2;
+const DOT_SET_SUPPLY_CAP_CALL: u8 = 3;
+const DOT_CHANGE_AUTHORITIES_CALL: u8 = 4;
 
 fn encode_notice_params_eth_like(
     id: &NoticeId,
@@ -208,6 +295,199 @@ fn encode_notice_params_eth_like(
     result
 }
 
+/// Encode a notice for the Dot starport, which decodes its parameters using SCALE.
+fn encode_notice_params_dot(
+    header: &[u8],
+    id: &NoticeId,
+    parent: &<Polkadot as Chain>::Hash,
+    call_index: u8,
+    params: &[u8],
+) -> Vec<u8> {
+    let mut result = header.to_vec();
+    result.extend_from_slice(&(id.era_id(), id.era_index(), parent).encode()[..]);
+    result.push(call_index);
+    result.extend_from_slice(params);
+    result
+}
+
+pub fn encode_extraction_notice_eth_like(
+    header: &[u8],
+    id: &NoticeId,
+    parent: &<Ethereum as Chain>::Hash,
+    asset: &<Ethereum as Chain>::Address,
+    account: &<Ethereum as Chain>::Address,
+    amount: &<Ethereum as Chain>::Amount,
+) -> EncodedNotice {
+    encode_notice_params_eth_like(
+        id,
+        parent,
+        *UNLOCK_SIG,
+        &[
+            Token::Address(asset.into()),
+            Token::Uint((*amount).into()),
+            Token::Address(account.into()),
+        ],
+        header.to_vec(),
+    )
+}
+
+pub fn encode_cash_extraction_notice_eth_like(
+    header: &[u8],
+    id: &NoticeId,
+    parent: &<Ethereum as Chain>::Hash,
+    account: &<Ethereum as Chain>::Address,
+    principal: &<Ethereum as Chain>::Amount,
+) -> EncodedNotice {
+    encode_notice_params_eth_like(
+        id,
+        parent,
+        *UNLOCK_CASH_SIG,
+        &[
+            Token::Address(account.into()),
+            Token::Uint((*principal).into()),
+        ],
+        header.to_vec(),
+    )
+}
+
+pub fn encode_future_yield_notice_eth_like(
+    header: &[u8],
+    id: &NoticeId,
+    parent: &<Ethereum as Chain>::Hash,
+    next_cash_yield: &<Ethereum as Chain>::Rate,
+    next_cash_index: &<Ethereum as Chain>::CashIndex,
+    next_cash_yield_start: &<Ethereum as Chain>::Timestamp,
+) -> EncodedNotice {
+    encode_notice_params_eth_like(
+        id,
+        parent,
+        *SET_FUTURE_YIELD_SIG,
+        &[
+            Token::Uint((*next_cash_yield).into()),
+            Token::Uint((*next_cash_index).into()),
+            Token::Uint((*next_cash_yield_start).into()),
+        ],
+        header.to_vec(),
+    )
+}
+
+pub fn encode_set_supply_cap_notice_eth_like(
+    header: &[u8],
+    id: &NoticeId,
+    parent: &<Ethereum as Chain>::Hash,
+    asset: &<Ethereum as Chain>::Address,
+    cap: &<Ethereum as Chain>::Amount,
+) -> EncodedNotice {
+    encode_notice_params_eth_like(
+        id,
+        parent,
+        *SET_SUPPLY_CAP_SIG,
+        &[Token::Address(asset.into()), Token::Uint((*cap).into())],
+        header.to_vec(),
+    )
+}
+
+pub fn encode_change_authority_notice_eth_like(
+    header: &[u8],
+    id: &NoticeId,
+    parent: &<Ethereum as Chain>::Hash,
+    new_authorities: &[<Ethereum as Chain>::Address],
+) -> EncodedNotice {
+    encode_notice_params_eth_like(
+        id,
+        parent,
+        *CHANGE_AUTHORITIES_SIG,
+        &[Token::Array(
+            new_authorities
+                .iter()
+                .map(|auth| Token::Address(auth.into()))
+                .collect(),
+        )],
+        header.to_vec(),
+    )
+}
+
+pub fn encode_extraction_notice_dot(
+    header: &[u8],
+    id: &NoticeId,
+    parent: &<Polkadot as Chain>::Hash,
+    asset: &PolkadotAssetId,
+    account: &<Polkadot as Chain>::Address,
+    amount: &<Polkadot as Chain>::Amount,
+) -> EncodedNotice {
+    encode_notice_params_dot(
+        header,
+        id,
+        parent,
+        DOT_UNLOCK_CALL,
+        &(asset, amount, account).encode()[..],
+    )
+}
+
+pub fn encode_cash_extraction_notice_dot(
+    header: &[u8],
+    id: &NoticeId,
+    parent: &<Polkadot as Chain>::Hash,
+    account: &<Polkadot as Chain>::Address,
+    principal: &<Polkadot as Chain>::Amount,
+) -> EncodedNotice {
+    encode_notice_params_dot(
+        header,
+        id,
+        parent,
+        DOT_UNLOCK_CASH_CALL,
+        &(account, principal).encode()[..],
+    )
+}
+
+pub fn encode_future_yield_notice_dot(
+    header: &[u8],
+    id: &NoticeId,
+    parent: &<Polkadot as Chain>::Hash,
+    next_cash_yield: &<Polkadot as Chain>::Rate,
+    next_cash_index: &<Polkadot as Chain>::CashIndex,
+    next_cash_yield_start: &<Polkadot as Chain>::Timestamp,
+) -> EncodedNotice {
+    encode_notice_params_dot(
+        header,
+        id,
+        parent,
+        DOT_SET_FUTURE_YIELD_CALL,
+        &(next_cash_yield, next_cash_index, next_cash_yield_start).encode()[..],
+    )
+}
+
+pub fn encode_set_supply_cap_notice_dot(
+    header: &[u8],
+    id: &NoticeId,
+    parent: &<Polkadot as Chain>::Hash,
+    asset: &PolkadotAssetId,
+    cap: &<Polkadot as Chain>::Amount,
+) -> EncodedNotice {
+    encode_notice_params_dot(
+        header,
+        id,
+        parent,
+        DOT_SET_SUPPLY_CAP_CALL,
+        &(asset, cap).encode()[..],
+    )
+}
+
+pub fn encode_change_authority_notice_dot(
+    header: &[u8],
+    id: &NoticeId,
+    parent: &<Polkadot as Chain>::Hash,
+    new_authorities: &[<Ethereum as Chain>::Address],
+) -> EncodedNotice {
+    encode_notice_params_dot(
+        header,
+        id,
+        parent,
+        DOT_CHANGE_AUTHORITIES_CALL,
+        &new_authorities.encode()[..],
+    )
+}
+
 impl EncodeNotice for ExtractionNotice {
     fn encode_notice(&self) -> EncodedNotice {
         match self {
@@ -217,33 +497,35 @@ impl EncodeNotice for ExtractionNotice {
                 asset,
                 account,
                 amount,
-            } => encode_notice_params_eth_like(
+            } => Ethereum::encode_extraction_notice(id, parent, asset, account, amount),
+            ExtractionNotice::Matic {
                 id,
                 parent,
-                *UNLOCK_SIG,
-                &[
-                    Token::Address(asset.into()),
-                    Token::Uint((*amount).into()),
-                    Token::Address(account.into()),
-                ],
-                ETH_CHAIN_IDENT.to_vec(),
-            ),
-            ExtractionNotice::Matic {
+                asset,
+                account,
+                amount,
+            } => Polygon::encode_extraction_notice(id, parent, asset, account, amount),
+            ExtractionNotice::Dot {
+                id,
+                parent,
+                asset,
+                account,
+                amount,
+            } => Polkadot::encode_extraction_notice(id, parent, asset, account, amount),
+            ExtractionNotice::Evm {
+                evm_chain_id,
                 id,
                 parent,
                 asset,
                 account,
                 amount,
-            } => encode_notice_params_eth_like(
+            } => encode_extraction_notice_eth_like(
+                &evm_notice_header(*evm_chain_id),
                 id,
                 parent,
-                *UNLOCK_SIG,
-                &[
-                    Token::Address(asset.into()),
-                    Token::Uint((*amount).into()),
-                    Token::Address(account.into()),
-                ],
-                MATIC_CHAIN_IDENT.to_vec(),
+                asset,
+                account,
+                amount,
             ),
         }
     }
@@ -257,30 +539,31 @@ impl EncodeNotice for CashExtractionNotice {
                 parent,
                 account,
                 principal,
-            } => encode_notice_params_eth_like(
+            } => Ethereum::encode_cash_extraction_notice(id, parent, account, principal),
+            CashExtractionNotice::Matic {
                 id,
                 parent,
-                *UNLOCK_CASH_SIG,
-                &[
-                    Token::Address(account.into()),
-                    Token::Uint((*principal).into()),
-                ],
-                ETH_CHAIN_IDENT.to_vec(),
-            ),
-            CashExtractionNotice::Matic {
+                account,
+                principal,
+            } => Polygon::encode_cash_extraction_notice(id, parent, account, principal),
+            CashExtractionNotice::Dot {
+                id,
+                parent,
+                account,
+                principal,
+            } => Polkadot::encode_cash_extraction_notice(id, parent, account, principal),
+            CashExtractionNotice::Evm {
+                evm_chain_id,
                 id,
                 parent,
                 account,
                 principal,
-            } => encode_notice_params_eth_like(
+            } => encode_cash_extraction_notice_eth_like(
+                &evm_notice_header(*evm_chain_id),
                 id,
                 parent,
-                *UNLOCK_CASH_SIG,
-                &[
-                    Token::Address(account.into()),
-                    Token::Uint((*principal).into()),
-                ],
-                MATIC_CHAIN_IDENT.to_vec(),
+                account,
+                principal,
             ),
         }
     }
@@ -295,16 +578,12 @@ impl EncodeNotice for FutureYieldNotice {
                 next_cash_yield,
                 next_cash_yield_start,
                 next_cash_index,
-            } => encode_notice_params_eth_like(
+            } => Ethereum::encode_future_yield_notice(
                 id,
                 parent,
-                *SET_FUTURE_YIELD_SIG,
-                &[
-                    Token::Uint((*next_cash_yield).into()),
-                    Token::Uint((*next_cash_index).into()),
-                    Token::Uint((*next_cash_yield_start).into()),
-                ],
-                ETH_CHAIN_IDENT.to_vec(),
+                next_cash_yield,
+                next_cash_index,
+                next_cash_yield_start,
             ),
             FutureYieldNotice::Matic {
                 id,
@@ -312,16 +591,40 @@ impl EncodeNotice for FutureYieldNotice {
                 next_cash_yield,
                 next_cash_yield_start,
                 next_cash_index,
-            } => encode_notice_params_eth_like(
+            } => Polygon::encode_future_yield_notice(
+                id,
+                parent,
+                next_cash_yield,
+                next_cash_index,
+                next_cash_yield_start,
+            ),
+            FutureYieldNotice::Dot {
+                id,
+                parent,
+                next_cash_yield,
+                next_cash_yield_start,
+                next_cash_index,
+            } => Polkadot::encode_future_yield_notice(
                 id,
                 parent,
-                *SET_FUTURE_YIELD_SIG,
-                &[
-                    Token::Uint((*next_cash_yield).into()),
-                    Token::Uint((*next_cash_index).into()),
-                    Token::Uint((*next_cash_yield_start).into()),
-                ],
-                MATIC_CHAIN_IDENT.to_vec(),
+                next_cash_yield,
+                next_cash_index,
+                next_cash_yield_start,
+            ),
+            FutureYieldNotice::Evm {
+                evm_chain_id,
+                id,
+                parent,
+                next_cash_yield,
+                next_cash_yield_start,
+                next_cash_index,
+            } => encode_future_yield_notice_eth_like(
+                &evm_notice_header(*evm_chain_id),
+                id,
+                parent,
+                next_cash_yield,
+                next_cash_index,
+                next_cash_yield_start,
             ),
         }
     }
@@ -335,24 +638,31 @@ impl EncodeNotice for SetSupplyCapNotice {
                 parent,
                 asset,
                 cap,
-            } => encode_notice_params_eth_like(
+            } => Ethereum::encode_set_supply_cap_notice(id, parent, asset, cap),
+            SetSupplyCapNotice::Matic {
                 id,
                 parent,
-                *SET_SUPPLY_CAP_SIG,
-                &[Token::Address(asset.into()), Token::Uint((*cap).into())],
-                ETH_CHAIN_IDENT.to_vec(),
-            ),
-            SetSupplyCapNotice::Matic {
+                asset,
+                cap,
+            } => Polygon::encode_set_supply_cap_notice(id, parent, asset, cap),
+            SetSupplyCapNotice::Dot {
+                id,
+                parent,
+                asset,
+                cap,
+            } => Polkadot::encode_set_supply_cap_notice(id, parent, asset, cap),
+            SetSupplyCapNotice::Evm {
+                evm_chain_id,
                 id,
                 parent,
                 asset,
                 cap,
-            } => encode_notice_params_eth_like(
+            } => encode_set_supply_cap_notice_eth_like(
+                &evm_notice_header(*evm_chain_id),
                 id,
                 parent,
-                *SET_SUPPLY_CAP_SIG,
-                &[Token::Address(asset.into()), Token::Uint((*cap).into())],
-                MATIC_CHAIN_IDENT.to_vec(),
+                asset,
+                cap,
             ),
         }
     }
@@ -365,33 +675,27 @@ impl EncodeNotice for ChangeAuthorityNotice {
                 id,
                 parent,
                 new_authorities,
-            } => encode_notice_params_eth_like(
+            } => Ethereum::encode_change_authority_notice(id, parent, new_authorities),
+            ChangeAuthorityNotice::Matic {
                 id,
                 parent,
-                *CHANGE_AUTHORITIES_SIG,
-                &[Token::Array(
-                    new_authorities
-                        .iter()
-                        .map(|auth| Token::Address(auth.into()))
-                        .collect(),
-                )],
-                ETH_CHAIN_IDENT.to_vec(),
-            ),
-            ChangeAuthorityNotice::Matic {
+                new_authorities,
+            } => Polygon::encode_change_authority_notice(id, parent, new_authorities),
+            ChangeAuthorityNotice::Dot {
                 id,
                 parent,
                 new_authorities,
-            } => encode_notice_params_eth_like(
+            } => Polkadot::encode_change_authority_notice(id, parent, new_authorities),
+            ChangeAuthorityNotice::Evm {
+                evm_chain_id,
                 id,
                 parent,
-                *CHANGE_AUTHORITIES_SIG,
-                &[Token::Array(
-                    new_authorities
-                        .iter()
-                        .map(|auth| Token::Address(auth.into()))
-                        .collect(),
-                )],
-                MATIC_CHAIN_IDENT.to_vec(),
+                new_authorities,
+            } => encode_change_authority_notice_eth_like(
+                &evm_notice_header(*evm_chain_id),
+                id,
+                parent,
+                new_authorities,
             ),
         }
     }
@@ -414,22 +718,42 @@ pub fn default_notice_signatures(notice: &Notice) -> ChainSignatureList {
         Notice::ExtractionNotice(n) => match n {
             ExtractionNotice::Eth { .. } => ChainSignatureList::Eth(vec![]),
             ExtractionNotice::Matic { .. } => ChainSignatureList::Matic(vec![]),
+            ExtractionNotice::Dot { .. } => ChainSignatureList::Dot(vec![]),
+            ExtractionNotice::Evm { evm_chain_id, .. } => {
+                ChainSignatureList::Evm(*evm_chain_id, vec![])
+            }
         },
         Notice::CashExtractionNotice(n) => match n {
             CashExtractionNotice::Eth { .. } => ChainSignatureList::Eth(vec![]),
             CashExtractionNotice::Matic { .. } => ChainSignatureList::Matic(vec![]),
+            CashExtractionNotice::Dot { .. } => ChainSignatureList::Dot(vec![]),
+            CashExtractionNotice::Evm { evm_chain_id, .. } => {
+                ChainSignatureList::Evm(*evm_chain_id, vec![])
+            }
         },
         Notice::FutureYieldNotice(n) => match n {
             FutureYieldNotice::Eth { .. } => ChainSignatureList::Eth(vec![]),
             FutureYieldNotice::Matic { .. } => ChainSignatureList::Matic(vec![]),
+            FutureYieldNotice::Dot { .. } => ChainSignatureList::Dot(vec![]),
+            FutureYieldNotice::Evm { evm_chain_id, .. } => {
+                ChainSignatureList::Evm(*evm_chain_id, vec![])
+            }
         },
         Notice::SetSupplyCapNotice(n) => match n {
             SetSupplyCapNotice::Eth { .. } => ChainSignatureList::Eth(vec![]),
             SetSupplyCapNotice::Matic { .. } => ChainSignatureList::Matic(vec![]),
+            SetSupplyCapNotice::Dot { .. } => ChainSignatureList::Dot(vec![]),
+            SetSupplyCapNotice::Evm { evm_chain_id, .. } => {
+                ChainSignatureList::Evm(*evm_chain_id, vec![])
+            }
         },
         Notice::ChangeAuthorityNotice(n) => match n {
             ChangeAuthorityNotice::Eth { .. } => ChainSignatureList::Eth(vec![]),
             ChangeAuthorityNotice::Matic { .. } => ChainSignatureList::Matic(vec![]),
+            ChangeAuthorityNotice::Dot { .. } => ChainSignatureList::Dot(vec![]),
+            ChangeAuthorityNotice::Evm { evm_chain_id, .. } => {
+                ChainSignatureList::Evm(*evm_chain_id, vec![])
+            }
         },
     }
 }
@@ -759,4 +1083,79 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_encodes_dot_extraction_notice() {
+        let notice = Notice::ExtractionNotice(ExtractionNotice::Dot {
+            id: NoticeId(80, 1),
+            parent: [3u8; 32],
+            asset: 7,
+            amount: 50,
+            account: [1u8; 32],
+        });
+
+        let expected = [
+            68, 79, 84, 58, // DOT:
+            80, 0, 0, 0, // eraId
+            1, 0, 0, 0, // eraIndex
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, // parent
+            0, // Call Index (unlock)
+            7, 0, 0, 0, // asset
+            50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, // amount
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, // account
+        ];
+        assert_eq!(notice.encode_notice(), expected);
+        assert_eq!(notice.chain_id(), ChainId::Dot);
+    }
+
+    #[test]
+    fn test_encodes_dot_change_authorities_notice() {
+        let notice = Notice::ChangeAuthorityNotice(ChangeAuthorityNotice::Dot {
+            id: NoticeId(0, 2),
+            parent: [0u8; 32],
+            new_authorities: vec![[6u8; 20]],
+        });
+
+        let expected = [
+            68, 79, 84, 58, // DOT:
+            0, 0, 0, 0, // eraId
+            2, 0, 0, 0, // eraIndex
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, // parent
+            4, // Call Index (changeAuthorities)
+            4, // vec length (compact)
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, // vec[0]
+        ];
+        assert_eq!(notice.encode_notice(), expected);
+    }
+    #[test]
+    fn test_encodes_evm_extraction_notice() {
+        let eth_notice = Notice::ExtractionNotice(ExtractionNotice::Eth {
+            id: NoticeId(80, 1),
+            parent: [3u8; 32],
+            asset: [2u8; 20],
+            amount: 50,
+            account: [1u8; 20],
+        });
+        let evm_notice = Notice::ExtractionNotice(ExtractionNotice::Evm {
+            evm_chain_id: 10,
+            id: NoticeId(80, 1),
+            parent: [3u8; 32],
+            asset: [2u8; 20],
+            amount: 50,
+            account: [1u8; 20],
+        });
+
+        let eth_encoded = eth_notice.encode_notice();
+        let evm_encoded = evm_notice.encode_notice();
+        assert_eq!(&evm_encoded[0..8], &[69, 86, 77, 0, 0, 0, 10, 58]); // EVM{10}:
+        assert_eq!(&evm_encoded[8..], &eth_encoded[4..]);
+        assert_eq!(evm_notice.chain_id(), ChainId::Evm(10));
+        assert_eq!(
+            default_notice_signatures(&evm_notice),
+            ChainSignatureList::Evm(10, vec![])
+        );
+    }
 }
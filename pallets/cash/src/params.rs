@@ -8,19 +8,36 @@ use crate::{
 pub const INGRESS_LARGE: Quantity = Quantity::from_nominal("1000000000000", USD);
 
 /// The maximum value (USD) that can be ingested per underlying chain block.
-/// Could become a per-chain quota in the future.
+/// Used for any chain without its own ingress config.
 pub const INGRESS_QUOTA: Quantity = Quantity::from_nominal("10000", USD);
 
 /// Maximum size of the block queue before we back-off sending new blocks.
+/// Used for any chain without its own ingress config.
 pub const INGRESS_SLACK: u32 = 50;
 
+/// Maximum number of underlying chain blocks which may be submitted together in a single batch.
+pub const MAX_CHAIN_BLOCKS: u32 = 100;
+
+/// Maximum number of events which may be submitted together in a single batch of blocks.
+/// A lone block cannot be split, so it may hold more.
+pub const MAX_CHAIN_BLOCK_EVENTS: u32 = 100;
+
+/// Maximum number of events which may wait on an ingression queue, before we stop accepting blocks.
+/// Used for any chain without its own ingress config.
+pub const MAX_QUEUE_EVENTS: u32 = 1000;
+
+/// Maximum number of dead-lettered events kept per chain, beyond which the oldest are dropped.
+pub const MAX_DEAD_LETTER_EVENTS: u32 = 1000;
+
 /// Number of milliseconds in a year.
 pub const MILLISECONDS_PER_YEAR: Timestamp = 365 * 24 * 60 * 60 * 1000;
 
 /// Minimum number of underlying chain blocks to wait before ingesting any event, due to reorg risk.
+/// Used for any chain without its own ingress config.
 pub const MIN_EVENT_BLOCKS: ChainBlockNumber = 3;
 
 /// Maximum number of underlying chain blocks to wait before just ingesting any event.
+/// Used for any chain without its own ingress config.
 pub const MAX_EVENT_BLOCKS: ChainBlockNumber = 60;
 
 /// Minimum amount of time (milliseconds) into the future that a synchronized change may be scheduled for.
@@ -34,6 +51,10 @@ pub const MIN_PRINCIPAL_GATE: CashPrincipal = CashPrincipal::from_nominal("1");
 /// Minimum value (USD) required across all protocol interactions.
 pub const MIN_TX_VALUE: Quantity = Quantity::from_nominal("1", USD);
 
+/// The SS58 address type used to encode Gateway accounts.
+/// The runtime configures its `SS58Prefix` from this, so that parsed accounts always agree with it.
+pub const SS58_PREFIX: u8 = gateway_crypto::GATEWAY_SS58_PREFIX;
+
 /// Flat transfer fee (CASH).
 pub const TRANSFER_FEE: Quantity = Quantity::from_nominal("0.01", CASH);
 
@@ -54,3 +75,9 @@ pub const GATEWAY_VOID: ChainAccount = ChainAccount::Gate([0u8; 32]);
 
 /// The maximum length of a trx request
 pub const MAX_TRX_REQUEST_LEN: usize = 2048;
+
+/// The maximum number of requests in a batch trx request
+pub const MAX_TRX_REQUEST_BATCH_SIZE: usize = 16;
+
+/// The maximum nesting depth of batch trx requests, i.e. batches may not contain batches
+pub const MAX_TRX_REQUEST_BATCH_DEPTH: usize = 1;
@@ -31,6 +31,15 @@ impl Portfolio {
         }
         Ok(liquidity)
     }
+
+    /// Get the balance of the given asset, which is zero if there is no position in it.
+    pub fn get_asset_balance(&self, asset_info: AssetInfo) -> Balance {
+        self.positions
+            .iter()
+            .find(|(info, _)| info.asset == asset_info.asset)
+            .map(|(_, balance)| *balance)
+            .unwrap_or_else(|| asset_info.as_balance(0))
+    }
 }
 
 #[cfg(test)]
@@ -59,7 +59,7 @@ pub enum Reason {
     SignatureMismatch,
     TimestampMissing,
     TimeTravelNotAllowed,
-    TrxRequestParseError(TrxReqParseError),
+    TrxRequestParseError(SpannedTrxReqParseError),
     UnknownValidator,
     InvalidChain,
     PendingAuthNotice,
@@ -75,6 +75,20 @@ pub enum Reason {
     StarportMissing,
     InvalidChainBlock,
     TrxRequestTooLong,
+    NothingToLiquidate,
+    ExcessivePrecision,
+    TrxRequestExpired,
+    TrxRequestWrongNetwork,
+    BadIngressConfig,
+    IngressQueueFull,
+    ReorgBelowFinality,
+    BadRiskModel,
+    TrxRequestBatchTooLarge,
+    TrxRequestBatchTooDeep,
+    ReorgTooDeep,
+    IrreversibleEventReorged,
+    IngressQueueNotEmpty,
+    AssetSymbolTaken,
 }
 
 impl From<Reason> for frame_support::dispatch::DispatchError {
@@ -146,6 +160,20 @@ impl From<Reason> for frame_support::dispatch::DispatchError {
             Reason::StarportMissing => (40, 0, "starport address not set"),
             Reason::InvalidChainBlock => (41, 0, "invalid chain block"),
             Reason::TrxRequestTooLong => (42, 0, "the trx request was too long"),
+            Reason::NothingToLiquidate => (43, 0, "nothing to liquidate"),
+            Reason::ExcessivePrecision => (44, 0, "amount is more precise than the asset allows"),
+            Reason::TrxRequestExpired => (45, 0, "the trx request has expired"),
+            Reason::TrxRequestWrongNetwork => (46, 0, "the trx request is for another network"),
+            Reason::BadIngressConfig => (47, 0, "bad ingress config"),
+            Reason::IngressQueueFull => (48, 0, "the ingression queue is full"),
+            Reason::ReorgBelowFinality => (49, 0, "cannot reorg finalized blocks"),
+            Reason::BadRiskModel => (50, 0, "bad risk model"),
+            Reason::TrxRequestBatchTooLarge => (51, 0, "the trx request batch was too large"),
+            Reason::TrxRequestBatchTooDeep => (52, 0, "the trx request batches were nested"),
+            Reason::ReorgTooDeep => (53, 0, "the reorg has too many blocks"),
+            Reason::IrreversibleEventReorged => (54, 0, "cannot reorg an irreversible event"),
+            Reason::IngressQueueNotEmpty => (55, 0, "the ingress queue still has events"),
+            Reason::AssetSymbolTaken => (56, 0, "another asset has the symbol"),
         };
         frame_support::dispatch::DispatchError::Module {
             index,
@@ -191,14 +219,14 @@ impl From<SetYieldNextError> for Reason {
     }
 }
 
-impl From<TrxReqParseError> for Reason {
-    fn from(err: TrxReqParseError) -> Self {
+impl From<SpannedTrxReqParseError> for Reason {
+    fn from(err: SpannedTrxReqParseError) -> Self {
         Reason::TrxRequestParseError(err)
     }
 }
 
-impl From<trx_request::ParseError<'_>> for Reason {
-    fn from(err: trx_request::ParseError<'_>) -> Self {
+impl From<trx_request::SpannedParseError<'_>> for Reason {
+    fn from(err: trx_request::SpannedParseError<'_>) -> Self {
         Reason::TrxRequestParseError(err.into())
     }
 }
@@ -237,11 +265,33 @@ pub enum TrxReqParseError {
     InvalidAmount,
     InvalidAccount,
     InvalidAsset,
-    InvalidArgs,
+    InvalidArgs(u32, u32),
     UnknownFunction,
     InvalidExpression,
     InvalidChain,
-    InvalidChainAccount,
+    InvalidChainAccount(ChainId),
+}
+
+/// The kind of token expected where a trx request failed to parse.
+#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
+pub enum TrxReqExpected {
+    Token,
+    Expression,
+    Function,
+    Arguments,
+    Amount,
+    Asset,
+    Account,
+    Chain,
+}
+
+/// Error from parsing trx requests, with the byte span of the request where it occurred.
+#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
+pub struct SpannedTrxReqParseError {
+    pub error: TrxReqParseError,
+    pub start: u32,
+    pub end: u32,
+    pub expected: TrxReqExpected,
 }
 
 impl From<trx_request::ParseError<'_>> for TrxReqParseError {
@@ -252,13 +302,42 @@ impl From<trx_request::ParseError<'_>> for TrxReqParseError {
             trx_request::ParseError::InvalidAmount => TrxReqParseError::InvalidAmount,
             trx_request::ParseError::InvalidAccount => TrxReqParseError::InvalidAccount,
             trx_request::ParseError::InvalidAsset => TrxReqParseError::InvalidAsset,
-            trx_request::ParseError::InvalidArgs(_, _, _) => TrxReqParseError::InvalidArgs,
+            trx_request::ParseError::InvalidArgs(_, expected, given) => {
+                TrxReqParseError::InvalidArgs(expected as u32, given as u32)
+            }
             trx_request::ParseError::UnknownFunction(_) => TrxReqParseError::UnknownFunction,
             trx_request::ParseError::InvalidExpression => TrxReqParseError::InvalidExpression,
             trx_request::ParseError::InvalidChain(_) => TrxReqParseError::InvalidChain,
-            trx_request::ParseError::InvalidChainAccount(_) => {
-                TrxReqParseError::InvalidChainAccount
+            trx_request::ParseError::InvalidChainAccount(chain) => {
+                TrxReqParseError::InvalidChainAccount(chain.into())
             }
         }
     }
 }
+
+impl From<trx_request::Expected> for TrxReqExpected {
+    fn from(expected: trx_request::Expected) -> Self {
+        match expected {
+            trx_request::Expected::Token => TrxReqExpected::Token,
+            trx_request::Expected::Expression => TrxReqExpected::Expression,
+            trx_request::Expected::Function => TrxReqExpected::Function,
+            trx_request::Expected::Arguments => TrxReqExpected::Arguments,
+            trx_request::Expected::Amount => TrxReqExpected::Amount,
+            trx_request::Expected::Asset => TrxReqExpected::Asset,
+            trx_request::Expected::Account => TrxReqExpected::Account,
+            trx_request::Expected::Chain => TrxReqExpected::Chain,
+        }
+    }
+}
+
+impl From<trx_request::SpannedParseError<'_>> for SpannedTrxReqParseError {
+    fn from(err: trx_request::SpannedParseError) -> Self {
+        let (start, end) = err.span;
+        SpannedTrxReqParseError {
+            expected: err.expected().into(),
+            error: err.error.into(),
+            start: start as u32,
+            end: end as u32,
+        }
+    }
+}
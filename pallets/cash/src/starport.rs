@@ -0,0 +1,1232 @@
+use crate::{
+    chains::{
+        Chain, ChainAccount, ChainAsset, ChainBlockEvent, ChainBlockNumber, ChainBlocks, ChainHash,
+        ChainId, ChainReorg, Ethereum, EvmChainId, Polkadot, Polygon,
+    },
+    core::dispatch_extrinsics_internal,
+    debug,
+    events::{
+        fetch_dot_block, fetch_eth_like_block, fetch_eth_like_block_number, fetch_eth_like_blocks,
+        get_cached_eth_block, get_cached_eth_blocks, EventError,
+    },
+    internal::{
+        self,
+        assets::{get_cash_quantity, get_quantity, get_value},
+    },
+    notices::{self, EncodedNotice, NoticeId},
+    params::INGRESS_LARGE,
+    reason::Reason,
+    types::{CashPrincipalAmount, EventKind, Quantity, USDQuantity, USD},
+    Config, EvmChains,
+};
+use ethereum_client::{
+    EthereumBlock, EthereumBlockId, EthereumClientError, EthereumEvent, EthereumLogEvent,
+};
+use frame_support::storage::StorageMap;
+use our_std::{vec::Vec, Debuggable};
+use polkadot_client::{PolkadotAssetId, PolkadotBlockId, PolkadotClientError, PolkadotEvent};
+
+/// A chain with a starport, which we ingest events from and submit notices to.
+/// This bundles everything the pallet needs to know about a particular chain,
+///  so supporting a new chain means implementing this trait and registering its `ChainId`.
+/// Ethereum-like chains get it by implementing `EthLikeChain` instead.
+pub trait StarportChain: Chain {
+    /// The header prepended to notices, identifying the chain they are meant for.
+    const NOTICE_HEADER: &'static [u8];
+
+    /// The type the starport uses to identify assets.
+    type AssetId: Debuggable + Clone + Eq;
+
+    fn chain_asset(asset: Self::AssetId) -> ChainAsset;
+    fn chain_hash(hash: Self::Hash) -> ChainHash;
+    fn chain_blocks(blocks: Vec<Self::Block>) -> ChainBlocks;
+
+    /// Describe a reorg of the chain, from the blocks to reverse (newest first)
+    ///  to the blocks to go forward with (oldest first).
+    fn chain_reorg(
+        reverse_blocks: Vec<Self::Block>,
+        forward_blocks: Vec<Self::Block>,
+    ) -> Result<ChainReorg, Reason>;
+
+    /// Fetch a single block from the starport by number.
+    fn fetch_block(
+        number: ChainBlockNumber,
+        starport: &Self::Address,
+    ) -> Result<Self::Block, EventError>;
+
+    /// Fetch a single block from the starport by hash.
+    fn fetch_block_by_hash(
+        hash: Self::Hash,
+        starport: &Self::Address,
+    ) -> Result<Self::Block, EventError>;
+
+    /// Whether the error means the block simply does not exist yet.
+    fn is_block_missing(err: &EventError) -> bool;
+
+    /// Fetch blocks from the starport, stopping at the first one which does not exist yet.
+    /// The result may hold fewer blocks than requested, even if later ones exist.
+    fn fetch_blocks(
+        from: ChainBlockNumber,
+        to: ChainBlockNumber,
+        starport: &Self::Address,
+    ) -> Result<ChainBlocks, EventError> {
+        debug!(
+            "Fetching Blocks chain_id={:?}, from_block={}, to_block={}",
+            Self::ID,
+            from,
+            to
+        );
+        let mut acc: Vec<Self::Block> = vec![];
+        for block_number in from..to {
+            match Self::fetch_block(block_number, starport) {
+                Ok(block) => {
+                    acc.push(block);
+                }
+                Err(err) => {
+                    if Self::is_block_missing(&err) {
+                        break;
+                    }
+                    return Err(err);
+                }
+            }
+        }
+        Ok(Self::chain_blocks(acc))
+    }
+
+    /// Fetch the number of the latest block the chain has finalized.
+    /// Chains without a notion of finality we can query simply do not support it.
+    fn fetch_finalized_block_number() -> Result<ChainBlockNumber, EventError> {
+        Err(EventError::ActionNotSupported)
+    }
+
+    /// Apply the event to the current state, effectively taking the action.
+    fn apply_event<T: Config>(event: &Self::Event) -> Result<(), Reason>;
+
+    /// Un-apply the event on the current state, undoing the action to the extent possible/necessary.
+    fn unapply_event<T: Config>(event: &Self::Event) -> Result<(), Reason>;
+
+    /// Whether the event has effects which un-applying cannot undo.
+    /// Such events are only applied once they are beyond reorg depth.
+    fn is_irreversible(event: &Self::Event) -> bool;
+
+    /// Determine the value at risk of an event, before any decay.
+    fn event_value<T: Config>(event: &Self::Event) -> Result<USDQuantity, Reason>;
+
+    /// Determine the kind of an event, which selects the risk model applied to its value.
+    fn event_kind(event: &Self::Event) -> Result<EventKind, Reason>;
+
+    fn encode_extraction_notice(
+        id: &NoticeId,
+        parent: &Self::Hash,
+        asset: &Self::AssetId,
+        account: &Self::Address,
+        amount: &Self::Amount,
+    ) -> EncodedNotice;
+
+    fn encode_cash_extraction_notice(
+        id: &NoticeId,
+        parent: &Self::Hash,
+        account: &Self::Address,
+        principal: &Self::Amount,
+    ) -> EncodedNotice;
+
+    fn encode_future_yield_notice(
+        id: &NoticeId,
+        parent: &Self::Hash,
+        next_cash_yield: &Self::Rate,
+        next_cash_index: &Self::CashIndex,
+        next_cash_yield_start: &Self::Timestamp,
+    ) -> EncodedNotice;
+
+    fn encode_set_supply_cap_notice(
+        id: &NoticeId,
+        parent: &Self::Hash,
+        asset: &Self::AssetId,
+        cap: &Self::Amount,
+    ) -> EncodedNotice;
+
+    fn encode_change_authority_notice(
+        id: &NoticeId,
+        parent: &Self::Hash,
+        new_authorities: &[<Ethereum as Chain>::Address],
+    ) -> EncodedNotice;
+}
+
+/// Determine the value at risk of a trx request from a starport, by what it would move now.
+/// Requests are valued against the current state, which is the state they are applied to,
+///  since the ingression queue applies each event in the same round it values it.
+/// A request which cannot be valued would fail to apply as well, moving nothing,
+///  so it is valued at zero and left to fail when applied, unless a price is just missing.
+fn trx_request_event_value<T: Config>(
+    trx_request: &str,
+    sender: ChainAccount,
+) -> Result<USDQuantity, Reason> {
+    match internal::exec_trx_request::trx_request_value::<T>(trx_request, sender) {
+        Err(Reason::NoPrice) => Err(Reason::NoPrice),
+        Err(_) => Ok(Quantity::new(0, USD)),
+        value => value,
+    }
+}
+
+/// Describe a reorg of whichever chain the blocks come from, as its starport would.
+/// Ethereum-like chains are all described by the `EthLikeStarport` for their chain id.
+pub fn chain_reorg(
+    reverse_blocks: ChainBlocks,
+    forward_blocks: ChainBlocks,
+) -> Result<ChainReorg, Reason> {
+    let chain_id = reverse_blocks.chain_id();
+    match (reverse_blocks, forward_blocks) {
+        (ChainBlocks::Dot(reverse), ChainBlocks::Dot(forward)) => {
+            Polkadot::chain_reorg(reverse, forward)
+        }
+        (ChainBlocks::Eth(reverse), ChainBlocks::Eth(forward))
+        | (ChainBlocks::Matic(reverse), ChainBlocks::Matic(forward)) => {
+            EthLikeStarport::new(chain_id).chain_reorg(reverse, forward)
+        }
+        (ChainBlocks::Evm(evm_chain_id, reverse), ChainBlocks::Evm(forward_chain_id, forward))
+            if evm_chain_id == forward_chain_id =>
+        {
+            EthLikeStarport::new(chain_id).chain_reorg(reverse, forward)
+        }
+        _ => Err(Reason::InvalidChainBlock),
+    }
+}
+
+/// An event, along with the starport it was ingested from, which knows how to handle it.
+/// Events from every Ethereum-like chain go through the `EthLikeStarport` for their chain id,
+///  so only a chain with its own kind of event needs its own variant.
+pub enum StarportEvent<'a> {
+    EthLike(EthLikeStarport, &'a EthereumLogEvent),
+    Dot(&'a PolkadotEvent),
+}
+
+impl<'a> StarportEvent<'a> {
+    pub fn new(event: &'a ChainBlockEvent) -> Self {
+        match event {
+            ChainBlockEvent::Reserved => panic!("reserved"),
+            ChainBlockEvent::Eth(_block_num, eth_event)
+            | ChainBlockEvent::Matic(_block_num, eth_event)
+            | ChainBlockEvent::Evm(_, _block_num, eth_event) => {
+                StarportEvent::EthLike(EthLikeStarport::new(event.chain_id()), eth_event)
+            }
+            ChainBlockEvent::Dot(_block_num, dot_event) => StarportEvent::Dot(dot_event),
+        }
+    }
+
+    /// Apply the event to the current state, effectively taking the action.
+    pub fn apply<T: Config>(&self) -> Result<(), Reason> {
+        match self {
+            StarportEvent::EthLike(starport, event) => starport.apply_event::<T>(event),
+            StarportEvent::Dot(event) => Polkadot::apply_event::<T>(event),
+        }
+    }
+
+    /// Un-apply the event on the current state, undoing the action to the extent possible/necessary.
+    pub fn unapply<T: Config>(&self) -> Result<(), Reason> {
+        match self {
+            StarportEvent::EthLike(starport, event) => starport.unapply_event::<T>(event),
+            StarportEvent::Dot(event) => Polkadot::unapply_event::<T>(event),
+        }
+    }
+
+    /// Whether the event has effects which un-applying cannot undo.
+    pub fn is_irreversible(&self) -> bool {
+        match self {
+            StarportEvent::EthLike(starport, event) => starport.is_irreversible(event),
+            StarportEvent::Dot(event) => Polkadot::is_irreversible(event),
+        }
+    }
+
+    /// Determine the value at risk of the event, before any decay.
+    pub fn value<T: Config>(&self) -> Result<USDQuantity, Reason> {
+        match self {
+            StarportEvent::EthLike(starport, event) => starport.event_value::<T>(event),
+            StarportEvent::Dot(event) => Polkadot::event_value::<T>(event),
+        }
+    }
+
+    /// Determine the kind of the event, which selects the risk model applied to its value.
+    pub fn kind(&self) -> Result<EventKind, Reason> {
+        match self {
+            StarportEvent::EthLike(starport, event) => starport.event_kind(event),
+            StarportEvent::Dot(event) => Polkadot::event_kind(event),
+        }
+    }
+}
+
+/// An Ethereum-like chain, whose starport emits Ethereum events and accepts Ethereum notices.
+/// Such chains share their `StarportChain` implementation, which reaches each of them through
+///  the `EthLikeStarport` for its chain id, so implementing this trait is all they need.
+pub trait EthLikeChain:
+    Chain<
+    Address = [u8; 20],
+    Amount = u128,
+    CashIndex = u128,
+    Rate = u128,
+    Timestamp = u64,
+    Hash = [u8; 32],
+    Event = EthereumLogEvent,
+    Block = EthereumBlock,
+>
+{
+    /// The header prepended to notices, identifying the chain they are meant for.
+    const NOTICE_HEADER: &'static [u8];
+
+    fn chain_asset(asset: [u8; 20]) -> ChainAsset;
+    fn chain_hash(hash: [u8; 32]) -> ChainHash;
+    fn chain_blocks(blocks: Vec<EthereumBlock>) -> ChainBlocks;
+}
+
+impl EthLikeChain for Ethereum {
+    const NOTICE_HEADER: &'static [u8] = b"ETH:";
+
+    fn chain_asset(asset: [u8; 20]) -> ChainAsset {
+        ChainAsset::Eth(asset)
+    }
+
+    fn chain_hash(hash: [u8; 32]) -> ChainHash {
+        ChainHash::Eth(hash)
+    }
+
+    fn chain_blocks(blocks: Vec<EthereumBlock>) -> ChainBlocks {
+        ChainBlocks::Eth(blocks)
+    }
+}
+
+impl EthLikeChain for Polygon {
+    const NOTICE_HEADER: &'static [u8] = b"MAT:";
+
+    fn chain_asset(asset: [u8; 20]) -> ChainAsset {
+        ChainAsset::Matic(asset)
+    }
+
+    fn chain_hash(hash: [u8; 32]) -> ChainHash {
+        ChainHash::Matic(hash)
+    }
+
+    fn chain_blocks(blocks: Vec<EthereumBlock>) -> ChainBlocks {
+        ChainBlocks::Matic(blocks)
+    }
+}
+
+/// The starport on an Ethereum-like chain, as reached through the node RPC configured for it.
+/// The chain id determines which RPC config is used and how client errors are reported.
+/// EVM chains registered by governance have no `StarportChain` of their own, and are handled
+///  by the `EthLikeStarport` for their chain id directly.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct EthLikeStarport {
+    pub chain_id: ChainId,
+}
+
+impl EthLikeStarport {
+    pub fn new(chain_id: ChainId) -> Self {
+        EthLikeStarport { chain_id }
+    }
+
+    /// The node RPC URL(s) configured for the chain.
+    fn rpc_url(self) -> Option<String> {
+        match self.chain_id {
+            ChainId::Eth => runtime_interfaces::validator_config_interface::get_eth_rpc_url(),
+            ChainId::Matic => runtime_interfaces::validator_config_interface::get_matic_rpc_url(),
+            ChainId::Evm(evm_chain_id) => get_evm_rpc_url(evm_chain_id),
+            ChainId::Gate | ChainId::Dot => None,
+        }
+    }
+
+    /// How many of the node RPC URLs configured for the chain must agree, if set.
+    fn rpc_quorum(self) -> Option<String> {
+        match self.chain_id {
+            ChainId::Eth => runtime_interfaces::validator_config_interface::get_eth_rpc_quorum(),
+            ChainId::Matic => {
+                runtime_interfaces::validator_config_interface::get_matic_rpc_quorum()
+            }
+            ChainId::Evm(evm_chain_id) => get_evm_rpc_quorum(evm_chain_id),
+            ChainId::Gate | ChainId::Dot => None,
+        }
+    }
+
+    /// The event error which client errors for the chain are reported as.
+    fn client_error(self) -> fn(EthereumClientError) -> EventError {
+        match self.chain_id {
+            ChainId::Matic => EventError::PolygonClientError,
+            ChainId::Evm(_) => EventError::EvmClientError,
+            ChainId::Eth | ChainId::Gate | ChainId::Dot => EventError::EthereumClientError,
+        }
+    }
+
+    /// Whether the error means the block simply does not exist yet.
+    pub fn is_block_missing(self, err: &EventError) -> bool {
+        *err == (self.client_error())(EthereumClientError::NoResult)
+    }
+
+    /// Whether blocks the node cached from its Ethereum head subscription may stand in for fetching.
+    fn uses_block_cache(self) -> bool {
+        self.chain_id == ChainId::Eth && is_block_cache_trusted(self.rpc_quorum())
+    }
+
+    /// Describe a reorg of the chain, from the blocks to reverse to the blocks to go forward with.
+    pub fn chain_reorg(
+        self,
+        reverse_blocks: Vec<EthereumBlock>,
+        forward_blocks: Vec<EthereumBlock>,
+    ) -> Result<ChainReorg, Reason> {
+        let from_hash = reverse_blocks
+            .first()
+            .ok_or(Reason::InvalidChainBlock)?
+            .hash;
+        let to_hash = forward_blocks.last().ok_or(Reason::InvalidChainBlock)?.hash;
+        match self.chain_id {
+            ChainId::Eth => Ok(ChainReorg::Eth {
+                from_hash,
+                to_hash,
+                reverse_blocks,
+                forward_blocks,
+            }),
+            ChainId::Matic => Ok(ChainReorg::Matic {
+                from_hash,
+                to_hash,
+                reverse_blocks,
+                forward_blocks,
+            }),
+            ChainId::Evm(evm_chain_id) => Ok(ChainReorg::Evm {
+                evm_chain_id,
+                from_hash,
+                to_hash,
+                reverse_blocks,
+                forward_blocks,
+            }),
+            ChainId::Gate | ChainId::Dot => Err(Reason::Unreachable),
+        }
+    }
+
+    /// Fetch a single block from the starport by number.
+    /// On Ethereum, blocks the node cached from its head subscription are used when available,
+    ///  unless a quorum is required.
+    pub fn fetch_block(
+        self,
+        number: ChainBlockNumber,
+        starport: &[u8; 20],
+    ) -> Result<EthereumBlock, EventError> {
+        if self.uses_block_cache() {
+            if let Some(block) = get_cached_eth_block(number, starport) {
+                return Ok(block);
+            }
+        }
+        fetch_eth_like_block(
+            self.rpc_url(),
+            self.rpc_quorum(),
+            starport,
+            EthereumBlockId::Number(number),
+            self.client_error(),
+        )
+    }
+
+    /// Fetch a single block from the starport by hash.
+    pub fn fetch_block_by_hash(
+        self,
+        hash: [u8; 32],
+        starport: &[u8; 20],
+    ) -> Result<EthereumBlock, EventError> {
+        fetch_eth_like_block(
+            self.rpc_url(),
+            self.rpc_quorum(),
+            starport,
+            EthereumBlockId::Hash(hash),
+            self.client_error(),
+        )
+    }
+
+    /// Fetch blocks from the starport, stopping at the first one which does not exist yet.
+    /// On Ethereum, the run of blocks the node cached is used instead when it is not empty,
+    ///  unless a quorum is required. That run may stop short of `to` even though later blocks
+    ///  exist, so the result can be partial, and callers pick up the rest on their next fetch.
+    pub fn fetch_blocks(
+        self,
+        from: ChainBlockNumber,
+        to: ChainBlockNumber,
+        starport: &[u8; 20],
+    ) -> Result<Vec<EthereumBlock>, EventError> {
+        if self.uses_block_cache() {
+            let cached = get_cached_eth_blocks(from, to, starport);
+            if !cached.is_empty() {
+                return Ok(cached);
+            }
+        }
+        fetch_eth_like_blocks(
+            self.rpc_url(),
+            self.rpc_quorum(),
+            starport,
+            from,
+            to,
+            self.client_error(),
+        )
+    }
+
+    /// Fetch the number of the latest block the chain has finalized.
+    pub fn fetch_finalized_block_number(self) -> Result<ChainBlockNumber, EventError> {
+        fetch_eth_like_block_number(
+            self.rpc_url(),
+            self.rpc_quorum(),
+            EthereumBlockId::Finalized,
+            self.client_error(),
+        )
+    }
+
+    /// Apply an event from the starport, effectively taking the action.
+    /// Proposals are only accepted from the Ethereum starport.
+    pub fn apply_event<T: Config>(self, event: &EthereumLogEvent) -> Result<(), Reason> {
+        match &event.event {
+            EthereumEvent::Lock {
+                asset,
+                sender,
+                chain,
+                recipient,
+                amount,
+            } => internal::lock::lock_internal::<T>(
+                internal::assets::get_asset::<T>(self.chain_id.eth_like_asset(*asset)?)?,
+                self.chain_id.eth_like_account(*sender)?,
+                crate::chains::get_chain_account(chain.to_string(), *recipient)?,
+                get_quantity::<T>(self.chain_id.eth_like_asset(*asset)?, *amount)?,
+            ),
+
+            EthereumEvent::LockCash {
+                sender,
+                chain,
+                recipient,
+                principal,
+                ..
+            } => internal::lock::lock_cash_principal_internal::<T>(
+                self.chain_id.eth_like_account(*sender)?,
+                crate::chains::get_chain_account(chain.to_string(), *recipient)?,
+                CashPrincipalAmount(*principal),
+            ),
+
+            EthereumEvent::ExecuteProposal {
+                title: _title,
+                extrinsics,
+            } if self.chain_id == ChainId::Eth => {
+                dispatch_extrinsics_internal::<T>(extrinsics.to_vec())
+            }
+
+            EthereumEvent::ExecuteProposal { .. } => Err(EventError::ActionNotSupported)?,
+
+            EthereumEvent::ExecTrxRequest {
+                account,
+                trx_request,
+            } => internal::exec_trx_request::exec_trx_request::<T>(
+                &trx_request[..],
+                self.chain_id.eth_like_account(*account)?,
+                None,
+            ),
+
+            EthereumEvent::NoticeInvoked {
+                era_id,
+                era_index,
+                notice_hash,
+                result,
+            } => internal::notices::handle_notice_invoked::<T>(
+                self.chain_id,
+                NoticeId(*era_id, *era_index),
+                self.chain_id.eth_like_hash(*notice_hash)?,
+                result.to_vec(),
+            ),
+        }
+    }
+
+    /// Un-apply an event from the starport, undoing the action to the extent possible/necessary.
+    pub fn unapply_event<T: Config>(self, event: &EthereumLogEvent) -> Result<(), Reason> {
+        match &event.event {
+            EthereumEvent::Lock {
+                asset,
+                sender,
+                chain,
+                recipient,
+                amount,
+            } => internal::lock::undo_lock_internal::<T>(
+                internal::assets::get_asset::<T>(self.chain_id.eth_like_asset(*asset)?)?,
+                self.chain_id.eth_like_account(*sender)?,
+                crate::chains::get_chain_account(chain.to_string(), *recipient)?,
+                get_quantity::<T>(self.chain_id.eth_like_asset(*asset)?, *amount)?,
+            ),
+
+            EthereumEvent::LockCash {
+                sender,
+                chain,
+                recipient,
+                principal,
+                ..
+            } => internal::lock::undo_lock_cash_principal_internal::<T>(
+                self.chain_id.eth_like_account(*sender)?,
+                crate::chains::get_chain_account(chain.to_string(), *recipient)?,
+                CashPrincipalAmount(*principal),
+            ),
+
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether an event from the starport has effects which un-applying cannot undo.
+    /// Locks are undone by un-applying them, but transaction requests and proposals are not.
+    pub fn is_irreversible(self, event: &EthereumLogEvent) -> bool {
+        match &event.event {
+            EthereumEvent::ExecTrxRequest { .. } | EthereumEvent::ExecuteProposal { .. } => true,
+            // a notice invoked on a reorged block is expected to be invoked again
+            EthereumEvent::Lock { .. }
+            | EthereumEvent::LockCash { .. }
+            | EthereumEvent::NoticeInvoked { .. } => false,
+        }
+    }
+
+    /// Determine the value at risk of an event from the starport, before any decay.
+    pub fn event_value<T: Config>(self, event: &EthereumLogEvent) -> Result<USDQuantity, Reason> {
+        match &event.event {
+            EthereumEvent::Lock { asset, amount, .. } => {
+                let quantity = get_quantity::<T>(self.chain_id.eth_like_asset(*asset)?, *amount)?;
+                Ok(get_value::<T>(quantity)?)
+            }
+
+            EthereumEvent::LockCash { principal, .. } => {
+                let quantity = get_cash_quantity::<T>(CashPrincipalAmount(*principal))?;
+                Ok(get_value::<T>(quantity)?)
+            }
+
+            EthereumEvent::ExecTrxRequest {
+                account,
+                trx_request,
+            } => trx_request_event_value::<T>(
+                &trx_request[..],
+                self.chain_id.eth_like_account(*account)?,
+            ),
+
+            EthereumEvent::ExecuteProposal { .. } => Ok(get_value::<T>(INGRESS_LARGE)?),
+
+            EthereumEvent::NoticeInvoked { .. } => Ok(Quantity::new(0, USD)),
+        }
+    }
+
+    /// Determine the kind of an event from the starport.
+    pub fn event_kind(self, event: &EthereumLogEvent) -> Result<EventKind, Reason> {
+        match &event.event {
+            EthereumEvent::Lock { asset, .. } => {
+                Ok(EventKind::Lock(self.chain_id.eth_like_asset(*asset)?))
+            }
+            EthereumEvent::LockCash { .. } => Ok(EventKind::LockCash),
+            EthereumEvent::ExecTrxRequest { .. } => Ok(EventKind::ExecTrxRequest),
+            EthereumEvent::ExecuteProposal { .. } => Ok(EventKind::ExecuteProposal),
+            EthereumEvent::NoticeInvoked { .. } => Ok(EventKind::NoticeInvoked),
+        }
+    }
+}
+
+impl<C: EthLikeChain> StarportChain for C {
+    const NOTICE_HEADER: &'static [u8] = <C as EthLikeChain>::NOTICE_HEADER;
+
+    type AssetId = [u8; 20];
+
+    fn chain_asset(asset: Self::AssetId) -> ChainAsset {
+        <C as EthLikeChain>::chain_asset(asset)
+    }
+
+    fn chain_hash(hash: Self::Hash) -> ChainHash {
+        <C as EthLikeChain>::chain_hash(hash)
+    }
+
+    fn chain_blocks(blocks: Vec<Self::Block>) -> ChainBlocks {
+        <C as EthLikeChain>::chain_blocks(blocks)
+    }
+
+    fn chain_reorg(
+        reverse_blocks: Vec<Self::Block>,
+        forward_blocks: Vec<Self::Block>,
+    ) -> Result<ChainReorg, Reason> {
+        EthLikeStarport::new(Self::ID).chain_reorg(reverse_blocks, forward_blocks)
+    }
+
+    fn fetch_block(
+        number: ChainBlockNumber,
+        starport: &Self::Address,
+    ) -> Result<Self::Block, EventError> {
+        EthLikeStarport::new(Self::ID).fetch_block(number, starport)
+    }
+
+    fn fetch_block_by_hash(
+        hash: Self::Hash,
+        starport: &Self::Address,
+    ) -> Result<Self::Block, EventError> {
+        EthLikeStarport::new(Self::ID).fetch_block_by_hash(hash, starport)
+    }
+
+    fn is_block_missing(err: &EventError) -> bool {
+        EthLikeStarport::new(Self::ID).is_block_missing(err)
+    }
+
+    fn fetch_blocks(
+        from: ChainBlockNumber,
+        to: ChainBlockNumber,
+        starport: &Self::Address,
+    ) -> Result<ChainBlocks, EventError> {
+        let blocks = EthLikeStarport::new(Self::ID).fetch_blocks(from, to, starport)?;
+        Ok(<C as EthLikeChain>::chain_blocks(blocks))
+    }
+
+    fn fetch_finalized_block_number() -> Result<ChainBlockNumber, EventError> {
+        EthLikeStarport::new(Self::ID).fetch_finalized_block_number()
+    }
+
+    fn apply_event<T: Config>(event: &Self::Event) -> Result<(), Reason> {
+        EthLikeStarport::new(Self::ID).apply_event::<T>(event)
+    }
+
+    fn unapply_event<T: Config>(event: &Self::Event) -> Result<(), Reason> {
+        EthLikeStarport::new(Self::ID).unapply_event::<T>(event)
+    }
+
+    fn is_irreversible(event: &Self::Event) -> bool {
+        EthLikeStarport::new(Self::ID).is_irreversible(event)
+    }
+
+    fn event_value<T: Config>(event: &Self::Event) -> Result<USDQuantity, Reason> {
+        EthLikeStarport::new(Self::ID).event_value::<T>(event)
+    }
+
+    fn event_kind(event: &Self::Event) -> Result<EventKind, Reason> {
+        EthLikeStarport::new(Self::ID).event_kind(event)
+    }
+
+    fn encode_extraction_notice(
+        id: &NoticeId,
+        parent: &Self::Hash,
+        asset: &Self::AssetId,
+        account: &Self::Address,
+        amount: &Self::Amount,
+    ) -> EncodedNotice {
+        notices::encode_extraction_notice_eth_like(
+            <C as EthLikeChain>::NOTICE_HEADER,
+            id,
+            parent,
+            asset,
+            account,
+            amount,
+        )
+    }
+
+    fn encode_cash_extraction_notice(
+        id: &NoticeId,
+        parent: &Self::Hash,
+        account: &Self::Address,
+        principal: &Self::Amount,
+    ) -> EncodedNotice {
+        notices::encode_cash_extraction_notice_eth_like(
+            <C as EthLikeChain>::NOTICE_HEADER,
+            id,
+            parent,
+            account,
+            principal,
+        )
+    }
+
+    fn encode_future_yield_notice(
+        id: &NoticeId,
+        parent: &Self::Hash,
+        next_cash_yield: &Self::Rate,
+        next_cash_index: &Self::CashIndex,
+        next_cash_yield_start: &Self::Timestamp,
+    ) -> EncodedNotice {
+        notices::encode_future_yield_notice_eth_like(
+            <C as EthLikeChain>::NOTICE_HEADER,
+            id,
+            parent,
+            next_cash_yield,
+            next_cash_index,
+            next_cash_yield_start,
+        )
+    }
+
+    fn encode_set_supply_cap_notice(
+        id: &NoticeId,
+        parent: &Self::Hash,
+        asset: &Self::AssetId,
+        cap: &Self::Amount,
+    ) -> EncodedNotice {
+        notices::encode_set_supply_cap_notice_eth_like(
+            <C as EthLikeChain>::NOTICE_HEADER,
+            id,
+            parent,
+            asset,
+            cap,
+        )
+    }
+
+    fn encode_change_authority_notice(
+        id: &NoticeId,
+        parent: &Self::Hash,
+        new_authorities: &[<Ethereum as Chain>::Address],
+    ) -> EncodedNotice {
+        notices::encode_change_authority_notice_eth_like(
+            <C as EthLikeChain>::NOTICE_HEADER,
+            id,
+            parent,
+            new_authorities,
+        )
+    }
+}
+
+impl StarportChain for Polkadot {
+    const NOTICE_HEADER: &'static [u8] = b"DOT:";
+
+    type AssetId = PolkadotAssetId;
+
+    fn chain_asset(asset: Self::AssetId) -> ChainAsset {
+        ChainAsset::Dot(asset)
+    }
+
+    fn chain_hash(hash: Self::Hash) -> ChainHash {
+        ChainHash::Dot(hash)
+    }
+
+    fn chain_blocks(blocks: Vec<Self::Block>) -> ChainBlocks {
+        ChainBlocks::Dot(blocks)
+    }
+
+    fn chain_reorg(
+        reverse_blocks: Vec<Self::Block>,
+        forward_blocks: Vec<Self::Block>,
+    ) -> Result<ChainReorg, Reason> {
+        Ok(ChainReorg::Dot {
+            from_hash: reverse_blocks
+                .first()
+                .ok_or(Reason::InvalidChainBlock)?
+                .hash,
+            to_hash: forward_blocks.last().ok_or(Reason::InvalidChainBlock)?.hash,
+            reverse_blocks,
+            forward_blocks,
+        })
+    }
+
+    /// The starport events are read from storage by key, so its address is not needed.
+    fn fetch_block(
+        number: ChainBlockNumber,
+        _starport: &Self::Address,
+    ) -> Result<Self::Block, EventError> {
+        fetch_dot_block(PolkadotBlockId::Number(number))
+    }
+
+    fn fetch_block_by_hash(
+        hash: Self::Hash,
+        _starport: &Self::Address,
+    ) -> Result<Self::Block, EventError> {
+        fetch_dot_block(PolkadotBlockId::Hash(hash))
+    }
+
+    fn is_block_missing(err: &EventError) -> bool {
+        *err == EventError::PolkadotClientError(PolkadotClientError::NoResult)
+    }
+
+    fn apply_event<T: Config>(event: &Self::Event) -> Result<(), Reason> {
+        match event {
+            PolkadotEvent::Lock {
+                asset,
+                sender,
+                chain,
+                recipient,
+                amount,
+            } => internal::lock::lock_internal::<T>(
+                internal::assets::get_asset::<T>(Self::chain_asset(*asset))?,
+                Self::chain_account(*sender),
+                crate::chains::get_chain_account(chain.to_string(), *recipient)?,
+                get_quantity::<T>(Self::chain_asset(*asset), *amount)?,
+            ),
+
+            PolkadotEvent::LockCash {
+                sender,
+                chain,
+                recipient,
+                principal,
+                ..
+            } => internal::lock::lock_cash_principal_internal::<T>(
+                Self::chain_account(*sender),
+                crate::chains::get_chain_account(chain.to_string(), *recipient)?,
+                CashPrincipalAmount(*principal),
+            ),
+
+            PolkadotEvent::ExecuteProposal { .. } => Err(EventError::ActionNotSupported)?,
+
+            PolkadotEvent::ExecTrxRequest {
+                account,
+                trx_request,
+            } => internal::exec_trx_request::exec_trx_request::<T>(
+                &trx_request[..],
+                Self::chain_account(*account),
+                None,
+            ),
+
+            PolkadotEvent::NoticeInvoked {
+                era_id,
+                era_index,
+                notice_hash,
+                result,
+            } => internal::notices::handle_notice_invoked::<T>(
+                Self::ID,
+                NoticeId(*era_id, *era_index),
+                Self::chain_hash(*notice_hash),
+                result.to_vec(),
+            ),
+        }
+    }
+
+    fn unapply_event<T: Config>(event: &Self::Event) -> Result<(), Reason> {
+        match event {
+            PolkadotEvent::Lock {
+                asset,
+                sender,
+                chain,
+                recipient,
+                amount,
+            } => internal::lock::undo_lock_internal::<T>(
+                internal::assets::get_asset::<T>(Self::chain_asset(*asset))?,
+                Self::chain_account(*sender),
+                crate::chains::get_chain_account(chain.to_string(), *recipient)?,
+                get_quantity::<T>(Self::chain_asset(*asset), *amount)?,
+            ),
+
+            PolkadotEvent::LockCash {
+                sender,
+                chain,
+                recipient,
+                principal,
+                ..
+            } => internal::lock::undo_lock_cash_principal_internal::<T>(
+                Self::chain_account(*sender),
+                crate::chains::get_chain_account(chain.to_string(), *recipient)?,
+                CashPrincipalAmount(*principal),
+            ),
+
+            _ => Ok(()),
+        }
+    }
+
+    fn is_irreversible(event: &Self::Event) -> bool {
+        match event {
+            PolkadotEvent::ExecTrxRequest { .. } | PolkadotEvent::ExecuteProposal { .. } => true,
+            // a notice invoked on a reorged block is expected to be invoked again
+            PolkadotEvent::Lock { .. }
+            | PolkadotEvent::LockCash { .. }
+            | PolkadotEvent::NoticeInvoked { .. } => false,
+        }
+    }
+
+    fn event_value<T: Config>(event: &Self::Event) -> Result<USDQuantity, Reason> {
+        match event {
+            PolkadotEvent::Lock { asset, amount, .. } => {
+                let quantity = get_quantity::<T>(Self::chain_asset(*asset), *amount)?;
+                Ok(get_value::<T>(quantity)?)
+            }
+
+            PolkadotEvent::LockCash { principal, .. } => {
+                let quantity = get_cash_quantity::<T>(CashPrincipalAmount(*principal))?;
+                Ok(get_value::<T>(quantity)?)
+            }
+
+            PolkadotEvent::ExecTrxRequest {
+                account,
+                trx_request,
+            } => trx_request_event_value::<T>(&trx_request[..], Self::chain_account(*account)),
+
+            PolkadotEvent::ExecuteProposal { .. } => Ok(get_value::<T>(INGRESS_LARGE)?),
+
+            PolkadotEvent::NoticeInvoked { .. } => Ok(Quantity::new(0, USD)),
+        }
+    }
+
+    fn event_kind(event: &Self::Event) -> Result<EventKind, Reason> {
+        match event {
+            PolkadotEvent::Lock { asset, .. } => Ok(EventKind::Lock(Self::chain_asset(*asset))),
+            PolkadotEvent::LockCash { .. } => Ok(EventKind::LockCash),
+            PolkadotEvent::ExecTrxRequest { .. } => Ok(EventKind::ExecTrxRequest),
+            PolkadotEvent::ExecuteProposal { .. } => Ok(EventKind::ExecuteProposal),
+            PolkadotEvent::NoticeInvoked { .. } => Ok(EventKind::NoticeInvoked),
+        }
+    }
+
+    fn encode_extraction_notice(
+        id: &NoticeId,
+        parent: &Self::Hash,
+        asset: &Self::AssetId,
+        account: &Self::Address,
+        amount: &Self::Amount,
+    ) -> EncodedNotice {
+        notices::encode_extraction_notice_dot(
+            Self::NOTICE_HEADER,
+            id,
+            parent,
+            asset,
+            account,
+            amount,
+        )
+    }
+
+    fn encode_cash_extraction_notice(
+        id: &NoticeId,
+        parent: &Self::Hash,
+        account: &Self::Address,
+        principal: &Self::Amount,
+    ) -> EncodedNotice {
+        notices::encode_cash_extraction_notice_dot(
+            Self::NOTICE_HEADER,
+            id,
+            parent,
+            account,
+            principal,
+        )
+    }
+
+    fn encode_future_yield_notice(
+        id: &NoticeId,
+        parent: &Self::Hash,
+        next_cash_yield: &Self::Rate,
+        next_cash_index: &Self::CashIndex,
+        next_cash_yield_start: &Self::Timestamp,
+    ) -> EncodedNotice {
+        notices::encode_future_yield_notice_dot(
+            Self::NOTICE_HEADER,
+            id,
+            parent,
+            next_cash_yield,
+            next_cash_index,
+            next_cash_yield_start,
+        )
+    }
+
+    fn encode_set_supply_cap_notice(
+        id: &NoticeId,
+        parent: &Self::Hash,
+        asset: &Self::AssetId,
+        cap: &Self::Amount,
+    ) -> EncodedNotice {
+        notices::encode_set_supply_cap_notice_dot(Self::NOTICE_HEADER, id, parent, asset, cap)
+    }
+
+    fn encode_change_authority_notice(
+        id: &NoticeId,
+        parent: &Self::Hash,
+        new_authorities: &[<Ethereum as Chain>::Address],
+    ) -> EncodedNotice {
+        notices::encode_change_authority_notice_dot(
+            Self::NOTICE_HEADER,
+            id,
+            parent,
+            new_authorities,
+        )
+    }
+}
+
+/// Whether cached blocks can be trusted under the configured quorum.
+/// The cache comes from a single websocket, so it cannot be when a quorum of RPC URLs must
+///  agree on each block, nor when the quorum is misconfigured.
+fn is_block_cache_trusted(rpc_quorum: Option<String>) -> bool {
+    match rpc_quorum {
+        None => true,
+        Some(quorum) => quorum.trim().parse::<usize>().map_or(false, |q| q <= 1),
+    }
+}
+
+/// The header prepended to notices for an EVM chain registered by governance.
+/// Each EVM starport only accepts notices carrying the header derived from its own chain id.
+pub fn evm_notice_header(evm_chain_id: EvmChainId) -> Vec<u8> {
+    [&b"EVM"[..], &evm_chain_id.to_be_bytes()[..], &b":"[..]].concat()
+}
+
+/// Determine the node RPC URL for an EVM chain, using the config key governance registered for it.
+pub fn get_evm_rpc_url(evm_chain_id: EvmChainId) -> Option<String> {
+    let evm_chain = EvmChains::get(evm_chain_id)?;
+    runtime_interfaces::validator_config_interface::get(&evm_chain.rpc_url_key)
+}
+
+/// Determine how many of the node RPC URLs for an EVM chain must agree, if set.
+pub fn get_evm_rpc_quorum(evm_chain_id: EvmChainId) -> Option<String> {
+    let evm_chain = EvmChains::get(evm_chain_id)?;
+    runtime_interfaces::validator_config_interface::get(&evm_rpc_quorum_key(&evm_chain.rpc_url_key))
+}
+
+/// The config key for the quorum of an EVM chain, derived from its RPC URL key the same way
+///  ETH_RPC_QUORUM is from ETH_RPC_URL, e.g. ARB_RPC_URL gives ARB_RPC_QUORUM.
+/// Keys without the `_URL` suffix are simply suffixed with `_QUORUM`.
+pub fn evm_rpc_quorum_key(rpc_url_key: &str) -> String {
+    let prefix = rpc_url_key.strip_suffix("_URL").unwrap_or(rpc_url_key);
+    format!("{}_QUORUM", prefix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::eth_log_event;
+
+    #[test]
+    fn test_eth_like_notices_differ_only_by_header() {
+        let id = NoticeId(80, 1);
+        let eth_notice = Ethereum::encode_extraction_notice(&id, &[3; 32], &[2; 20], &[1; 20], &50);
+        let matic_notice =
+            Polygon::encode_extraction_notice(&id, &[3; 32], &[2; 20], &[1; 20], &50);
+        assert_eq!(&eth_notice[0..4], b"ETH:");
+        assert_eq!(&matic_notice[0..4], b"MAT:");
+        assert_eq!(&eth_notice[4..], &matic_notice[4..]);
+    }
+
+    #[test]
+    fn test_is_block_missing() {
+        let eth_missing = EventError::EthereumClientError(EthereumClientError::NoResult);
+        let matic_missing = EventError::PolygonClientError(EthereumClientError::NoResult);
+        let dot_missing = EventError::PolkadotClientError(PolkadotClientError::NoResult);
+
+        assert!(Ethereum::is_block_missing(&eth_missing));
+        assert!(!Ethereum::is_block_missing(&matic_missing));
+        assert!(Polygon::is_block_missing(&matic_missing));
+        assert!(!Polygon::is_block_missing(&EventError::NoRpcUrl));
+        assert!(Polkadot::is_block_missing(&dot_missing));
+        assert!(!Polkadot::is_block_missing(&eth_missing));
+
+        let evm_missing = EventError::EvmClientError(EthereumClientError::NoResult);
+        assert!(EthLikeStarport::new(ChainId::Evm(10)).is_block_missing(&evm_missing));
+        assert!(!EthLikeStarport::new(ChainId::Evm(10)).is_block_missing(&eth_missing));
+    }
+
+    #[test]
+    fn test_is_irreversible() {
+        let lock = eth_log_event(
+            0,
+            EthereumEvent::Lock {
+                asset: [1; 20],
+                sender: [2; 20],
+                chain: String::from("ETH"),
+                recipient: [3; 32],
+                amount: 100,
+            },
+        );
+        let lock_cash = eth_log_event(
+            0,
+            EthereumEvent::LockCash {
+                sender: [2; 20],
+                chain: String::from("ETH"),
+                recipient: [3; 32],
+                amount: 100,
+                principal: 100,
+            },
+        );
+        let exec_trx_request = eth_log_event(
+            0,
+            EthereumEvent::ExecTrxRequest {
+                account: [2; 20],
+                trx_request: String::from(
+                    "(Extract 100 ETH Eth:0x0202020202020202020202020202020202020202)",
+                ),
+            },
+        );
+        let execute_proposal = eth_log_event(
+            0,
+            EthereumEvent::ExecuteProposal {
+                title: String::from("Proposal"),
+                extrinsics: vec![],
+            },
+        );
+        let notice_invoked = eth_log_event(
+            0,
+            EthereumEvent::NoticeInvoked {
+                era_id: 0,
+                era_index: 0,
+                notice_hash: [4; 32],
+                result: vec![],
+            },
+        );
+
+        assert!(!Ethereum::is_irreversible(&lock));
+        assert!(!Ethereum::is_irreversible(&lock_cash));
+        assert!(Ethereum::is_irreversible(&exec_trx_request));
+        assert!(Ethereum::is_irreversible(&execute_proposal));
+        assert!(!Ethereum::is_irreversible(&notice_invoked));
+
+        assert!(!Polkadot::is_irreversible(&PolkadotEvent::Lock {
+            asset: 0,
+            sender: [2; 32],
+            chain: String::from("DOT"),
+            recipient: [3; 32],
+            amount: 100,
+        }));
+        assert!(!Polkadot::is_irreversible(&PolkadotEvent::LockCash {
+            sender: [2; 32],
+            chain: String::from("DOT"),
+            recipient: [3; 32],
+            amount: 100,
+            principal: 100,
+        }));
+        assert!(Polkadot::is_irreversible(&PolkadotEvent::ExecTrxRequest {
+            account: [2; 32],
+            trx_request: String::from("(Extract 100 DOT Dot:0x02)"),
+        }));
+        assert!(Polkadot::is_irreversible(&PolkadotEvent::ExecuteProposal {
+            title: String::from("Proposal"),
+            extrinsics: vec![],
+        }));
+        assert!(!Polkadot::is_irreversible(&PolkadotEvent::NoticeInvoked {
+            era_id: 0,
+            era_index: 0,
+            notice_hash: [4; 32],
+            result: vec![],
+        }));
+    }
+
+    #[test]
+    fn test_eth_like_event_kind() {
+        let lock = eth_log_event(
+            0,
+            EthereumEvent::Lock {
+                asset: [1; 20],
+                sender: [2; 20],
+                chain: String::from("ETH"),
+                recipient: [3; 32],
+                amount: 100,
+            },
+        );
+        let exec_trx_request = eth_log_event(0, EthereumEvent::ExecTrxRequest {
+            account: [2; 20],
+            trx_request: String::from("(Extract 100 Eth:0x0101010101010101010101010101010101010101 Eth:0x0202020202020202020202020202020202020202)"),
+        });
+
+        assert_eq!(
+            Ethereum::event_kind(&lock),
+            Ok(EventKind::Lock(ChainAsset::Eth([1; 20])))
+        );
+        assert_eq!(
+            Polygon::event_kind(&lock),
+            Ok(EventKind::Lock(ChainAsset::Matic([1; 20])))
+        );
+        assert_eq!(
+            EthLikeStarport::new(ChainId::Evm(10)).event_kind(&lock),
+            Ok(EventKind::Lock(ChainAsset::Evm(10, [1; 20])))
+        );
+        assert_eq!(
+            Ethereum::event_kind(&exec_trx_request),
+            Ok(EventKind::ExecTrxRequest)
+        );
+    }
+
+    #[test]
+    fn test_is_block_cache_trusted() {
+        assert!(is_block_cache_trusted(None));
+        assert!(is_block_cache_trusted(Some(String::from("1"))));
+        assert!(!is_block_cache_trusted(Some(String::from("2"))));
+        assert!(!is_block_cache_trusted(Some(String::from("two"))));
+    }
+
+    #[test]
+    fn test_evm_notice_header() {
+        assert_eq!(evm_notice_header(10), b"EVM\x00\x00\x00\x0a:".to_vec());
+        assert_ne!(evm_notice_header(10), evm_notice_header(42161));
+    }
+
+    #[test]
+    fn test_evm_rpc_quorum_key() {
+        assert_eq!(evm_rpc_quorum_key("ARB_RPC_URL"), "ARB_RPC_QUORUM");
+        assert_eq!(evm_rpc_quorum_key("ARB_RPC"), "ARB_RPC_QUORUM");
+        assert_eq!(evm_rpc_quorum_key("URL_ARB_URL"), "URL_ARB_QUORUM");
+    }
+}
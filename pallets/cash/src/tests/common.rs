@@ -77,3 +77,67 @@ pub fn init_cash(account: ChainAccount, amount: CashPrincipal) {
     );
     CashPrincipals::insert(account, amount);
 }
+
+pub fn trx_request_domain(expiry: Option<Timestamp>) -> TrxRequestDomain {
+    TrxRequestDomain {
+        genesis_hash: internal::exec_trx_request::get_genesis_hash::<Test>(),
+        expiry,
+    }
+}
+
+pub fn sign_trx_request(
+    request: &Vec<u8>,
+    nonce: Nonce,
+    domain: TrxRequestDomain,
+) -> ChainAccountSignature {
+    let payload = internal::exec_trx_request::get_signed_payload(request, nonce, domain);
+    let mut full_request: Vec<u8> =
+        format!("\x19Ethereum Signed Message:\n{}", payload.len()).into();
+    full_request.extend_from_slice(&payload[..]);
+    let eth_address = <Ethereum as Chain>::signer_address().unwrap();
+    let eth_key_id = runtime_interfaces::validator_config_interface::get_eth_key_id().unwrap();
+    let signature_raw =
+        runtime_interfaces::keyring_interface::sign_one(full_request, eth_key_id).unwrap();
+    ChainAccountSignature::Eth(eth_address, signature_raw)
+}
+
+pub fn sign_typed_trx_request(
+    request: &Vec<u8>,
+    nonce: Nonce,
+    domain: TrxRequestDomain,
+) -> ChainAccountSignature {
+    let message = trx_req::get_typed_data_message(request, nonce, domain).unwrap();
+    let eth_address = <Ethereum as Chain>::signer_address().unwrap();
+    let eth_key_id = runtime_interfaces::validator_config_interface::get_eth_key_id().unwrap();
+    let signature_raw =
+        runtime_interfaces::keyring_interface::sign_one(message, eth_key_id).unwrap();
+    ChainAccountSignature::EthTypedData(eth_address, signature_raw)
+}
+
+pub fn sign_sr25519_trx_request(
+    pair: &sp_core::sr25519::Pair,
+    request: &Vec<u8>,
+    nonce: Nonce,
+    domain: TrxRequestDomain,
+) -> ChainAccountSignature {
+    use sp_core::Pair;
+    let payload = internal::exec_trx_request::get_signed_payload(request, nonce, domain);
+    ChainAccountSignature::Gate(
+        pair.public().0,
+        GatewaySignature::Sr25519(pair.sign(&payload[..]).0),
+    )
+}
+
+pub fn sign_ed25519_trx_request(
+    pair: &sp_core::ed25519::Pair,
+    request: &Vec<u8>,
+    nonce: Nonce,
+    domain: TrxRequestDomain,
+) -> ChainAccountSignature {
+    use sp_core::Pair;
+    let payload = internal::exec_trx_request::get_signed_payload(request, nonce, domain);
+    ChainAccountSignature::Gate(
+        pair.public().0,
+        GatewaySignature::Ed25519(pair.sign(&payload[..]).0),
+    )
+}
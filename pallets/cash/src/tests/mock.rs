@@ -93,7 +93,7 @@ frame_support::construct_runtime!(
 
 frame_support::parameter_types! {
     pub const BlockHashCount: u64 = 250;
-    pub const SS58Prefix: u8 = 42;
+    pub const SS58Prefix: u8 = crate::params::SS58_PREFIX;
     pub const MinimumPeriod: types::Timestamp = SLOT_DURATION / 2;
 }
 
@@ -13,6 +13,7 @@ pub mod assets;
 pub mod common;
 pub mod mock;
 pub mod protocol;
+pub mod reorgs;
 pub mod testdata;
 pub mod worker;
 
@@ -66,6 +67,18 @@ pub fn val_b() -> ValidatorKeys {
     }
 }
 
+/// Wrap an event as if emitted by the given log of a single test transaction.
+pub fn eth_log_event(
+    log_index: u64,
+    event: ethereum_client::EthereumEvent,
+) -> ethereum_client::EthereumLogEvent {
+    ethereum_client::EthereumLogEvent {
+        transaction_hash: [0xaa; 32],
+        log_index,
+        event,
+    }
+}
+
 pub fn premined_block() -> ethereum_client::EthereumBlock {
     ethereum_client::EthereumBlock {
         hash: [
@@ -78,54 +91,63 @@ pub fn premined_block() -> ethereum_client::EthereumBlock {
         ],
         number: 1,
         events: vec![
-            ethereum_client::EthereumEvent::Lock {
-                asset: [
-                    238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238,
-                    238, 238, 238, 238,
-                ],
-                sender: [
-                    254, 177, 234, 39, 248, 136, 195, 132, 241, 176, 220, 20, 253, 107, 56, 125,
-                    95, 244, 112, 49,
-                ],
-                chain: String::from("ETH"),
-                recipient: [
-                    81, 60, 31, 244, 53, 236, 206, 221, 15, 218, 94, 221, 42, 213, 229, 70, 31, 14,
-                    135, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                ],
-                amount: 1000000000000000000,
-            },
-            ethereum_client::EthereumEvent::Lock {
-                asset: [
-                    216, 123, 167, 165, 11, 46, 126, 102, 15, 103, 138, 137, 94, 75, 114, 231, 203,
-                    76, 205, 156,
-                ],
-                sender: [
-                    254, 177, 234, 39, 248, 136, 195, 132, 241, 176, 220, 20, 253, 107, 56, 125,
-                    95, 244, 112, 49,
-                ],
-                chain: String::from("ETH"),
-                recipient: [
-                    254, 177, 234, 39, 248, 136, 195, 132, 241, 176, 220, 20, 253, 107, 56, 125,
-                    95, 244, 112, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                ],
-                amount: 1000000000000000000,
-            },
-            ethereum_client::EthereumEvent::Lock {
-                asset: [
-                    228, 232, 31, 166, 177, 99, 39, 212, 183, 140, 254, 184, 58, 173, 224, 75, 167,
-                    7, 81, 101,
-                ],
-                sender: [
-                    254, 177, 234, 39, 248, 136, 195, 132, 241, 176, 220, 20, 253, 107, 56, 125,
-                    95, 244, 112, 49,
-                ],
-                chain: String::from("ETH"),
-                recipient: [
-                    254, 177, 234, 39, 248, 136, 195, 132, 241, 176, 220, 20, 253, 107, 56, 125,
-                    95, 244, 112, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                ],
-                amount: 1000000000000000000,
-            },
+            eth_log_event(
+                0,
+                ethereum_client::EthereumEvent::Lock {
+                    asset: [
+                        238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238,
+                        238, 238, 238, 238, 238,
+                    ],
+                    sender: [
+                        254, 177, 234, 39, 248, 136, 195, 132, 241, 176, 220, 20, 253, 107, 56,
+                        125, 95, 244, 112, 49,
+                    ],
+                    chain: String::from("ETH"),
+                    recipient: [
+                        81, 60, 31, 244, 53, 236, 206, 221, 15, 218, 94, 221, 42, 213, 229, 70, 31,
+                        14, 135, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                    ],
+                    amount: 1000000000000000000,
+                },
+            ),
+            eth_log_event(
+                1,
+                ethereum_client::EthereumEvent::Lock {
+                    asset: [
+                        216, 123, 167, 165, 11, 46, 126, 102, 15, 103, 138, 137, 94, 75, 114, 231,
+                        203, 76, 205, 156,
+                    ],
+                    sender: [
+                        254, 177, 234, 39, 248, 136, 195, 132, 241, 176, 220, 20, 253, 107, 56,
+                        125, 95, 244, 112, 49,
+                    ],
+                    chain: String::from("ETH"),
+                    recipient: [
+                        254, 177, 234, 39, 248, 136, 195, 132, 241, 176, 220, 20, 253, 107, 56,
+                        125, 95, 244, 112, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                    ],
+                    amount: 1000000000000000000,
+                },
+            ),
+            eth_log_event(
+                2,
+                ethereum_client::EthereumEvent::Lock {
+                    asset: [
+                        228, 232, 31, 166, 177, 99, 39, 212, 183, 140, 254, 184, 58, 173, 224, 75,
+                        167, 7, 81, 101,
+                    ],
+                    sender: [
+                        254, 177, 234, 39, 248, 136, 195, 132, 241, 176, 220, 20, 253, 107, 56,
+                        125, 95, 244, 112, 49,
+                    ],
+                    chain: String::from("ETH"),
+                    recipient: [
+                        254, 177, 234, 39, 248, 136, 195, 132, 241, 176, 220, 20, 253, 107, 56,
+                        125, 95, 244, 112, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                    ],
+                    amount: 1000000000000000000,
+                },
+            ),
         ],
     }
 }
@@ -239,6 +261,130 @@ pub fn gen_mock_call_block_by_hash_fail(
     }
 }
 
+pub fn gen_mock_call_blocks_range(
+    from: u64,
+    to: u64,
+    block_results: Vec<serde_json::Value>,
+) -> testing::PendingRequest {
+    let mut block_results = block_results.into_iter();
+    let (get_blocks_data, get_blocks_result): (Vec<serde_json::Value>, Vec<serde_json::Value>) =
+        (from..to)
+            .enumerate()
+            .map(|(i, number)| {
+                let get_block_params: Vec<serde_json::Value> =
+                    vec![encode_block_number_hex(number).into(), false.into()];
+                (
+                    serde_json::json!({
+                        "jsonrpc": "2.0",
+                        "method": "eth_getBlockByNumber",
+                        "params": get_block_params,
+                        "id": i + 1
+                    }),
+                    serde_json::json!({
+                        "jsonrpc": "2.0",
+                        "id": i + 1,
+                        "result": block_results.next().unwrap_or(serde_json::Value::Null)
+                    }),
+                )
+            })
+            .unzip();
+
+    testing::PendingRequest {
+        method: "POST".into(),
+        uri: "https://ropsten-eth.compound.finance".into(),
+        headers: vec![("Content-Type".to_owned(), "application/json".to_owned())],
+        body: serde_json::to_vec(&get_blocks_data).unwrap(),
+        response: Some(serde_json::to_vec(&get_blocks_result).unwrap()),
+        sent: true,
+        ..Default::default()
+    }
+}
+
+pub fn gen_mock_call_logs_range(
+    from: u64,
+    to_inclusive: u64,
+    starport_address: <Ethereum as Chain>::Address,
+    response: Vec<u8>,
+) -> testing::PendingRequest {
+    let get_logs_params = vec![serde_json::json!({
+        "address": format!("0x{}", ::hex::encode(&starport_address[..])),
+        "fromBlock": encode_block_number_hex(from),
+        "toBlock": encode_block_number_hex(to_inclusive),
+    })];
+    let get_logs_data = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "eth_getLogs",
+        "params": get_logs_params,
+        "id": 1
+    });
+
+    testing::PendingRequest {
+        method: "POST".into(),
+        uri: "https://ropsten-eth.compound.finance".into(),
+        headers: vec![("Content-Type".to_owned(), "application/json".to_owned())],
+        body: serde_json::to_vec(&get_logs_data).unwrap(),
+        response: Some(response),
+        sent: true,
+        ..Default::default()
+    }
+}
+
+/// Generate the calls for fetching a range of blocks, which have no starport events.
+pub fn gen_mock_range_calls(
+    blocks: &[ethereum_client::EthereumBlock],
+    from: u64,
+    to: u64,
+    starport_address: <Ethereum as Chain>::Address,
+) -> Vec<testing::PendingRequest> {
+    let block_results = blocks
+        .iter()
+        .map(|block| {
+            serde_json::json!({
+                "hash": format!("0x{}", hex::encode(&block.hash[..])),
+                "number": encode_block_number_hex(block.number),
+                "parentHash": format!("0x{}", hex::encode(&block.parent_hash[..])),
+            })
+        })
+        .collect();
+    let mut calls = vec![gen_mock_call_blocks_range(from, to, block_results)];
+    if let Some(last_block) = blocks.last() {
+        calls.push(gen_mock_call_logs_range(
+            from,
+            last_block.number,
+            starport_address,
+            br#"{"jsonrpc":"2.0","id":1,"result":[]}"#.to_vec(),
+        ));
+    }
+    calls
+}
+
+pub fn gen_mock_call_finalized_block(
+    block: &ethereum_client::EthereumBlock,
+) -> testing::PendingRequest {
+    let get_block_data = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "eth_getBlockByNumber",
+        "params": ["finalized", false],
+        "id": 1
+    });
+    let get_block_result = serde_json::json!({
+        "result": {
+            "hash": format!("0x{}", hex::encode(&block.hash[..])),
+            "number": encode_block_number_hex(block.number),
+            "parentHash": format!("0x{}", hex::encode(&block.parent_hash[..])),
+        }
+    });
+    testing::PendingRequest {
+        method: "POST".into(),
+        uri: "https://ropsten-eth.compound.finance".into(),
+        headers: vec![("Content-Type".to_owned(), "application/json".to_owned())],
+        body: serde_json::to_vec(&get_block_data).unwrap(),
+        response: Some(serde_json::to_vec(&get_block_result).unwrap()),
+        sent: true,
+        ..Default::default()
+    }
+}
+
 pub fn gen_mock_calls(
     blocks: &[ethereum_client::EthereumBlock],
     starport_address: <Ethereum as Chain>::Address,
@@ -313,13 +459,29 @@ fn test_it_fails_exec_trx_request_signed() {
                 Origin::signed(Default::default()),
                 vec![],
                 ChainAccountSignature::Eth([0; 20], [0; 65]),
-                0
+                0,
+                TrxRequestDomain {
+                    genesis_hash: [0; 32],
+                    expiry: None
+                }
             ),
             DispatchError::BadOrigin
         );
     });
 }
 
+#[test]
+fn test_exec_trx_request_batch_weights_each_part() {
+    let extract = "(Extract 3 Cash Eth:0x0101010101010101010101010101010101010101)";
+    let transfer = "(Transfer 3 Cash Eth:0x0101010101010101010101010101010101010101)";
+    let batch = format!("(Batch {} {} {})", extract, transfer, extract);
+    assert_eq!(
+        crate::get_exec_req_weights::<Test>(batch.into()),
+        2 * crate::get_exec_req_weights::<Test>(extract.into())
+            + crate::get_exec_req_weights::<Test>(transfer.into())
+    );
+}
+
 #[test]
 fn test_set_interest_rate_model() {
     new_test_ext().execute_with(|| {
@@ -0,0 +1,635 @@
+//! A deterministic simulation of validators tracking an underlying chain which forks.
+//!
+//! The underlying chain is modeled as a tree of blocks, and each validator follows its own head.
+//! Stepping a validator points the simulated node at its view of the chain, runs
+//!  `track_chain_events_on` under its key, and dispatches whatever the worker submits.
+//! The worker reaches the node over its usual JSON-RPC calls, which are answered from that view.
+
+use super::test;
+use super::*;
+use crate::internal::events::track_chain_events_on;
+use codec::alloc::sync::Arc;
+use ethereum_client::{
+    encode_block_hash_hex, encode_block_number_hex, parse_u64, EthereumBlock, EthereumEvent,
+    EthereumHash, EthereumLogEvent,
+};
+use parking_lot::RwLock;
+use sp_core::offchain::{
+    testing::{self, PoolState},
+    Externalities, HttpError, HttpRequestId, HttpRequestStatus, OffchainDbExt, OffchainWorkerExt,
+    OpaqueNetworkState, OpaquePeerId, Timestamp, TransactionPoolExt,
+};
+use std::collections::BTreeMap;
+
+/// The topic of the starport `Lock` event.
+const LOCK_TOPIC: &str = "0xc459acef3ffe957663bb49d644b20d0c790bcb41573893752a72ba6f023b9386";
+
+/// An underlying chain, modeled as a tree of blocks which can fork anywhere.
+pub struct SimChain {
+    blocks: Vec<EthereumBlock>,
+}
+
+impl SimChain {
+    pub fn new(genesis: EthereumBlock) -> Self {
+        SimChain {
+            blocks: vec![genesis],
+        }
+    }
+
+    pub fn genesis(&self) -> EthereumHash {
+        self.blocks[0].hash
+    }
+
+    pub fn block(&self, hash: EthereumHash) -> &EthereumBlock {
+        self.blocks
+            .iter()
+            .find(|block| block.hash == hash)
+            .expect("unknown block")
+    }
+
+    /// Mine a block with the given events on top of the parent, returning its hash.
+    pub fn mine(&mut self, parent: EthereumHash, events: Vec<EthereumLogEvent>) -> EthereumHash {
+        let mut hash = [0xbb; 32];
+        hash[..8].copy_from_slice(&(self.blocks.len() as u64).to_le_bytes());
+        self.blocks.push(EthereumBlock {
+            hash,
+            parent_hash: parent,
+            number: self.block(parent).number + 1,
+            events,
+        });
+        hash
+    }
+
+    /// Mine a run of empty blocks on top of the parent, returning the hash of the last one.
+    pub fn mine_empty(&mut self, parent: EthereumHash, count: u64) -> EthereumHash {
+        (0..count).fold(parent, |tip, _| self.mine(tip, vec![]))
+    }
+
+    /// The blocks from genesis up to the given head, as seen by a node following it.
+    pub fn path(&self, head: EthereumHash) -> Vec<EthereumBlock> {
+        let mut path = vec![self.block(head).clone()];
+        while path[path.len() - 1].hash != self.genesis() {
+            let parent = path[path.len() - 1].parent_hash;
+            path.push(self.block(parent).clone());
+        }
+        path.reverse();
+        path
+    }
+
+    /// The block at the given height, on the path to the given head.
+    pub fn ancestor(&self, head: EthereumHash, number: u64) -> EthereumBlock {
+        self.path(head)
+            .into_iter()
+            .find(|block| block.number == number)
+            .expect("no ancestor at height")
+    }
+}
+
+/// The node a validator queries, which has seen every block but follows a single fork.
+#[derive(Default)]
+pub struct SimNode {
+    blocks: Vec<EthereumBlock>,
+    view: Vec<EthereumBlock>,
+}
+
+impl SimNode {
+    /// Answer a JSON-RPC request, which may be a batch of calls.
+    fn respond(&self, request: &serde_json::Value) -> serde_json::Value {
+        match request {
+            serde_json::Value::Array(calls) => {
+                serde_json::Value::Array(calls.iter().map(|call| self.respond_call(call)).collect())
+            }
+            call => self.respond_call(call),
+        }
+    }
+
+    fn respond_call(&self, call: &serde_json::Value) -> serde_json::Value {
+        let params = &call["params"];
+        let result = match call["method"].as_str() {
+            Some("eth_getBlockByNumber") => {
+                let number = parse_u64(params[0].as_str().map(String::from));
+                self.view
+                    .iter()
+                    .find(|block| Some(block.number) == number)
+                    .map_or(serde_json::Value::Null, block_object)
+            }
+            Some("eth_getBlockByHash") => self
+                .blocks
+                .iter()
+                .find(|block| params[0] == encode_block_hash_hex(block.hash))
+                .map_or(serde_json::Value::Null, block_object),
+            Some("eth_getLogs") => {
+                let filter = &params[0];
+                let blocks: Vec<&EthereumBlock> = match filter["blockHash"].as_str() {
+                    Some(hash) => self
+                        .blocks
+                        .iter()
+                        .filter(|block| hash == encode_block_hash_hex(block.hash))
+                        .collect(),
+                    None => {
+                        let from = parse_u64(filter["fromBlock"].as_str().map(String::from));
+                        let to = parse_u64(filter["toBlock"].as_str().map(String::from));
+                        self.view
+                            .iter()
+                            .filter(|block| Some(block.number) >= from && Some(block.number) <= to)
+                            .collect()
+                    }
+                };
+                blocks
+                    .into_iter()
+                    .flat_map(|block| {
+                        block
+                            .events
+                            .iter()
+                            .map(move |event| log_object(block, event))
+                    })
+                    .collect()
+            }
+            method => panic!("unexpected RPC call {:?}", method),
+        };
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": call["id"],
+            "result": result
+        })
+    }
+}
+
+fn block_object(block: &EthereumBlock) -> serde_json::Value {
+    serde_json::json!({
+        "hash": encode_block_hash_hex(block.hash),
+        "number": encode_block_number_hex(block.number),
+        "parentHash": encode_block_hash_hex(block.parent_hash),
+    })
+}
+
+fn log_object(block: &EthereumBlock, event: &EthereumLogEvent) -> serde_json::Value {
+    let word = |bytes: &[u8]| format!("0x{:0>64}", hex::encode(bytes));
+    let (topics, data) = match &event.event {
+        EthereumEvent::Lock {
+            asset,
+            sender,
+            chain,
+            recipient,
+            amount,
+        } => (
+            vec![
+                String::from(LOCK_TOPIC),
+                word(asset),
+                word(sender),
+                format!("0x{}", hex::encode(recipient)),
+            ],
+            format!(
+                "0x{:064x}{:064x}{:064x}{:0<64}",
+                0x40,
+                amount,
+                chain.len(),
+                hex::encode(chain)
+            ),
+        ),
+        event => panic!("unexpected event in simulation {:?}", event),
+    };
+    serde_json::json!({
+        "address": format!("0x{}", hex::encode(ETH_STARPORT_ADDR)),
+        "blockHash": encode_block_hash_hex(block.hash),
+        "blockNumber": encode_block_number_hex(block.number),
+        "data": data,
+        "logIndex": encode_block_number_hex(event.log_index),
+        "removed": false,
+        "topics": topics,
+        "transactionHash": encode_block_hash_hex(event.transaction_hash),
+        "transactionIndex": "0x0",
+    })
+}
+
+#[derive(Default)]
+struct SimRequest {
+    body: Vec<u8>,
+    response: Option<Vec<u8>>,
+    read: usize,
+}
+
+/// The offchain worker externalities, answering HTTP requests from the simulated node.
+/// Everything besides HTTP is left to the usual test externalities.
+struct SimRpc {
+    offchain: testing::TestOffchainExt,
+    node: Arc<RwLock<SimNode>>,
+    requests: BTreeMap<u16, SimRequest>,
+    next_id: u16,
+}
+
+impl Externalities for SimRpc {
+    fn is_validator(&self) -> bool {
+        self.offchain.is_validator()
+    }
+
+    fn network_state(&self) -> Result<OpaqueNetworkState, ()> {
+        self.offchain.network_state()
+    }
+
+    fn timestamp(&mut self) -> Timestamp {
+        self.offchain.timestamp()
+    }
+
+    fn sleep_until(&mut self, deadline: Timestamp) {
+        self.offchain.sleep_until(deadline)
+    }
+
+    fn random_seed(&mut self) -> [u8; 32] {
+        self.offchain.random_seed()
+    }
+
+    fn http_request_start(
+        &mut self,
+        _method: &str,
+        _uri: &str,
+        _meta: &[u8],
+    ) -> Result<HttpRequestId, ()> {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.requests.insert(id, SimRequest::default());
+        Ok(HttpRequestId(id))
+    }
+
+    fn http_request_add_header(
+        &mut self,
+        _request_id: HttpRequestId,
+        _name: &str,
+        _value: &str,
+    ) -> Result<(), ()> {
+        Ok(())
+    }
+
+    fn http_request_write_body(
+        &mut self,
+        request_id: HttpRequestId,
+        chunk: &[u8],
+        _deadline: Option<Timestamp>,
+    ) -> Result<(), HttpError> {
+        let request = self
+            .requests
+            .get_mut(&request_id.0)
+            .ok_or(HttpError::IoError)?;
+        if chunk.is_empty() {
+            // an empty chunk finishes the body, so the node answers right away
+            let call: serde_json::Value =
+                serde_json::from_slice(&request.body).expect("bad RPC request");
+            request.response = Some(serde_json::to_vec(&self.node.read().respond(&call)).unwrap());
+        } else {
+            request.body.extend_from_slice(chunk);
+        }
+        Ok(())
+    }
+
+    fn http_response_wait(
+        &mut self,
+        ids: &[HttpRequestId],
+        _deadline: Option<Timestamp>,
+    ) -> Vec<HttpRequestStatus> {
+        ids.iter()
+            .map(|id| match self.requests.get(&id.0) {
+                Some(SimRequest {
+                    response: Some(_), ..
+                }) => HttpRequestStatus::Finished(200),
+                _ => HttpRequestStatus::Invalid,
+            })
+            .collect()
+    }
+
+    fn http_response_headers(&mut self, _request_id: HttpRequestId) -> Vec<(Vec<u8>, Vec<u8>)> {
+        vec![]
+    }
+
+    fn http_response_read_body(
+        &mut self,
+        request_id: HttpRequestId,
+        buffer: &mut [u8],
+        _deadline: Option<Timestamp>,
+    ) -> Result<usize, HttpError> {
+        let request = self
+            .requests
+            .get_mut(&request_id.0)
+            .ok_or(HttpError::IoError)?;
+        let response = request.response.as_ref().ok_or(HttpError::IoError)?;
+        let read = buffer.len().min(response.len() - request.read);
+        buffer[..read].copy_from_slice(&response[request.read..request.read + read]);
+        request.read += read;
+        if read == 0 {
+            self.requests.remove(&request_id.0);
+        }
+        Ok(read)
+    }
+
+    fn set_authorized_nodes(&mut self, nodes: Vec<OpaquePeerId>, authorized_only: bool) {
+        self.offchain.set_authorized_nodes(nodes, authorized_only)
+    }
+}
+
+/// Build test externalities whose offchain worker reaches the returned node for RPC.
+pub fn new_sim_test_ext() -> (
+    sp_io::TestExternalities,
+    Arc<RwLock<PoolState>>,
+    Arc<RwLock<SimNode>>,
+) {
+    let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+    let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+    let node = Arc::new(RwLock::new(SimNode::default()));
+
+    let mut test_externalities = sp_io::TestExternalities::default();
+    test_externalities.register_extension(OffchainDbExt::new(offchain.clone()));
+    test_externalities.register_extension(OffchainWorkerExt::new(SimRpc {
+        offchain,
+        node: node.clone(),
+        requests: BTreeMap::new(),
+        next_id: 0,
+    }));
+    test_externalities.register_extension(TransactionPoolExt::new(pool));
+
+    test_externalities.execute_with(|| System::set_block_number(1));
+    (test_externalities, pool_state, node)
+}
+
+/// A validator running the worker, with its own key and view of the underlying chain.
+pub struct SimValidator {
+    pub keys: ValidatorKeys,
+    pub head: EthereumHash,
+    eth_key: String,
+}
+
+/// A set of validators, each tracking some fork of a simulated underlying chain.
+pub struct Sim {
+    pub chain: SimChain,
+    pub validators: Vec<SimValidator>,
+    pool_state: Arc<RwLock<PoolState>>,
+    node: Arc<RwLock<SimNode>>,
+}
+
+impl Sim {
+    /// Initialize storage with the given number of validators, all following the genesis block.
+    pub fn new(
+        num_validators: u8,
+        pool_state: Arc<RwLock<PoolState>>,
+        node: Arc<RwLock<SimNode>>,
+    ) -> Self {
+        initialize_storage_with_blocks(vec![]);
+        let genesis = premined_block();
+        let validators: Vec<SimValidator> = (1..=num_validators)
+            .map(|i| {
+                let eth_key = hex::encode([i; 32]);
+                std::env::set_var("ETH_KEY", &eth_key);
+                SimValidator {
+                    keys: ValidatorKeys {
+                        substrate_id: AccountId32::new([i; 32]),
+                        eth_address: <Ethereum as Chain>::signer_address().unwrap(),
+                    },
+                    head: genesis.hash,
+                    eth_key,
+                }
+            })
+            .collect();
+
+        // replace the default validators with our own
+        for validator in [val_a(), val_b()].iter() {
+            Validators::remove(&validator.substrate_id);
+        }
+        CashModule::initialize_validators(validators.iter().map(|v| v.keys.clone()).collect());
+        CashModule::initialize_genesis_blocks(vec![ChainBlock::Eth(genesis.clone())]);
+        pallet_oracle::Prices::insert(ETH.ticker, Price::from_nominal(ETH.ticker, "2000.00").value);
+
+        Sim {
+            chain: SimChain::new(genesis),
+            validators,
+            pool_state,
+            node,
+        }
+    }
+
+    /// Point the given validators at a new head.
+    pub fn follow(&mut self, validators: &[usize], head: EthereumHash) {
+        for &v in validators {
+            self.validators[v].head = head;
+        }
+    }
+
+    /// Point all the validators at a new head.
+    pub fn follow_all(&mut self, head: EthereumHash) {
+        for validator in self.validators.iter_mut() {
+            validator.head = head;
+        }
+    }
+
+    /// Run the worker once for a validator, dispatching whatever it submits.
+    pub fn step(&mut self, v: usize) -> Result<(), DispatchError> {
+        let last_block = get_last_block::<Test>(ChainId::Eth)?;
+        let view = self.chain.path(self.validators[v].head);
+
+        // a worker whose node cannot see past the last block has nothing to do
+        if view[view.len() - 1].number <= last_block.number() {
+            return Ok(());
+        }
+
+        *self.node.write() = SimNode {
+            blocks: self.chain.blocks.clone(),
+            view,
+        };
+        std::env::set_var("ETH_KEY", &self.validators[v].eth_key);
+        track_chain_events_on::<Test>(ChainId::Eth)?;
+
+        let txs: Vec<Vec<u8>> = self.pool_state.write().transactions.drain(..).collect();
+        for tx in txs {
+            let ex: Extrinsic = Decode::decode(&mut &*tx).unwrap();
+            match ex.call {
+                mock::Call::Cash(crate::Call::receive_chain_blocks(blocks, signature)) => {
+                    CashModule::receive_chain_blocks(Origin::none(), blocks, signature)?
+                }
+                mock::Call::Cash(crate::Call::receive_chain_reorg(reorg, signature)) => {
+                    CashModule::receive_chain_reorg(Origin::none(), reorg, signature)?
+                }
+                call => panic!("unexpected call {:?}", call),
+            }
+        }
+        Ok(())
+    }
+
+    /// Run the worker once for each validator in turn.
+    pub fn step_all(&mut self) -> Result<(), DispatchError> {
+        for v in 0..self.validators.len() {
+            self.step(v)?;
+        }
+        Ok(())
+    }
+}
+
+fn lock_event(amount: &'static str) -> EthereumLogEvent {
+    eth_log_event(
+        0,
+        EthereumEvent::Lock {
+            asset: [238; 20],
+            sender: [3; 20],
+            chain: String::from("ETH"),
+            recipient: [4; 32],
+            amount: qty!(amount, ETH).value,
+        },
+    )
+}
+
+fn reverted_lock(amount: &'static str) -> mock::Event {
+    mock::Event::pallet_cash(crate::Event::ReorgRevertLocked(
+        ChainAsset::Eth([238; 20]),
+        ChainAccount::Eth([3; 20]),
+        ChainAccount::Eth([4; 20]),
+        qty!(amount, ETH).value,
+    ))
+}
+
+fn locked_balance() -> AssetBalance {
+    AssetBalances::get(&Eth, ChainAccount::Eth([4; 20]))
+}
+
+fn emitted(event: &mock::Event) -> bool {
+    System::events().iter().any(|record| record.event == *event)
+}
+
+#[test]
+fn test_sim_reorg_reverts_lock() {
+    let (mut t, pool_state, node) = new_sim_test_ext();
+    t.execute_with(|| {
+        let mut sim = Sim::new(3, pool_state, node);
+        let genesis = sim.chain.genesis();
+        let lock_block = sim.chain.mine(genesis, vec![lock_event("1")]);
+        let fork_a = sim.chain.mine_empty(lock_block, 4);
+        let fork_b = sim.chain.mine_empty(genesis, 6);
+
+        sim.follow_all(fork_a);
+        sim.step_all().unwrap();
+        assert_eq!(locked_balance(), bal!("1", ETH).value);
+
+        sim.follow_all(fork_b);
+        sim.step_all().unwrap();
+        assert_eq!(
+            LastProcessedBlock::get(ChainId::Eth),
+            Some(ChainBlock::Eth(sim.chain.ancestor(fork_b, 6)))
+        );
+        assert_eq!(locked_balance(), 0);
+        assert!(emitted(&reverted_lock("1")));
+
+        sim.step_all().unwrap();
+        assert_eq!(
+            LastProcessedBlock::get(ChainId::Eth),
+            Some(ChainBlock::Eth(sim.chain.block(fork_b).clone()))
+        );
+        assert_eq!(PendingChainReorgs::get(ChainId::Eth), vec![]);
+    });
+}
+
+#[test]
+fn test_sim_reorg_waits_for_supermajority() {
+    let (mut t, pool_state, node) = new_sim_test_ext();
+    t.execute_with(|| {
+        let mut sim = Sim::new(4, pool_state, node);
+        let genesis = sim.chain.genesis();
+        let lock_block = sim.chain.mine(genesis, vec![lock_event("1")]);
+        let fork_a = sim.chain.mine_empty(lock_block, 4);
+        let fork_b = sim.chain.mine_empty(genesis, 6);
+
+        sim.follow_all(fork_a);
+        sim.step_all().unwrap();
+
+        // two out of four is not enough to reorg
+        sim.follow(&[0, 1], fork_b);
+        sim.step_all().unwrap();
+        let pending_reorgs = PendingChainReorgs::get(ChainId::Eth);
+        assert_eq!(pending_reorgs.len(), 1);
+        assert_eq!(pending_reorgs[0].support.len(), 2);
+        assert_eq!(
+            LastProcessedBlock::get(ChainId::Eth),
+            Some(ChainBlock::Eth(sim.chain.block(fork_a).clone()))
+        );
+        assert_eq!(locked_balance(), bal!("1", ETH).value);
+        assert!(!emitted(&reverted_lock("1")));
+
+        // the third supporter pushes it through
+        sim.follow(&[2], fork_b);
+        sim.step(2).unwrap();
+        assert_eq!(PendingChainReorgs::get(ChainId::Eth), vec![]);
+        assert_eq!(
+            LastProcessedBlock::get(ChainId::Eth),
+            Some(ChainBlock::Eth(sim.chain.ancestor(fork_b, 6)))
+        );
+        assert_eq!(locked_balance(), 0);
+        assert!(emitted(&reverted_lock("1")));
+    });
+}
+
+#[test]
+fn test_sim_competing_reorgs() {
+    let (mut t, pool_state, node) = new_sim_test_ext();
+    t.execute_with(|| {
+        let mut sim = Sim::new(4, pool_state, node);
+        let genesis = sim.chain.genesis();
+        let lock_block = sim.chain.mine(genesis, vec![lock_event("1")]);
+        let fork_a = sim.chain.mine_empty(lock_block, 4);
+        let fork_b = sim.chain.mine_empty(genesis, 6);
+        let fork_c = sim.chain.mine_empty(lock_block, 5);
+
+        sim.follow_all(fork_a);
+        sim.step_all().unwrap();
+
+        // the validators disagree on where the chain went, so neither reorg has enough support
+        sim.follow(&[0, 1], fork_b);
+        sim.follow(&[2], fork_c);
+        sim.step_all().unwrap();
+        assert_eq!(PendingChainReorgs::get(ChainId::Eth).len(), 2);
+        assert_eq!(
+            LastProcessedBlock::get(ChainId::Eth),
+            Some(ChainBlock::Eth(sim.chain.block(fork_a).clone()))
+        );
+
+        // once a validator changes its mind and the last one picks the same side, that reorg wins
+        sim.follow(&[0, 3], fork_c);
+        sim.step(0).unwrap();
+        assert_eq!(PendingChainReorgs::get(ChainId::Eth).len(), 2);
+        sim.step(3).unwrap();
+        assert_eq!(PendingChainReorgs::get(ChainId::Eth), vec![]);
+        assert_eq!(
+            LastProcessedBlock::get(ChainId::Eth),
+            Some(ChainBlock::Eth(sim.chain.ancestor(fork_c, 6)))
+        );
+
+        // the lock is from before the fork point, so it stays applied
+        assert_eq!(locked_balance(), bal!("1", ETH).value);
+        assert!(!emitted(&reverted_lock("1")));
+    });
+}
+
+#[test]
+fn test_sim_dissent_clears_pending_blocks() {
+    let (mut t, pool_state, node) = new_sim_test_ext();
+    t.execute_with(|| {
+        let mut sim = Sim::new(3, pool_state, node);
+        let genesis = sim.chain.genesis();
+        let lock_block = sim.chain.mine(genesis, vec![lock_event("1")]);
+        let fork_a = sim.chain.mine_empty(lock_block, 4);
+        let fork_b = sim.chain.mine_empty(genesis, 5);
+
+        sim.follow(&[0], fork_a);
+        sim.follow(&[1, 2], fork_b);
+
+        // the first validator proposes its fork, which the others dissent from
+        sim.step_all().unwrap();
+        assert_eq!(PendingChainBlocks::get(ChainId::Eth), vec![]);
+        assert_eq!(
+            LastProcessedBlock::get(ChainId::Eth),
+            Some(ChainBlock::Eth(premined_block()))
+        );
+
+        // with the dissenting proposal out of the way, the others agree on theirs
+        sim.step(1).unwrap();
+        sim.step(2).unwrap();
+        assert_eq!(
+            LastProcessedBlock::get(ChainId::Eth),
+            Some(ChainBlock::Eth(sim.chain.block(fork_b).clone()))
+        );
+        assert_eq!(locked_balance(), 0);
+    });
+}
@@ -2,10 +2,11 @@ use super::test;
 use super::*;
 
 use frame_support::traits::OffchainWorker;
+use sp_core::offchain::Duration;
+use sp_runtime::offchain::storage_lock::{StorageLock, Time};
 
-#[test]
-fn test_offchain_worker() {
-    let calls = vec![
+fn eth_worker_calls() -> Vec<testing::PendingRequest> {
+    vec![
         testing::PendingRequest {
             method: "POST".into(),
             uri: "https://ropsten-eth.compound.finance".to_string(),
@@ -24,36 +25,29 @@ fn test_offchain_worker() {
             sent: true,
             ..Default::default()
         },
-        testing::PendingRequest {
-            method: "POST".into(),
-            uri: "https://ropsten-eth.compound.finance".to_string(),
-            headers: vec![("Content-Type".to_owned(), "application/json".to_owned())],
-            body: br#"{"jsonrpc":"2.0","method":"eth_getBlockByNumber","params":["0x3",false],"id":1}"#.to_vec(),
-            response: Some(tests::testdata::json_responses::GET_BLOCK_BY_NUMBER_3.to_vec()),
-            sent: true,
-            ..Default::default()
-        },
-        testing::PendingRequest {
-            method: "POST".into(),
-            uri: "https://ropsten-eth.compound.finance".to_string(),
-            headers: vec![("Content-Type".to_owned(), "application/json".to_owned())],
-            body: br#"{"jsonrpc":"2.0","method":"eth_getLogs","params":[{"address":"0x7777777777777777777777777777777777777777","blockHash":"0x72314c1c6837e15e60c5b6732f092118dd25e3ec681f5e089b3a9ad2374e5a8a"}],"id":1}"#.to_vec(),
-            response: Some(testdata::json_responses::GET_LOGS_3.to_vec()),
-            sent: true,
-            ..Default::default()
-        },
-        testing::PendingRequest {
-            method: "POST".into(),
-            uri: "https://ropsten-eth.compound.finance".to_string(),
-            headers: vec![("Content-Type".to_owned(), "application/json".to_owned())],
-            body: br#"{"jsonrpc":"2.0","method":"eth_getBlockByNumber","params":["0x4",false],"id":1}"#.to_vec(),
-            response: Some(tests::testdata::json_responses::NO_RESULT.to_vec()),
-            sent: true,
-            ..Default::default()
-        },
-    ];
+        gen_mock_call_blocks_range(
+            3,
+            53,
+            vec![
+                serde_json::from_slice::<serde_json::Value>(
+                    tests::testdata::json_responses::GET_BLOCK_BY_NUMBER_3,
+                )
+                .unwrap()["result"]
+                    .clone(),
+            ],
+        ),
+        gen_mock_call_logs_range(
+            3,
+            3,
+            [0x77; 20],
+            testdata::json_responses::GET_LOGS_3.to_vec(),
+        ),
+    ]
+}
 
-    let (mut t, pool_state, _offchain_state) = new_test_ext_with_http_calls(calls);
+#[test]
+fn test_offchain_worker() {
+    let (mut t, pool_state, _offchain_state) = new_test_ext_with_http_calls(eth_worker_calls());
 
     t.execute_with(|| {
         initialize_storage();
@@ -78,8 +72,9 @@ fn test_offchain_worker() {
                     assert_eq!(block.events.len(), 3);
 
                     let event = &block.events[1];
+                    assert_eq!(event.log_index, 1);
                     assert_eq!(
-                        event.clone(),
+                        event.event.clone(),
                         ethereum_client::EthereumEvent::Lock {
                             asset: [
                                 216, 123, 167, 165, 11, 46, 126, 102, 15, 103, 138, 137, 94, 75,
@@ -107,3 +102,54 @@ fn test_offchain_worker() {
         }
     });
 }
+
+#[test]
+fn test_track_chain_events_advances_eth_while_matic_fails() {
+    let (mut t, pool_state, _offchain_state) = new_test_ext_with_http_calls(eth_worker_calls());
+
+    t.execute_with(|| {
+        initialize_storage();
+        Starports::insert(ChainId::Matic, ChainStarport::Matic([0x88; 20]));
+        LastProcessedBlock::insert(ChainId::Matic, ChainBlock::Matic(premined_block()));
+
+        // no rpc url is configured for matic
+        let failures = internal::events::track_chain_events::<Test>();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, ChainId::Matic);
+        assert_ne!(failures[0].1, Reason::WorkerBusy);
+
+        assert_eq!(pool_state.read().transactions.len(), 1);
+        let tx = pool_state.write().transactions.pop().unwrap();
+        let ex: Extrinsic = Decode::decode(&mut &*tx).unwrap();
+        if let mock::Call::Cash(crate::Call::receive_chain_blocks(blocks, _signature)) = ex.call {
+            assert_eq!(blocks.chain_id(), ChainId::Eth);
+            assert_eq!(blocks.len(), 2);
+        } else {
+            assert!(false);
+        }
+    });
+}
+
+#[test]
+fn test_track_chain_events_advances_eth_while_matic_busy() {
+    let (mut t, pool_state, _offchain_state) = new_test_ext_with_http_calls(eth_worker_calls());
+
+    t.execute_with(|| {
+        initialize_storage();
+        Starports::insert(ChainId::Matic, ChainStarport::Matic([0x88; 20]));
+        LastProcessedBlock::insert(ChainId::Matic, ChainBlock::Matic(premined_block()));
+
+        // another worker is still stuck on matic
+        let mut matic_lock = StorageLock::<Time>::with_deadline(
+            b"cash::track_chain_events::Matic",
+            Duration::from_millis(120_000),
+        );
+        let _matic_guard = matic_lock.try_lock().unwrap();
+
+        assert_eq!(
+            internal::events::track_chain_events::<Test>(),
+            vec![(ChainId::Matic, Reason::WorkerBusy)]
+        );
+        assert_eq!(pool_state.read().transactions.len(), 1);
+    });
+}
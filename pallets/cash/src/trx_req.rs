@@ -1,9 +1,32 @@
 use trx_request;
 
 use crate::{
-    chains::{ChainAccount, ChainAsset},
-    types::CashOrChainAsset,
+    chains::{ChainAccount, ChainAsset, ChainId},
+    reason::{MathError, Reason},
+    require,
+    symbol::{Symbol, Units},
+    types::{CashOrChainAsset, Nonce, Quantity, TrxRequestDomain},
+    AssetSymbols, Config,
 };
+use frame_support::storage::StorageDoubleMap;
+use gateway_crypto::{
+    eip712_encode_array, eip712_encode_string, eip712_encode_uint, eip712_hash_struct,
+    eip712_message,
+};
+use our_std::{
+    str::{self, FromStr},
+    vec::Vec,
+};
+
+/// The EIP-712 domain for trx requests, salted with the genesis hash of the network.
+const EIP712_DOMAIN_TYPE: &[u8] = b"EIP712Domain(string name,string version,bytes32 salt)";
+const EIP712_DOMAIN_NAME: &str = "Compound Gateway";
+const EIP712_DOMAIN_VERSION: &str = "1";
+
+/// The EIP-712 types for trx requests, with each action broken out for wallets to display.
+const EIP712_ACTION_TYPE: &[u8] =
+    b"Action(string action,string amount,string asset,string collateral,string account)";
+const EIP712_TRX_REQUEST_TYPE: &[u8] = b"TrxRequest(string request,Action[] actions,uint256 nonce,uint256 expiry)Action(string action,string amount,string asset,string collateral,string account)";
 
 impl From<trx_request::Account> for ChainAccount {
     fn from(account: trx_request::Account) -> Self {
@@ -15,23 +38,146 @@ impl From<trx_request::Account> for ChainAccount {
     }
 }
 
-impl From<trx_request::Asset> for CashOrChainAsset {
-    fn from(account: trx_request::Asset) -> Self {
-        match account {
-            trx_request::Asset::Cash => CashOrChainAsset::Cash,
-            trx_request::Asset::Eth(eth_address) => {
-                CashOrChainAsset::ChainAsset(ChainAsset::Eth(eth_address))
-            }
-            trx_request::Asset::Matic(eth_address) => {
-                CashOrChainAsset::ChainAsset(ChainAsset::Matic(eth_address))
-            }
+/// Resolve a trx request asset, looking up any symbol amongst the supported assets on its chain.
+pub fn get_cash_or_chain_asset<T: Config>(
+    asset: trx_request::Asset,
+) -> Result<CashOrChainAsset, Reason> {
+    match asset {
+        trx_request::Asset::Cash => Ok(CashOrChainAsset::Cash),
+        trx_request::Asset::Eth(eth_address) => {
+            Ok(CashOrChainAsset::ChainAsset(ChainAsset::Eth(eth_address)))
+        }
+        trx_request::Asset::Matic(eth_address) => {
+            Ok(CashOrChainAsset::ChainAsset(ChainAsset::Matic(eth_address)))
+        }
+        trx_request::Asset::Symbol(chain, symbol_str) => {
+            let chain_id = ChainId::from(chain);
+            let symbol = Symbol::from_str(&symbol_str)?;
+            let asset = AssetSymbols::get(chain_id, symbol).ok_or(Reason::AssetNotSupported)?;
+            Ok(CashOrChainAsset::ChainAsset(asset))
+        }
+    }
+}
+
+/// Convert a trx request amount into a quantity of the given units.
+/// Decimal amounts are given in whole units, and may not be more precise than the units allow.
+pub fn get_quantity(max_amount: trx_request::MaxAmount, units: Units) -> Result<Quantity, Reason> {
+    match max_amount {
+        trx_request::MaxAmount::Amount(amount) => Ok(Quantity::new(amount, units)),
+        trx_request::MaxAmount::Decimal(decimal) => {
+            require!(
+                decimal.decimals <= units.decimals,
+                Reason::ExcessivePrecision
+            );
+            let scale = 10u128
+                .checked_pow((units.decimals - decimal.decimals).into())
+                .ok_or(MathError::Overflow)?;
+            let amount = decimal
+                .mantissa
+                .checked_mul(scale)
+                .ok_or(MathError::Overflow)?;
+            Ok(Quantity::new(amount, units))
+        }
+        trx_request::MaxAmount::Max => Err(Reason::Unreachable),
+    }
+}
+
+impl From<trx_request::Chain> for ChainId {
+    fn from(chain: trx_request::Chain) -> Self {
+        match chain {
+            trx_request::Chain::Gate => ChainId::Gate,
+            trx_request::Chain::Eth => ChainId::Eth,
+            trx_request::Chain::Matic => ChainId::Matic,
         }
     }
 }
 
+fn eip712_action(
+    action: &str,
+    amount: &trx_request::MaxAmount,
+    asset: &trx_request::Asset,
+    collateral: Option<&trx_request::Asset>,
+    account: &trx_request::Account,
+) -> [u8; 32] {
+    let collateral_str = collateral.map(|c| c.to_string()).unwrap_or_default();
+    eip712_hash_struct(
+        EIP712_ACTION_TYPE,
+        &[
+            eip712_encode_string(action),
+            eip712_encode_string(&amount.to_string()),
+            eip712_encode_string(&asset.to_string()),
+            eip712_encode_string(&collateral_str),
+            eip712_encode_string(&account.to_string()),
+        ],
+    )
+}
+
+fn eip712_actions(trx_request: &trx_request::TrxRequest) -> Vec<[u8; 32]> {
+    match trx_request {
+        trx_request::TrxRequest::Extract(amount, asset, account) => {
+            vec![eip712_action("Extract", amount, asset, None, account)]
+        }
+        trx_request::TrxRequest::Transfer(amount, asset, account) => {
+            vec![eip712_action("Transfer", amount, asset, None, account)]
+        }
+        trx_request::TrxRequest::Liquidate(amount, borrowed_asset, collateral_asset, borrower) => {
+            vec![eip712_action(
+                "Liquidate",
+                amount,
+                borrowed_asset,
+                Some(collateral_asset),
+                borrower,
+            )]
+        }
+        trx_request::TrxRequest::Batch(trx_requests) => {
+            trx_requests.iter().flat_map(eip712_actions).collect()
+        }
+    }
+}
+
+/// Return the EIP-712 domain separator for trx requests signed for the given network.
+pub fn get_eip712_domain_separator(genesis_hash: [u8; 32]) -> [u8; 32] {
+    eip712_hash_struct(
+        EIP712_DOMAIN_TYPE,
+        &[
+            eip712_encode_string(EIP712_DOMAIN_NAME),
+            eip712_encode_string(EIP712_DOMAIN_VERSION),
+            genesis_hash,
+        ],
+    )
+}
+
+/// Return the EIP-712 typed data message for a trx request, whose keccak is signed by the wallet.
+/// Amounts, assets and accounts are given in their canonical trx request form.
+pub fn get_typed_data_message(
+    request: &Vec<u8>,
+    nonce: Nonce,
+    domain: TrxRequestDomain,
+) -> Result<Vec<u8>, Reason> {
+    let request_str: &str = str::from_utf8(&request[..]).map_err(|_| Reason::InvalidUTF8)?;
+    let trx_request = trx_request::parse_request(request_str)?;
+    let struct_hash = eip712_hash_struct(
+        EIP712_TRX_REQUEST_TYPE,
+        &[
+            eip712_encode_string(request_str),
+            eip712_encode_array(&eip712_actions(&trx_request)[..]),
+            eip712_encode_uint(nonce.into()),
+            eip712_encode_uint(domain.expiry.unwrap_or(0).into()),
+        ],
+    );
+    Ok(eip712_message(
+        &get_eip712_domain_separator(domain.genesis_hash),
+        &struct_hash,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        internal::assets::support_asset,
+        tests::{assets::eth, common::*, mock::*},
+    };
     use trx_request;
 
     const ALAN: [u8; 20] = [1; 20];
@@ -47,14 +193,155 @@ mod tests {
 
     #[test]
     fn test_asset_to_chain_asset() {
+        new_test_ext().execute_with(|| {
+            assert_eq!(
+                get_cash_or_chain_asset::<Test>(trx_request::Asset::Eth(ETH)),
+                Ok(CashOrChainAsset::ChainAsset(ChainAsset::Eth(ETH)))
+            );
+
+            assert_eq!(
+                get_cash_or_chain_asset::<Test>(trx_request::Asset::Cash),
+                Ok(CashOrChainAsset::Cash)
+            );
+        });
+    }
+
+    #[test]
+    fn test_symbol_to_chain_asset() {
+        new_test_ext().execute_with(|| {
+            let eth_asset = init_eth_asset().unwrap();
+            support_asset::<Test>(eth).unwrap();
+
+            assert_eq!(
+                get_cash_or_chain_asset::<Test>(trx_request::Asset::Symbol(
+                    trx_request::Chain::Eth,
+                    String::from("ETH")
+                )),
+                Ok(CashOrChainAsset::ChainAsset(eth_asset))
+            );
+            assert_eq!(
+                get_cash_or_chain_asset::<Test>(trx_request::Asset::Symbol(
+                    trx_request::Chain::Matic,
+                    String::from("ETH")
+                )),
+                Err(Reason::AssetNotSupported)
+            );
+            assert_eq!(
+                get_cash_or_chain_asset::<Test>(trx_request::Asset::Symbol(
+                    trx_request::Chain::Eth,
+                    String::from("USDC")
+                )),
+                Err(Reason::AssetNotSupported)
+            );
+        });
+    }
+
+    #[test]
+    fn test_get_quantity() {
+        let units = Units::from_ticker_str("ETH", 18);
+        let decimal = |mantissa, decimals| {
+            trx_request::MaxAmount::Decimal(trx_request::Decimal { mantissa, decimals })
+        };
+
+        assert_eq!(
+            get_quantity(trx_request::MaxAmount::Amount(3), units),
+            Ok(Quantity::new(3, units))
+        );
         assert_eq!(
-            CashOrChainAsset::from(trx_request::Asset::Eth(ETH)),
-            CashOrChainAsset::ChainAsset(ChainAsset::Eth(ETH))
+            get_quantity(decimal(15, 1), units),
+            Ok(Quantity::new(1500000000000000000, units))
+        );
+        assert_eq!(
+            get_quantity(decimal(1, 19), units),
+            Err(Reason::ExcessivePrecision)
+        );
+        assert_eq!(
+            get_quantity(decimal(u128::MAX, 0), units),
+            Err(Reason::MathError(MathError::Overflow))
+        );
+    }
+
+    #[test]
+    fn test_typed_data_message() {
+        let request: Vec<u8> =
+            "(Transfer 1.5 Eth:USDC Eth:0x0101010101010101010101010101010101010101)".into();
+        let domain = TrxRequestDomain {
+            genesis_hash: [1; 32],
+            expiry: None,
+        };
+        let message = get_typed_data_message(&request, 0, domain).unwrap();
+
+        assert_eq!(&message[0..2], b"\x19\x01");
+        assert_eq!(&message[2..34], &get_eip712_domain_separator([1; 32])[..]);
+        assert_eq!(message.len(), 66);
+    }
+
+    #[test]
+    fn test_typed_data_message_binds_domain_and_nonce() {
+        let request: Vec<u8> =
+            "(Transfer 1.5 Eth:USDC Eth:0x0101010101010101010101010101010101010101)".into();
+        let domain = TrxRequestDomain {
+            genesis_hash: [1; 32],
+            expiry: None,
+        };
+        let message = get_typed_data_message(&request, 0, domain).unwrap();
+
+        assert_ne!(
+            get_typed_data_message(&request, 1, domain).unwrap(),
+            message
+        );
+        assert_ne!(
+            get_typed_data_message(
+                &request,
+                0,
+                TrxRequestDomain {
+                    genesis_hash: [2; 32],
+                    expiry: None
+                }
+            )
+            .unwrap(),
+            message
+        );
+        assert_ne!(
+            get_typed_data_message(
+                &request,
+                0,
+                TrxRequestDomain {
+                    genesis_hash: [1; 32],
+                    expiry: Some(1000)
+                }
+            )
+            .unwrap(),
+            message
         );
+    }
+
+    #[test]
+    fn test_typed_data_batch_actions() {
+        let extract = trx_request::parse_request(
+            "(Extract 3 Cash Eth:0x0101010101010101010101010101010101010101)",
+        )
+        .unwrap();
+        let liquidate = trx_request::parse_request("(Liquidate Max Cash Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee Eth:0x0101010101010101010101010101010101010101)").unwrap();
+        let batch = trx_request::parse_request("(Batch (Extract 3 Cash Eth:0x0101010101010101010101010101010101010101) (Liquidate Max Cash Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee Eth:0x0101010101010101010101010101010101010101))").unwrap();
 
         assert_eq!(
-            CashOrChainAsset::from(trx_request::Asset::Cash),
-            CashOrChainAsset::Cash
+            eip712_actions(&batch),
+            [eip712_actions(&extract), eip712_actions(&liquidate)].concat()
         );
     }
+
+    #[test]
+    fn test_typed_data_message_invalid_request() {
+        let request: Vec<u8> = "(Transfer 1.5 Eth:USDC)".into();
+        let domain = TrxRequestDomain {
+            genesis_hash: [1; 32],
+            expiry: None,
+        };
+
+        assert!(matches!(
+            get_typed_data_message(&request, 0, domain),
+            Err(Reason::TrxRequestParseError(_))
+        ));
+    }
 }
@@ -11,6 +11,10 @@ use types_derive::{type_alias, Types};
 
 pub use pallet_oracle::{ticker::Ticker, types::Price};
 
+use crate::params::{
+    INGRESS_QUOTA, INGRESS_SLACK, MAX_EVENT_BLOCKS, MAX_QUEUE_EVENTS, MIN_EVENT_BLOCKS,
+};
+
 pub use crate::{
     chains::{Chain, ChainAsset, ChainBlockNumber, ChainId, Ethereum},
     factor::{BigInt, BigUint, Factor},
@@ -121,6 +125,13 @@ pub enum CashOrChainAsset {
     ChainAsset(ChainAsset),
 }
 
+/// Type for the network a trx request is signed for, and when it stops being executable.
+#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
+pub struct TrxRequestDomain {
+    pub genesis_hash: [u8; 32],
+    pub expiry: Option<Timestamp>,
+}
+
 /// Type for representing a quantity, potentially of any symbol.
 #[derive(Serialize, Deserialize)] // used in config
 #[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
@@ -170,6 +181,98 @@ impl AssetInfo {
     }
 }
 
+/// Type for the parameters governing how events are ingressed from an underlying chain.
+#[derive(Serialize, Deserialize)] // used in config
+#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
+pub struct IngressConfig {
+    /// The maximum value (USD) that can be ingested per underlying chain block.
+    pub quota: AssetAmount,
+    /// Minimum number of underlying chain blocks to wait before ingesting any event.
+    pub min_event_blocks: ChainBlockNumber,
+    /// Maximum number of underlying chain blocks to wait before just ingesting any event.
+    pub max_event_blocks: ChainBlockNumber,
+    /// Maximum size of the block queue before we back-off sending new blocks.
+    pub slack: u32,
+    /// Maximum number of events which may wait on the ingression queue.
+    pub max_queue_events: u32,
+    /// Only ingest blocks the underlying chain has finalized, applying their events immediately.
+    /// This replaces the risk quota, and means reorgs of ingested blocks are refused outright.
+    #[serde(default)]
+    pub finalized_only: bool,
+}
+
+impl IngressConfig {
+    pub const fn quota(self) -> USDQuantity {
+        Quantity::new(self.quota, USD)
+    }
+
+    pub fn is_valid(self) -> bool {
+        self.min_event_blocks <= self.max_event_blocks
+            && self.slack > 0
+            && self.max_queue_events > 0
+    }
+}
+
+impl Default for IngressConfig {
+    fn default() -> Self {
+        IngressConfig {
+            quota: INGRESS_QUOTA.value,
+            min_event_blocks: MIN_EVENT_BLOCKS,
+            max_event_blocks: MAX_EVENT_BLOCKS,
+            slack: INGRESS_SLACK,
+            max_queue_events: MAX_QUEUE_EVENTS,
+            finalized_only: false,
+        }
+    }
+}
+
+/// Type for the kinds of events from an underlying chain, as distinguished by the risk model.
+#[derive(Serialize, Deserialize)] // used in rpc
+#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
+pub enum EventKind {
+    Lock(ChainAsset),
+    LockCash,
+    ExecTrxRequest,
+    ExecuteProposal,
+    NoticeInvoked,
+}
+
+/// Type for the parameters governing the risk an event poses to ingression, before it is final.
+#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
+pub struct RiskModel {
+    /// The factor by which the value of the event is scaled, e.g. above one for volatile collateral.
+    pub factor: Factor,
+    /// The number of underlying chain blocks over which the value at risk halves.
+    pub half_life: ChainBlockNumber,
+}
+
+impl RiskModel {
+    pub fn is_valid(self) -> bool {
+        self.half_life > 0
+    }
+
+    /// Determine the value still at risk, once the given number of blocks have elapsed.
+    pub fn risk(
+        self,
+        value: Quantity,
+        elapsed_blocks: ChainBlockNumber,
+    ) -> Result<Quantity, MathError> {
+        let half_lives = elapsed_blocks
+            .checked_div(self.half_life)
+            .ok_or(MathError::DivisionByZero)?;
+        value.mul_factor(self.factor)?.decay(half_lives)
+    }
+}
+
+impl Default for RiskModel {
+    fn default() -> Self {
+        RiskModel {
+            factor: Factor::ONE,
+            half_life: 1,
+        }
+    }
+}
+
 // Note: ideally we would impl Ord ourselves for all these Ord types,
 //  and assert ticker/units are the same when comparing.
 // We would have to panic, though not for PartialOrd...
@@ -402,6 +505,24 @@ impl Balance {
         Ok(Balance::new(result, self.units))
     }
 
+    /// Returns the positive part of the balance, i.e. the quantity supplied
+    pub fn amount_withdrawable(self) -> Quantity {
+        if self.value > 0 {
+            Quantity::new(self.value as AssetAmount, self.units)
+        } else {
+            Quantity::new(0, self.units)
+        }
+    }
+
+    /// Returns the negative part of the balance, i.e. the quantity borrowed
+    pub fn amount_repayable(self) -> Quantity {
+        if self.value < 0 {
+            Quantity::new(-self.value as AssetAmount, self.units)
+        } else {
+            Quantity::new(0, self.units)
+        }
+    }
+
     /// Returns true if the balance is greater than or equal to given value
     pub fn gte(self: &Self, v: i128) -> bool {
         self.value >= v
@@ -43,7 +43,10 @@ pub fn initialize_validator_config(map: HashMap<String, String>) {
 
 const ETH_KEY_ID_ENV_VAR: &str = "ETH_KEY_ID";
 const ETH_RPC_URL_ENV_VAR: &str = "ETH_RPC_URL";
+const ETH_RPC_QUORUM_ENV_VAR: &str = "ETH_RPC_QUORUM";
 const MATIC_RPC_URL_ENV_VAR: &str = "MATIC_RPC_URL";
+const MATIC_RPC_QUORUM_ENV_VAR: &str = "MATIC_RPC_QUORUM";
+const DOT_RPC_URL_ENV_VAR: &str = "DOT_RPC_URL";
 const MINER_ENV_VAR: &str = "MINER";
 const OPF_URL_ENV_VAR: &str = "OPF_URL";
 
@@ -100,16 +103,31 @@ pub trait ValidatorConfigInterface {
         validator_config_interface_get_internal(ETH_KEY_ID_ENV_VAR).map(Into::into)
     }
 
-    /// Get the Ethereum node RPC URL
+    /// Get the Ethereum node RPC URL, or a comma-separated list of them
     fn get_eth_rpc_url() -> Option<String> {
         validator_config_interface_get_internal(ETH_RPC_URL_ENV_VAR)
     }
 
-    /// Get the Matic node RPC URL
+    /// Get how many of the Ethereum node RPC URLs must agree, if set
+    fn get_eth_rpc_quorum() -> Option<String> {
+        validator_config_interface_get_internal(ETH_RPC_QUORUM_ENV_VAR)
+    }
+
+    /// Get the Matic node RPC URL, or a comma-separated list of them
     fn get_matic_rpc_url() -> Option<String> {
         validator_config_interface_get_internal(MATIC_RPC_URL_ENV_VAR)
     }
 
+    /// Get how many of the Matic node RPC URLs must agree, if set
+    fn get_matic_rpc_quorum() -> Option<String> {
+        validator_config_interface_get_internal(MATIC_RPC_QUORUM_ENV_VAR)
+    }
+
+    /// Get the Polkadot (or parachain) node RPC URL
+    fn get_dot_rpc_url() -> Option<String> {
+        validator_config_interface_get_internal(DOT_RPC_URL_ENV_VAR)
+    }
+
     /// Get the open price feed URLs
     fn get_opf_url() -> Option<String> {
         validator_config_interface_get_internal(OPF_URL_ENV_VAR)
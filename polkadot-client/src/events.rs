@@ -0,0 +1,96 @@
+use codec::{Decode, Encode};
+use our_std::RuntimeDebug;
+
+use types_derive::{type_alias, Types};
+
+/// Type for an asset id on the Polkadot starport, as assigned by the assets pallet.
+#[type_alias]
+pub type PolkadotAssetId = u32;
+
+/// Type for the events deposited by the Polkadot starport.
+/// Note that these must be kept in the same order as the starport declares them.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
+pub enum PolkadotEvent {
+    Lock {
+        asset: PolkadotAssetId,
+        sender: [u8; 32],
+        chain: String,
+        recipient: [u8; 32],
+        amount: u128,
+    },
+    LockCash {
+        sender: [u8; 32],
+        chain: String,
+        recipient: [u8; 32],
+        amount: u128,
+        principal: u128,
+    },
+    ExecTrxRequest {
+        account: [u8; 32],
+        trx_request: String,
+    },
+    ExecuteProposal {
+        title: String,
+        extrinsics: Vec<Vec<u8>>,
+    },
+    NoticeInvoked {
+        era_id: u32,
+        era_index: u32,
+        notice_hash: [u8; 32],
+        result: Vec<u8>,
+    },
+}
+
+/// The storage key under which the starport keeps the events deposited during each block.
+/// This is the key of the `Starport::Events` storage value, i.e. `twox128(pallet) ++ twox128(item)`.
+pub fn starport_events_key() -> Vec<u8> {
+    [
+        sp_io::hashing::twox_128(b"Starport"),
+        sp_io::hashing::twox_128(b"Events"),
+    ]
+    .concat()
+}
+
+/// Decode the SCALE encoded events stored by the starport.
+pub fn decode_events(data: &[u8]) -> Result<Vec<PolkadotEvent>, codec::Error> {
+    Decode::decode(&mut &data[..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_events() {
+        let events = vec![
+            PolkadotEvent::Lock {
+                asset: 7,
+                sender: [1; 32],
+                chain: String::from("ETH"),
+                recipient: [2; 32],
+                amount: 100,
+            },
+            PolkadotEvent::ExecTrxRequest {
+                account: [1; 32],
+                trx_request: String::from(
+                    "(Extract 3 Cash Eth:0x0101010101010101010101010101010101010101)",
+                ),
+            },
+        ];
+        assert_eq!(decode_events(&events.encode()[..]).ok(), Some(events));
+    }
+
+    #[test]
+    fn test_decode_events_bad_data() {
+        assert!(decode_events(&[4, 9]).is_err());
+    }
+
+    #[test]
+    fn test_starport_events_key() {
+        assert_eq!(starport_events_key().len(), 32);
+        assert_eq!(
+            &starport_events_key()[0..16],
+            &sp_io::hashing::twox_128(b"Starport")[..]
+        );
+    }
+}
@@ -0,0 +1,383 @@
+use codec::{Decode, Encode};
+use hex_buffer_serde::{ConstHex, ConstHexForm};
+use our_std::convert::TryInto;
+use sp_runtime::offchain::{http, Duration};
+
+use our_std::{debug, error, info, trace, warn, Deserialize, RuntimeDebug, Serialize};
+use types_derive::{type_alias, Types};
+
+pub mod events;
+
+pub use crate::events::{PolkadotAssetId, PolkadotEvent};
+
+#[type_alias]
+pub type PolkadotBlockNumber = u64;
+
+#[type_alias]
+pub type PolkadotHash = [u8; 32];
+
+const DOT_FETCH_DEADLINE: u64 = 10_000;
+
+#[derive(Clone, RuntimeDebug)]
+pub enum PolkadotBlockId {
+    Hash(PolkadotHash),
+    Number(PolkadotBlockNumber),
+}
+
+#[derive(Serialize, Deserialize)] // used in config
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
+pub struct PolkadotBlock {
+    #[serde(with = "ConstHexForm")]
+    pub hash: PolkadotHash,
+    #[serde(with = "ConstHexForm")]
+    pub parent_hash: PolkadotHash,
+    pub number: PolkadotBlockNumber,
+    #[serde(skip)]
+    pub events: Vec<PolkadotEvent>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, Types)]
+pub enum PolkadotClientError {
+    DecodeError,
+    HttpIoError,
+    HttpTimeout,
+    HttpErrorCode(u16),
+    InvalidUTF8,
+    JsonParseError,
+    NoResult,
+}
+
+#[derive(Deserialize, Serialize, RuntimeDebug, PartialEq)]
+pub struct ResponseError {
+    pub message: Option<String>,
+    pub code: Option<i64>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone, Deserialize, Serialize, RuntimeDebug, PartialEq)]
+pub struct HeaderObject {
+    pub parentHash: Option<String>,
+    pub number: Option<String>,
+    pub stateRoot: Option<String>,
+    pub extrinsicsRoot: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, RuntimeDebug, PartialEq)]
+pub struct HeaderResponse {
+    pub id: Option<u64>,
+    pub result: Option<HeaderObject>,
+    pub error: Option<ResponseError>,
+}
+
+/// Type for the responses to `chain_getBlockHash` and `state_getStorage`, which are hex strings.
+#[derive(Deserialize, RuntimeDebug, PartialEq)]
+pub struct HexResponse {
+    pub id: Option<u64>,
+    pub result: Option<String>,
+    pub error: Option<ResponseError>,
+}
+
+fn parse_error(data: &str) -> PolkadotClientError {
+    error!("Error Parsing: {}", data);
+    PolkadotClientError::JsonParseError
+}
+
+fn deserialize_header_response(response: &str) -> Result<HeaderResponse, PolkadotClientError> {
+    let result: serde_json::error::Result<HeaderResponse> = serde_json::from_str(response);
+    Ok(result.map_err(|_| parse_error(response))?)
+}
+
+fn deserialize_hex_response(response: &str) -> Result<HexResponse, PolkadotClientError> {
+    let result: serde_json::error::Result<HexResponse> = serde_json::from_str(response);
+    Ok(result.map_err(|_| parse_error(response))?)
+}
+
+pub fn decode_hex(data: &str) -> Option<Vec<u8>> {
+    if data.len() < 2 || !data.starts_with("0x") {
+        None
+    } else {
+        hex::decode(&data[2..]).ok()
+    }
+}
+
+pub fn encode_hex(data: &[u8]) -> String {
+    format!("0x{}", ::hex::encode(data))
+}
+
+pub fn parse_hash(val_opt: Option<String>) -> Option<PolkadotHash> {
+    decode_hex(&val_opt?)?.try_into().ok()
+}
+
+pub fn parse_number(val_opt: Option<String>) -> Option<PolkadotBlockNumber> {
+    let val = val_opt?;
+    if val.len() < 3 || !val.starts_with("0x") {
+        None
+    } else {
+        u64::from_str_radix(&val[2..], 16).ok()
+    }
+}
+
+pub fn send_rpc(
+    server: &str,
+    method: serde_json::Value,
+    params: Vec<serde_json::Value>,
+) -> Result<String, PolkadotClientError> {
+    let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(DOT_FETCH_DEADLINE));
+    let data = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": method,
+        "params": params,
+        "id":1
+    })
+    .to_string();
+    trace!("RPC: {}", &data);
+
+    let request = http::Request::post(server, vec![data]);
+
+    let pending = request
+        .deadline(deadline)
+        .add_header("Content-Type", "application/json")
+        .send()
+        .map_err(|_| PolkadotClientError::HttpIoError)?;
+
+    let response = pending
+        .try_wait(deadline)
+        .map_err(|_| PolkadotClientError::HttpTimeout)?
+        .map_err(|_| PolkadotClientError::HttpTimeout)?;
+
+    if response.code != 200 {
+        warn!("Unexpected status code: {}", response.code);
+        return Err(PolkadotClientError::HttpErrorCode(response.code));
+    }
+
+    let body = response.body().collect::<Vec<u8>>();
+
+    // Create a str slice from the body.
+    let body_str = sp_std::str::from_utf8(&body).map_err(|_| {
+        warn!("No UTF8 body");
+        PolkadotClientError::InvalidUTF8
+    })?;
+    trace!("RPC Response: {}", body_str.clone());
+
+    Ok(String::from(body_str))
+}
+
+/// Get the hash of the block with the given number on the canonical chain, if there is one yet.
+pub fn get_block_hash(
+    server: &str,
+    number: PolkadotBlockNumber,
+) -> Result<PolkadotHash, PolkadotClientError> {
+    let response_str: String = send_rpc(server, "chain_getBlockHash".into(), vec![number.into()])?;
+    let response = deserialize_hex_response(&response_str)?;
+    let hash = response.result.ok_or(PolkadotClientError::NoResult)?;
+    parse_hash(Some(hash)).ok_or_else(|| parse_error(&response_str[..]))
+}
+
+pub fn get_header(server: &str, hash: PolkadotHash) -> Result<HeaderObject, PolkadotClientError> {
+    let params = vec![encode_hex(&hash).into()];
+    let response_str: String = send_rpc(server, "chain_getHeader".into(), params)?;
+    let response = deserialize_header_response(&response_str)?;
+    response.result.ok_or(PolkadotClientError::NoResult)
+}
+
+/// Get the events the starport deposited during the block with the given hash.
+pub fn get_events(
+    server: &str,
+    hash: PolkadotHash,
+) -> Result<Vec<PolkadotEvent>, PolkadotClientError> {
+    let params = vec![
+        encode_hex(&events::starport_events_key()).into(),
+        encode_hex(&hash).into(),
+    ];
+    let response_str: String = send_rpc(server, "state_getStorage".into(), params)?;
+    let response = deserialize_hex_response(&response_str)?;
+    if response.error.is_some() {
+        return Err(parse_error(&response_str[..]));
+    }
+
+    // note the storage is simply empty for blocks in which the starport deposited no events
+    match response.result {
+        None => Ok(vec![]),
+        Some(data) => {
+            let bytes = decode_hex(&data).ok_or_else(|| parse_error(&response_str[..]))?;
+            events::decode_events(&bytes[..]).map_err(|err| {
+                error!("Failed to decode {:?}", err);
+                PolkadotClientError::DecodeError
+            })
+        }
+    }
+}
+
+pub fn get_block(
+    server: &str,
+    block_id: PolkadotBlockId,
+) -> Result<PolkadotBlock, PolkadotClientError> {
+    let hash = match &block_id {
+        PolkadotBlockId::Hash(hash) => *hash,
+        PolkadotBlockId::Number(number) => get_block_hash(server, *number)?,
+    };
+    let header = get_header(server, hash)?;
+    let events = get_events(server, hash)?;
+
+    if events.len() > 0 {
+        info!("Found {} events for Dot block {:?}", events.len(), block_id);
+    } else {
+        debug!("Found no events for Dot block {:?}", block_id);
+    }
+
+    Ok(PolkadotBlock {
+        hash,
+        parent_hash: parse_hash(header.parentHash).ok_or_else(|| parse_error("bad parent hash"))?,
+        number: parse_number(header.number).ok_or_else(|| parse_error("bad block number"))?,
+        events,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt};
+
+    const SERVER: &str = "http://localhost:9933";
+    const HASH: &str = "0x2b5c3fbd8b3a4e3f36f1db2bce9c5aa4a8cde0d3d9e0d8e6e0dc77fb1bf1a6e2";
+    const PARENT_HASH: &str = "0x0e0626477621754665a4ee2c4b3b8a6ff6bc3ea18e8d2d1c67b0f28f9c32d1d2";
+
+    fn get_storage_rpc(result: &str) -> (String, String) {
+        (
+            format!(
+                r#"{{"jsonrpc":"2.0","method":"state_getStorage","params":["{}","{}"],"id":1}}"#,
+                encode_hex(&events::starport_events_key()),
+                HASH
+            ),
+            format!(r#"{{"jsonrpc":"2.0","id":1,"result":{}}}"#, result),
+        )
+    }
+
+    /// Stand in for a local node, expecting the given requests and returning the given responses.
+    fn new_test_ext_with_rpcs(rpcs: Vec<(String, String)>) -> sp_io::TestExternalities {
+        let (offchain, state) = testing::TestOffchainExt::new();
+        let mut t = sp_io::TestExternalities::default();
+        t.register_extension(OffchainDbExt::new(offchain.clone()));
+        t.register_extension(OffchainWorkerExt::new(offchain));
+        let mut s = state.write();
+        for (body, response) in rpcs {
+            s.expect_request(testing::PendingRequest {
+                method: "POST".into(),
+                uri: SERVER.into(),
+                headers: vec![("Content-Type".to_owned(), "application/json".to_owned())],
+                body: body.into_bytes(),
+                response: Some(response.into_bytes()),
+                sent: true,
+                ..Default::default()
+            });
+        }
+        t
+    }
+
+    #[test]
+    fn test_get_block() {
+        let lock = PolkadotEvent::Lock {
+            asset: 1984,
+            sender: [1; 32],
+            chain: String::from("ETH"),
+            recipient: [2; 32],
+            amount: 500,
+        };
+        let mut t = new_test_ext_with_rpcs(vec![
+            (
+                String::from(
+                    r#"{"jsonrpc":"2.0","method":"chain_getBlockHash","params":[1286],"id":1}"#,
+                ),
+                format!(r#"{{"jsonrpc":"2.0","id":1,"result":"{}"}}"#, HASH),
+            ),
+            (
+                format!(
+                    r#"{{"jsonrpc":"2.0","method":"chain_getHeader","params":["{}"],"id":1}}"#,
+                    HASH
+                ),
+                format!(
+                    r#"{{"jsonrpc":"2.0","id":1,"result":{{"digest":{{"logs":[]}},"extrinsicsRoot":"0x00","number":"0x506","parentHash":"{}","stateRoot":"0x00"}}}}"#,
+                    PARENT_HASH
+                ),
+            ),
+            get_storage_rpc(&format!(
+                r#""{}""#,
+                encode_hex(&vec![lock.clone()].encode())
+            )),
+        ]);
+        t.execute_with(|| {
+            let block = get_block(SERVER, PolkadotBlockId::Number(1286)).unwrap();
+            assert_eq!(Some(block.hash), parse_hash(Some(HASH.into())));
+            assert_eq!(
+                Some(block.parent_hash),
+                parse_hash(Some(PARENT_HASH.into()))
+            );
+            assert_eq!(block.number, 1286);
+            assert_eq!(block.events, vec![lock]);
+        });
+    }
+
+    #[test]
+    fn test_get_block_without_events() {
+        let mut t = new_test_ext_with_rpcs(vec![
+            (
+                format!(
+                    r#"{{"jsonrpc":"2.0","method":"chain_getHeader","params":["{}"],"id":1}}"#,
+                    HASH
+                ),
+                format!(
+                    r#"{{"jsonrpc":"2.0","id":1,"result":{{"number":"0x1","parentHash":"{}"}}}}"#,
+                    PARENT_HASH
+                ),
+            ),
+            get_storage_rpc("null"),
+        ]);
+        t.execute_with(|| {
+            let hash = parse_hash(Some(HASH.into())).unwrap();
+            let block = get_block(SERVER, PolkadotBlockId::Hash(hash)).unwrap();
+            assert_eq!(block.number, 1);
+            assert_eq!(block.events, vec![]);
+        });
+    }
+
+    #[test]
+    fn test_get_block_not_yet_produced() {
+        let mut t = new_test_ext_with_rpcs(vec![(
+            String::from(r#"{"jsonrpc":"2.0","method":"chain_getBlockHash","params":[99],"id":1}"#),
+            String::from(r#"{"jsonrpc":"2.0","id":1,"result":null}"#),
+        )]);
+        t.execute_with(|| {
+            assert_eq!(
+                get_block(SERVER, PolkadotBlockId::Number(99)),
+                Err(PolkadotClientError::NoResult)
+            );
+        });
+    }
+
+    #[test]
+    fn test_get_events_bad_data() {
+        let mut t = new_test_ext_with_rpcs(vec![get_storage_rpc(r#""0x0409""#)]);
+        t.execute_with(|| {
+            let hash = parse_hash(Some(HASH.into())).unwrap();
+            assert_eq!(
+                get_events(SERVER, hash),
+                Err(PolkadotClientError::DecodeError)
+            );
+        });
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number(Some(String::from("0x506"))), Some(1286));
+        assert_eq!(parse_number(Some(String::from("0x"))), None);
+        assert_eq!(parse_number(Some(String::from("506"))), None);
+        assert_eq!(parse_number(None), None);
+    }
+
+    #[test]
+    fn test_parse_hash() {
+        assert_eq!(parse_hash(Some(encode_hex(&[7; 32]))), Some([7; 32]));
+        assert_eq!(parse_hash(Some(String::from("0x0707"))), None);
+    }
+}
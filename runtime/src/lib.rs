@@ -47,13 +47,14 @@ pub use pallet_timestamp::Call as TimestampCall;
 
 use our_std::warn;
 use pallet_cash::{
-    chains::{ChainAccount, ChainAsset},
+    chains::{ChainAccount, ChainAsset, ChainBlockEvent, ChainId, ChainStarport},
     core::BTreeMap,
     portfolio::Portfolio,
     rates::APR,
     reason::Reason,
     types::{
-        AssetAmount, AssetBalance, AssetInfo, Balance, CashIndex, CashPrincipal, ValidatorKeys,
+        AssetAmount, AssetBalance, AssetInfo, Balance, CashIndex, CashPrincipal, EventKind,
+        RiskModel, ValidatorKeys,
     },
 };
 use pallet_oracle::{ticker::Ticker, types::AssetPrice};
@@ -157,7 +158,7 @@ parameter_types! {
         ::with_sensible_defaults(2 * WEIGHT_PER_SECOND, NORMAL_DISPATCH_RATIO);
     pub BlockLength: frame_system::limits::BlockLength = frame_system::limits::BlockLength
         ::max_with_normal_ratio(5 * 1024 * 1024, NORMAL_DISPATCH_RATIO);
-    pub const SS58Prefix: u8 = 42;
+    pub const SS58Prefix: u8 = pallet_cash::params::SS58_PREFIX;
 }
 
 // Configure FRAME pallets to include in runtime.
@@ -586,6 +587,18 @@ impl_runtime_apis! {
         fn get_validator_info() -> Result<(Vec<ValidatorKeys>, Vec<(ChainAccount, String)>), Reason> {
             Cash::get_validator_info()
         }
+
+        fn get_risk_models() -> Result<Vec<(EventKind, RiskModel)>, Reason> {
+            Cash::get_risk_models()
+        }
+
+        fn get_starport(chain_id: ChainId) -> Result<ChainStarport, Reason> {
+            Cash::get_starport(chain_id)
+        }
+
+        fn get_dead_letter_events(chain_id: ChainId) -> Result<Vec<(ChainBlockEvent, Reason)>, Reason> {
+            Cash::get_dead_letter_events(chain_id)
+        }
     }
 
     #[cfg(feature = "runtime-benchmarks")]
@@ -8,7 +8,7 @@ types_json="./types.json"
 
 cp "$types_json" "$types_json.bak"
 
-projects=("gateway" "pallet-cash" "pallet-oracle" "ethereum-client" "gateway-crypto")
+X
 
 set -x
 
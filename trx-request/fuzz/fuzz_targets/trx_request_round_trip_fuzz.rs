@@ -0,0 +1,14 @@
+#![no_main]
+use std::str;
+use libfuzzer_sys::fuzz_target;
+use trx_request::parse_request;
+
+fuzz_target!(|data: &[u8]| {
+    let data = str::from_utf8(data);
+    if let Ok(v) = data {
+        if let Ok(request) = parse_request(v) {
+            let printed = request.to_request_string();
+            assert_eq!(parse_request(&printed), Ok(request));
+        }
+    }
+});
@@ -1,4 +1,7 @@
 use logos::{Lexer, Logos};
+use our_std::convert::TryFrom;
+
+use crate::Decimal;
 
 #[derive(Logos, Debug, PartialEq, Eq, Clone)]
 pub enum Token<'a> {
@@ -14,6 +17,9 @@ pub enum Token<'a> {
     #[regex(r"[0-9]+", parse_int)]
     Integer(Option<u128>),
 
+    #[regex(r"[0-9]+\.[0-9]+", parse_decimal)]
+    Decimal(Option<Decimal>),
+
     #[regex(r"[a-zA-Z-]+")]
     Identifier(&'a str),
 
@@ -39,6 +45,16 @@ fn parse_int<'a>(lex: &mut Lexer<'a, Token<'a>>) -> Option<u128> {
     u128::from_str_radix(&lex.slice()[..], 10).ok()
 }
 
+fn parse_decimal<'a>(lex: &mut Lexer<'a, Token<'a>>) -> Option<Decimal> {
+    let (whole, fraction) = lex.slice().split_once('.')?;
+    let fraction = fraction.trim_end_matches('0');
+    let decimals = u8::try_from(fraction.len()).ok()?;
+    let mut digits = String::from(whole);
+    digits.push_str(fraction);
+    let mantissa = u128::from_str_radix(&digits, 10).ok()?;
+    Some(Decimal { mantissa, decimals })
+}
+
 pub fn lex<'source>(text: &'source str) -> Lexer<'source, Token> {
     Token::lexer(text)
 }
@@ -85,5 +101,13 @@ mod tests {
           Token::Pair(Some(("eth", "0x20"))),
           Token::RightDelim
         ],
+      decimal_lex:
+        "(1.5 0.250 7.0)" => vec![
+          Token::LeftDelim,
+          Token::Decimal(Some(Decimal { mantissa: 15, decimals: 1 })),
+          Token::Decimal(Some(Decimal { mantissa: 25, decimals: 2 })),
+          Token::Decimal(Some(Decimal { mantissa: 7, decimals: 0 })),
+          Token::RightDelim
+        ],
     }
 }
@@ -3,6 +3,7 @@
 
 mod hex_util;
 mod lex;
+mod print;
 use lex::{lex, Token};
 use logos::Lexer;
 
@@ -10,9 +11,20 @@ use our_std::convert::TryInto;
 
 pub type Amount = u128;
 
-#[derive(PartialEq, Eq, Debug)]
+/// The maximum length of an asset symbol, e.g. `Eth:USDC`.
+pub const MAX_SYMBOL_LEN: usize = 12;
+
+/// A decimal amount given in whole units of an asset, e.g. `1.5` is `15` with `1` decimal.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct Decimal {
+    pub mantissa: u128,
+    pub decimals: u8,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
 pub enum MaxAmount {
     Amount(Amount),
+    Decimal(Decimal),
     Max,
 }
 
@@ -23,25 +35,27 @@ pub enum Chain {
     Matic,
 }
 
-#[derive(PartialEq, Eq, Debug)]
+#[derive(PartialEq, Eq, Debug, Clone)]
 pub enum Asset {
     Cash,
     Eth([u8; 20]),
     Matic([u8; 20]),
+    Symbol(Chain, String),
 }
 
-#[derive(PartialEq, Eq, Debug)]
+#[derive(PartialEq, Eq, Debug, Clone)]
 pub enum Account {
     Gate([u8; 32]),
     Eth([u8; 20]),
     Matic([u8; 20]),
 }
 
-#[derive(PartialEq, Eq, Debug)]
+#[derive(PartialEq, Eq, Debug, Clone)]
 pub enum TrxRequest {
     Extract(MaxAmount, Asset, Account),
     Transfer(MaxAmount, Asset, Account),
     Liquidate(MaxAmount, Asset, Asset, Account),
+    Batch(Vec<TrxRequest>),
 }
 
 #[derive(PartialEq, Eq, Debug)]
@@ -58,6 +72,67 @@ pub enum ParseError<'a> {
     InvalidChainAccount(Chain),
 }
 
+impl<'a> ParseError<'a> {
+    /// The kind of token which was expected where the error occurred.
+    pub fn expected(&self) -> Expected {
+        match self {
+            ParseError::NotImplemented => Expected::Expression,
+            ParseError::LexError(_) => Expected::Token,
+            ParseError::InvalidAmount => Expected::Amount,
+            ParseError::InvalidAccount => Expected::Account,
+            ParseError::InvalidAsset => Expected::Asset,
+            ParseError::InvalidArgs(_, _, _) => Expected::Arguments,
+            ParseError::UnknownFunction(_) => Expected::Function,
+            ParseError::InvalidExpression => Expected::Expression,
+            ParseError::InvalidChain(_) => Expected::Chain,
+            ParseError::InvalidChainAccount(_) => Expected::Account,
+        }
+    }
+}
+
+/// A byte range within the request, given as start (inclusive) and end (exclusive) offsets.
+pub type Span = (usize, usize);
+
+/// The kinds of tokens the parser may expect to find.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Expected {
+    Token,
+    Expression,
+    Function,
+    Arguments,
+    Amount,
+    Asset,
+    Account,
+    Chain,
+}
+
+/// A parse error along with the span of the request at which it occurred.
+#[derive(PartialEq, Eq, Debug)]
+pub struct SpannedParseError<'a> {
+    pub error: ParseError<'a>,
+    pub span: Span,
+}
+
+impl<'a> SpannedParseError<'a> {
+    /// The kind of token which was expected where the error occurred.
+    pub fn expected(&self) -> Expected {
+        self.error.expected()
+    }
+}
+
+type Spanned<'a> = (Token<'a>, Span);
+
+fn at<'a>(span: Span) -> impl Fn(ParseError<'a>) -> SpannedParseError<'a> {
+    move |error| SpannedParseError { error, span }
+}
+
+fn span_of(tokens: &[Spanned]) -> Option<Span> {
+    match (tokens.first(), tokens.last()) {
+        (Some((_, (start, _))), Some((_, (_, end)))) => Some((*start, *end)),
+        _ => None,
+    }
+}
+
 fn parse_amount<'a>(t: &Token) -> Result<Amount, ParseError<'a>> {
     match t {
         Token::Integer(Some(v)) => Ok(*v),
@@ -69,6 +144,7 @@ fn parse_amount<'a>(t: &Token) -> Result<Amount, ParseError<'a>> {
 fn parse_max_amount<'a>(t: &Token) -> Result<MaxAmount, ParseError<'a>> {
     match t {
         Token::Identifier("Max") | Token::Identifier("MAX") => Ok(MaxAmount::Max),
+        Token::Decimal(Some(v)) => Ok(MaxAmount::Decimal(*v)),
         els => Ok(MaxAmount::Amount(parse_amount(els)?)),
     }
 }
@@ -83,17 +159,8 @@ fn parse_chain<'a>(chain: &'a str) -> Result<Chain, ParseError<'a>> {
 }
 
 fn parse_gate_address<'a>(account: &'a str) -> Result<[u8; 32], ParseError<'a>> {
-    // TODO: handle SS58 encoded addresses as well
-    if let Some(stripped) = account.strip_prefix("0x") {
-        let decoded: Vec<u8> =
-            hex::decode(stripped).map_err(|_| ParseError::InvalidChainAccount(Chain::Gate))?;
-        let address: [u8; 32] = decoded
-            .try_into()
-            .map_err(|_| ParseError::InvalidChainAccount(Chain::Gate))?;
-        Ok(address)
-    } else {
-        Err(ParseError::InvalidChainAccount(Chain::Gate))?
-    }
+    gateway_crypto::gateway_str_to_address(account)
+        .ok_or(ParseError::InvalidChainAccount(Chain::Gate))
 }
 
 fn parse_eth_like_address<'a>(account: &'a str, chain: Chain) -> Result<[u8; 20], ParseError<'a>> {
@@ -126,9 +193,16 @@ fn parse_chain_account<'a>(chain: Chain, address: &'a str) -> Result<Account, Pa
     }
 }
 
+fn is_symbol(address: &str) -> bool {
+    !address.starts_with("0x")
+        && address.len() <= MAX_SYMBOL_LEN
+        && address.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
 fn parse_chain_asset<'a>(chain: Chain, address: &'a str) -> Result<Asset, ParseError<'a>> {
     match chain {
         Chain::Gate => Err(ParseError::InvalidAsset),
+        _ if is_symbol(address) => Ok(Asset::Symbol(chain, String::from(address))),
         Chain::Eth => Ok(Asset::Eth(parse_eth_address(address)?)),
         Chain::Matic => Ok(Asset::Matic(parse_matic_address(address)?)),
     }
@@ -155,39 +229,51 @@ fn parse_asset<'a>(t: &Token<'a>) -> Result<Asset, ParseError<'a>> {
     }
 }
 
-fn parse_extract<'a>(args: &[Token<'a>]) -> Result<TrxRequest, ParseError<'a>> {
+fn parse_extract<'a>(
+    span: Span,
+    args: &[Spanned<'a>],
+) -> Result<TrxRequest, SpannedParseError<'a>> {
     match args {
-        [amount_token, asset_token, account_token] => {
-            let max_amount = parse_max_amount(amount_token)?;
-            let asset = parse_asset(asset_token)?;
-            let account = parse_account(account_token)?;
+        [(amount_token, amount_span), (asset_token, asset_span), (account_token, account_span)] => {
+            let max_amount = parse_max_amount(amount_token).map_err(at(*amount_span))?;
+            let asset = parse_asset(asset_token).map_err(at(*asset_span))?;
+            let account = parse_account(account_token).map_err(at(*account_span))?;
 
             Ok(TrxRequest::Extract(max_amount, asset, account))
         }
-        _ => Err(ParseError::InvalidArgs("Extract", 3, args.len())),
+        _ => Err(at(span)(ParseError::InvalidArgs("Extract", 3, args.len()))),
     }
 }
 
-fn parse_transfer<'a>(args: &[Token<'a>]) -> Result<TrxRequest, ParseError<'a>> {
+fn parse_transfer<'a>(
+    span: Span,
+    args: &[Spanned<'a>],
+) -> Result<TrxRequest, SpannedParseError<'a>> {
     match args {
-        [amount_token, asset_token, account_token] => {
-            let max_amount = parse_max_amount(amount_token)?;
-            let asset = parse_asset(asset_token)?;
-            let account = parse_account(account_token)?;
+        [(amount_token, amount_span), (asset_token, asset_span), (account_token, account_span)] => {
+            let max_amount = parse_max_amount(amount_token).map_err(at(*amount_span))?;
+            let asset = parse_asset(asset_token).map_err(at(*asset_span))?;
+            let account = parse_account(account_token).map_err(at(*account_span))?;
 
             Ok(TrxRequest::Transfer(max_amount, asset, account))
         }
-        _ => Err(ParseError::InvalidArgs("Transfer", 3, args.len())),
+        _ => Err(at(span)(ParseError::InvalidArgs("Transfer", 3, args.len()))),
     }
 }
 
-fn parse_liquidate<'a>(args: &[Token<'a>]) -> Result<TrxRequest, ParseError<'a>> {
+fn parse_liquidate<'a>(
+    span: Span,
+    args: &[Spanned<'a>],
+) -> Result<TrxRequest, SpannedParseError<'a>> {
     match args {
-        [amount_token, borrowed_asset_token, collateral_asset_token, account_token] => {
-            let max_amount = parse_max_amount(amount_token)?;
-            let borrowed_asset = parse_asset(borrowed_asset_token)?;
-            let collateral_asset = parse_asset(collateral_asset_token)?;
-            let account = parse_account(account_token)?;
+        [(amount_token, amount_span), (borrowed_asset_token, borrowed_asset_span), (collateral_asset_token, collateral_asset_span), (account_token, account_span)] =>
+        {
+            let max_amount = parse_max_amount(amount_token).map_err(at(*amount_span))?;
+            let borrowed_asset =
+                parse_asset(borrowed_asset_token).map_err(at(*borrowed_asset_span))?;
+            let collateral_asset =
+                parse_asset(collateral_asset_token).map_err(at(*collateral_asset_span))?;
+            let account = parse_account(account_token).map_err(at(*account_span))?;
 
             Ok(TrxRequest::Liquidate(
                 max_amount,
@@ -196,43 +282,116 @@ fn parse_liquidate<'a>(args: &[Token<'a>]) -> Result<TrxRequest, ParseError<'a>>
                 account,
             ))
         }
-        _ => Err(ParseError::InvalidArgs("Liquidate", 4, args.len())),
+        _ => Err(at(span)(ParseError::InvalidArgs(
+            "Liquidate",
+            4,
+            args.len(),
+        ))),
     }
 }
 
-fn parse<'a>(tokens: Lexer<'a, Token<'a>>) -> Result<TrxRequest, ParseError<'a>> {
-    // TODO: I don't love having to clone here at all
-    tokens
-        .clone()
-        .spanned()
-        .fold(Ok(()) as Result<(), ParseError<'a>>, |acc, el| {
-            match (acc, el) {
-                (Err(err), _) => Err(err),
-                (_, (Token::Error, span)) => Err(ParseError::LexError(&tokens.source()[span])),
-                (_, _) => Ok(()),
+/// Split a sequence of tokens into its top-level parenthesized expressions.
+fn split_expressions<'a, 'b>(
+    span: Span,
+    tokens: &'b [Spanned<'a>],
+) -> Result<Vec<&'b [Spanned<'a>]>, SpannedParseError<'a>> {
+    let mut expressions = Vec::new();
+    let mut depth: usize = 0;
+    let mut start: usize = 0;
+
+    for (i, (token, token_span)) in tokens.iter().enumerate() {
+        match token {
+            Token::LeftDelim => {
+                if depth == 0 {
+                    start = i;
+                }
+                depth += 1;
+            }
+            Token::RightDelim => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or(at(*token_span)(ParseError::InvalidExpression))?;
+                if depth == 0 {
+                    expressions.push(&tokens[start..=i]);
+                }
+            }
+            _ if depth == 0 => Err(at(*token_span)(ParseError::InvalidExpression))?,
+            _ => (),
+        }
+    }
+
+    if depth != 0 {
+        Err(at(span_of(&tokens[start..]).unwrap_or(span))(
+            ParseError::InvalidExpression,
+        ))?;
+    }
+
+    Ok(expressions)
+}
+
+fn parse_batch<'a>(span: Span, args: &[Spanned<'a>]) -> Result<TrxRequest, SpannedParseError<'a>> {
+    let expressions = split_expressions(span, args)?;
+    if expressions.is_empty() {
+        Err(at(span)(ParseError::InvalidArgs("Batch", 1, 0)))?;
+    }
+
+    let requests = expressions
+        .into_iter()
+        .map(|expression| match expression {
+            [(Token::LeftDelim, _), (Token::Identifier("Batch"), _), ..] => {
+                Err(at(span_of(expression).unwrap_or(span))(
+                    ParseError::InvalidExpression,
+                ))
             }
-        })?;
+            _ => parse_expression(span, expression),
+        })
+        .collect::<Result<Vec<TrxRequest>, SpannedParseError<'a>>>()?;
 
-    let token_vec = tokens.collect::<Vec<Token<'a>>>();
+    Ok(TrxRequest::Batch(requests))
+}
 
-    match &token_vec[..] {
-        [Token::LeftDelim, Token::Identifier("Extract"), args @ .., Token::RightDelim] => {
-            parse_extract(args)
+fn parse_expression<'a>(
+    outer_span: Span,
+    tokens: &[Spanned<'a>],
+) -> Result<TrxRequest, SpannedParseError<'a>> {
+    let span = span_of(tokens).unwrap_or(outer_span);
+    match tokens {
+        [(Token::LeftDelim, _), (Token::Identifier("Extract"), _), args @ .., (Token::RightDelim, _)] => {
+            parse_extract(span, args)
+        }
+        [(Token::LeftDelim, _), (Token::Identifier("Transfer"), _), args @ .., (Token::RightDelim, _)] => {
+            parse_transfer(span, args)
         }
-        [Token::LeftDelim, Token::Identifier("Transfer"), args @ .., Token::RightDelim] => {
-            parse_transfer(args)
+        [(Token::LeftDelim, _), (Token::Identifier("Liquidate"), _), args @ .., (Token::RightDelim, _)] => {
+            parse_liquidate(span, args)
         }
-        [Token::LeftDelim, Token::Identifier("Liquidate"), args @ .., Token::RightDelim] => {
-            parse_liquidate(args)
+        [(Token::LeftDelim, _), (Token::Identifier("Batch"), _), args @ .., (Token::RightDelim, _)] => {
+            parse_batch(span, args)
         }
-        [Token::LeftDelim, Token::Identifier(fun), .., Token::RightDelim] => {
-            Err(ParseError::UnknownFunction(fun))
+        [(Token::LeftDelim, _), (Token::Identifier(fun), fun_span), .., (Token::RightDelim, _)] => {
+            Err(at(*fun_span)(ParseError::UnknownFunction(fun)))
         }
-        _ => Err(ParseError::InvalidExpression),
+        _ => Err(at(span)(ParseError::InvalidExpression)),
     }
 }
 
-pub fn parse_request<'a>(request: &'a str) -> Result<TrxRequest, ParseError<'a>> {
+fn parse<'a>(tokens: Lexer<'a, Token<'a>>) -> Result<TrxRequest, SpannedParseError<'a>> {
+    let source = tokens.source();
+    let token_vec = tokens
+        .spanned()
+        .map(|(token, range)| match token {
+            Token::Error => Err(at((range.start, range.end))(ParseError::LexError(
+                &source[range],
+            ))),
+            _ => Ok((token, (range.start, range.end))),
+        })
+        .collect::<Result<Vec<Spanned<'a>>, SpannedParseError<'a>>>()?;
+
+    parse_expression((0, source.len()), &token_vec[..])
+}
+
+/// Parse a trx request, or else return the error along with where it occurred.
+pub fn parse_request<'a>(request: &'a str) -> Result<TrxRequest, SpannedParseError<'a>> {
     parse(lex(request))
 }
 
@@ -251,7 +410,7 @@ mod tests {
                 fn $name() {
                     assert_eq!(
                         $exp,
-                        parse_request($input)
+                        parse_request($input).map_err(|err| err.error)
                     )
                 }
             )*
@@ -319,6 +478,16 @@ mod tests {
             Asset::Eth(ETH),
             Account::Gate(BERT)
         )),
+        parse_transfer_gate_ss58:
+        "(Transfer 3 Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee Gate:5C7LYpP2ZH3tpKbvVvwiVe54AapxErdPBbvkYhe6y9ZBkqWt)" => Ok(TrxRequest::Transfer(
+            MaxAmount::Amount(3),
+            Asset::Eth(ETH),
+            Account::Gate(BERT)
+        )),
+        parse_fail_transfer_gate_ss58_wrong_prefix:
+        "(Transfer 3 Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee Gate:13dh9e6R4KNFrcSTZzidnuD2CpbwABXG6fEhzdTXEahwG9h)" => Err(ParseError::InvalidChainAccount(Chain::Gate)),
+        parse_fail_transfer_gate_ss58_bad_checksum:
+        "(Transfer 3 Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee Gate:5C7LYpP2ZH3tpKbvVvwiVe54AapxErdPBbvkYhe6y9ZBkqWu)" => Err(ParseError::InvalidChainAccount(Chain::Gate)),
         parse_transfer_max:
         "(Transfer Max Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee Eth:0x0101010101010101010101010101010101010101)" => Ok(TrxRequest::Transfer(
             MaxAmount::Max,
@@ -339,6 +508,28 @@ mod tests {
             Asset::Eth(ETH),
             Account::Eth(ALAN)
         )),
+        parse_extract_decimal:
+        "(Extract 1.5 Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee Eth:0x0101010101010101010101010101010101010101)" => Ok(TrxRequest::Extract(
+            MaxAmount::Decimal(Decimal { mantissa: 15, decimals: 1 }),
+            Asset::Eth(ETH),
+            Account::Eth(ALAN)
+        )),
+        parse_extract_symbol:
+        "(Extract 3 Eth:USDC Eth:0x0101010101010101010101010101010101010101)" => Ok(TrxRequest::Extract(
+            MaxAmount::Amount(3),
+            Asset::Symbol(Chain::Eth, String::from("USDC")),
+            Account::Eth(ALAN)
+        )),
+        parse_transfer_decimal_symbol:
+        "(Transfer 0.25 Matic:WBTC Eth:0x0101010101010101010101010101010101010101)" => Ok(TrxRequest::Transfer(
+            MaxAmount::Decimal(Decimal { mantissa: 25, decimals: 2 }),
+            Asset::Symbol(Chain::Matic, String::from("WBTC")),
+            Account::Eth(ALAN)
+        )),
+        parse_fail_gate_symbol:
+        "(Extract 3 Gate:USDC Eth:0x0101010101010101010101010101010101010101)" => Err(ParseError::InvalidAsset),
+        parse_fail_invalid_amount_decimal_too_large:
+        "(Extract 340282366920938463463374607431768211456.1 Eth:USDC Eth:0x0101010101010101010101010101010101010101)" => Err(ParseError::InvalidAmount),
         // TODO: Should we prohibit non-Cash from being Maxable?
         parse_fail_no_zero_ex:
         "(Extract 3 Eth:xxeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee Eth:0x0101010101010101010101010101010101010101)" => Err(ParseError::InvalidChainAccount(Chain::Eth)),
@@ -352,5 +543,78 @@ mod tests {
         "(Extract 5 Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeff Eth:0x0101010101010101010101010101010101010101)" => Err(ParseError::InvalidChainAccount(Chain::Eth)),
         parse_fail_invalid_recipient:
         "(Extract 5 Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee Eth:0x0101010101010101010101010101010101010101ff)" => Err(ParseError::InvalidChainAccount(Chain::Eth)),
+        parse_batch:
+        "(Batch (Transfer 3 Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee Gate:0x0202020202020202020202020202020202020202020202020202020202020202) (Extract Max Cash Eth:0x0101010101010101010101010101010101010101))" => Ok(TrxRequest::Batch(vec![
+            TrxRequest::Transfer(
+                MaxAmount::Amount(3),
+                Asset::Eth(ETH),
+                Account::Gate(BERT)
+            ),
+            TrxRequest::Extract(
+                MaxAmount::Max,
+                Asset::Cash,
+                Account::Eth(ALAN)
+            ),
+        ])),
+        parse_batch_single:
+        "(Batch (Extract 3 Cash Eth:0x0101010101010101010101010101010101010101))" => Ok(TrxRequest::Batch(vec![
+            TrxRequest::Extract(
+                MaxAmount::Amount(3),
+                Asset::Cash,
+                Account::Eth(ALAN)
+            ),
+        ])),
+        parse_fail_batch_empty:
+        "(Batch)" => Err(ParseError::InvalidArgs("Batch", 1, 0)),
+        parse_fail_batch_nested:
+        "(Batch (Batch (Extract 3 Cash Eth:0x0101010101010101010101010101010101010101)))" => Err(ParseError::InvalidExpression),
+        parse_fail_batch_bare_args:
+        "(Batch Extract 3 Cash Eth:0x0101010101010101010101010101010101010101)" => Err(ParseError::InvalidExpression),
+        parse_fail_batch_unbalanced:
+        "(Batch (Extract 3 Cash Eth:0x0101010101010101010101010101010101010101)" => Err(ParseError::InvalidExpression),
+        parse_fail_batch_invalid_child:
+        "(Batch (Extract 3 Cash Eth:0x0101010101010101010101010101010101010101) (Transfer 3 Cash))" => Err(ParseError::InvalidArgs("Transfer", 3, 2)),
+        parse_fail_batch_unknown_child:
+        "(Batch (MyFun 3 Eth:0x55))" => Err(ParseError::UnknownFunction("MyFun")),
+    }
+
+    macro_rules! span_tests {
+        ($($name:ident: $input:expr => ($start:expr, $end:expr, $expected:expr),)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let err = parse_request($input).unwrap_err();
+                    assert_eq!(err.span, ($start, $end));
+                    assert_eq!(err.expected(), $expected);
+                }
+            )*
+        }
+    }
+
+    span_tests! {
+        span_lex_error:
+        "(fricassée)" => (8, 10, Expected::Token),
+        span_invalid_expression:
+        "hello" => (0, 5, Expected::Expression),
+        span_empty:
+        "" => (0, 0, Expected::Expression),
+        span_unknown_function:
+        "(MyFun 3 Eth:0x55)" => (1, 6, Expected::Function),
+        span_invalid_args:
+        "(Extract 3 Cash)" => (0, 16, Expected::Arguments),
+        span_invalid_amount:
+        "(Extract hi Cash Eth:0x0101010101010101010101010101010101010101)" => (9, 11, Expected::Amount),
+        span_invalid_asset:
+        "(Extract 3 Gate:0x01 Eth:0x0101010101010101010101010101010101010101)" => (11, 20, Expected::Asset),
+        span_invalid_account:
+        "(Extract 3 Cash Eth:0x0101)" => (16, 26, Expected::Account),
+        span_invalid_chain:
+        "(Extract 3 Cash Btc:0x0101)" => (16, 26, Expected::Chain),
+        span_batch_nested:
+        "(Batch (Batch (Extract 3 Cash Eth:0x0101010101010101010101010101010101010101)))" => (7, 78, Expected::Expression),
+        span_batch_child:
+        "(Batch (Extract 3 Cash Eth:0x0101010101010101010101010101010101010101) (Extract hi Cash Eth:0x0101010101010101010101010101010101010101))" => (80, 82, Expected::Amount),
+        span_batch_unbalanced:
+        "(Batch (Extract 3 Cash Eth:0x0101010101010101010101010101010101010101)" => (7, 69, Expected::Expression),
     }
 }
@@ -0,0 +1,216 @@
+use our_std::fmt;
+
+use crate::{Account, Asset, Chain, Decimal, MaxAmount, TrxRequest};
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let decimals = self.decimals as usize;
+        let digits = format!("{:0>width$}", self.mantissa, width = decimals + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - decimals);
+        if fraction.is_empty() {
+            write!(f, "{}.0", whole)
+        } else {
+            write!(f, "{}.{}", whole, fraction)
+        }
+    }
+}
+
+impl fmt::Display for MaxAmount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MaxAmount::Amount(amount) => write!(f, "{}", amount),
+            MaxAmount::Decimal(decimal) => write!(f, "{}", decimal),
+            MaxAmount::Max => write!(f, "Max"),
+        }
+    }
+}
+
+impl fmt::Display for Chain {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Chain::Gate => write!(f, "Gate"),
+            Chain::Eth => write!(f, "Eth"),
+            Chain::Matic => write!(f, "Matic"),
+        }
+    }
+}
+
+impl fmt::Display for Asset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Asset::Cash => write!(f, "Cash"),
+            Asset::Eth(address) => write!(f, "{}:0x{}", Chain::Eth, hex::encode(address)),
+            Asset::Matic(address) => write!(f, "{}:0x{}", Chain::Matic, hex::encode(address)),
+            Asset::Symbol(chain, symbol) => write!(f, "{}:{}", chain, symbol),
+        }
+    }
+}
+
+impl fmt::Display for Account {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Account::Gate(address) => write!(
+                f,
+                "{}:{}",
+                Chain::Gate,
+                gateway_crypto::gateway_address_string(address)
+            ),
+            Account::Eth(address) => write!(f, "{}:0x{}", Chain::Eth, hex::encode(address)),
+            Account::Matic(address) => write!(f, "{}:0x{}", Chain::Matic, hex::encode(address)),
+        }
+    }
+}
+
+impl fmt::Display for TrxRequest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TrxRequest::Extract(amount, asset, account) => {
+                write!(f, "(Extract {} {} {})", amount, asset, account)
+            }
+            TrxRequest::Transfer(amount, asset, account) => {
+                write!(f, "(Transfer {} {} {})", amount, asset, account)
+            }
+            TrxRequest::Liquidate(amount, borrowed_asset, collateral_asset, borrower) => write!(
+                f,
+                "(Liquidate {} {} {} {})",
+                amount, borrowed_asset, collateral_asset, borrower
+            ),
+            TrxRequest::Batch(requests) => {
+                write!(f, "(Batch")?;
+                for request in requests {
+                    write!(f, " {}", request)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+impl TrxRequest {
+    /// Render the request in its canonical form, which `parse_request` parses back to the same request.
+    pub fn to_request_string(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use proptest::{collection::vec, prelude::*};
+
+    const ALAN: [u8; 20] = [1; 20];
+    const BERT: [u8; 32] = [2; 32];
+    const ETH: [u8; 20] = [238; 20];
+
+    macro_rules! print_tests {
+        ($($name:ident: $input:expr => $exp:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    assert_eq!($exp, $input.to_request_string())
+                }
+            )*
+        }
+    }
+
+    print_tests! {
+        print_extract:
+        TrxRequest::Extract(MaxAmount::Amount(3), Asset::Eth(ETH), Account::Eth(ALAN)) =>
+            "(Extract 3 Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee Eth:0x0101010101010101010101010101010101010101)",
+        print_extract_max_cash:
+        TrxRequest::Extract(MaxAmount::Max, Asset::Cash, Account::Matic(ALAN)) =>
+            "(Extract Max Cash Matic:0x0101010101010101010101010101010101010101)",
+        print_transfer_gate:
+        TrxRequest::Transfer(MaxAmount::Amount(5), Asset::Cash, Account::Gate(BERT)) =>
+            "(Transfer 5 Cash Gate:5C7LYpP2ZH3tpKbvVvwiVe54AapxErdPBbvkYhe6y9ZBkqWt)",
+        print_transfer_decimal_symbol:
+        TrxRequest::Transfer(
+            MaxAmount::Decimal(Decimal { mantissa: 15, decimals: 3 }),
+            Asset::Symbol(Chain::Eth, String::from("USDC")),
+            Account::Eth(ALAN)
+        ) => "(Transfer 0.015 Eth:USDC Eth:0x0101010101010101010101010101010101010101)",
+        print_decimal_whole:
+        TrxRequest::Transfer(
+            MaxAmount::Decimal(Decimal { mantissa: 7, decimals: 0 }),
+            Asset::Cash,
+            Account::Eth(ALAN)
+        ) => "(Transfer 7.0 Cash Eth:0x0101010101010101010101010101010101010101)",
+        print_liquidate:
+        TrxRequest::Liquidate(MaxAmount::Amount(55), Asset::Cash, Asset::Eth(ETH), Account::Eth(ALAN)) =>
+            "(Liquidate 55 Cash Eth:0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee Eth:0x0101010101010101010101010101010101010101)",
+        print_batch:
+        TrxRequest::Batch(vec![
+            TrxRequest::Extract(MaxAmount::Amount(3), Asset::Cash, Account::Eth(ALAN)),
+            TrxRequest::Transfer(MaxAmount::Max, Asset::Cash, Account::Eth(ALAN)),
+        ]) => "(Batch (Extract 3 Cash Eth:0x0101010101010101010101010101010101010101) (Transfer Max Cash Eth:0x0101010101010101010101010101010101010101))",
+    }
+
+    fn chain_asset_chain() -> impl Strategy<Value = Chain> {
+        prop_oneof![Just(Chain::Eth), Just(Chain::Matic)]
+    }
+
+    fn max_amount() -> impl Strategy<Value = MaxAmount> {
+        prop_oneof![
+            any::<u128>().prop_map(MaxAmount::Amount),
+            (any::<u128>(), any::<u8>())
+                .prop_filter("decimals are normalized", |(mantissa, decimals)| {
+                    *decimals == 0 || mantissa % 10 != 0
+                })
+                .prop_map(|(mantissa, decimals)| MaxAmount::Decimal(Decimal {
+                    mantissa,
+                    decimals
+                })),
+            Just(MaxAmount::Max),
+        ]
+    }
+
+    fn asset() -> impl Strategy<Value = Asset> {
+        prop_oneof![
+            Just(Asset::Cash),
+            any::<[u8; 20]>().prop_map(Asset::Eth),
+            any::<[u8; 20]>().prop_map(Asset::Matic),
+            (chain_asset_chain(), "[a-zA-Z0-9]{1,12}")
+                .prop_filter("symbols are not addresses", |(_, symbol)| {
+                    !symbol.starts_with("0x")
+                })
+                .prop_map(|(chain, symbol)| Asset::Symbol(chain, symbol)),
+        ]
+    }
+
+    fn account() -> impl Strategy<Value = Account> {
+        prop_oneof![
+            any::<[u8; 32]>().prop_map(Account::Gate),
+            any::<[u8; 20]>().prop_map(Account::Eth),
+            any::<[u8; 20]>().prop_map(Account::Matic),
+        ]
+    }
+
+    fn single_request() -> impl Strategy<Value = TrxRequest> {
+        prop_oneof![
+            (max_amount(), asset(), account())
+                .prop_map(|(amount, asset, account)| TrxRequest::Extract(amount, asset, account)),
+            (max_amount(), asset(), account())
+                .prop_map(|(amount, asset, account)| TrxRequest::Transfer(amount, asset, account)),
+            (max_amount(), asset(), asset(), account()).prop_map(
+                |(amount, borrowed_asset, collateral_asset, borrower)| {
+                    TrxRequest::Liquidate(amount, borrowed_asset, collateral_asset, borrower)
+                }
+            ),
+        ]
+    }
+
+    fn trx_request() -> impl Strategy<Value = TrxRequest> {
+        prop_oneof![
+            single_request(),
+            vec(single_request(), 1..5).prop_map(TrxRequest::Batch),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn print_parse_round_trip(request in trx_request()) {
+            let printed = request.to_request_string();
+            prop_assert_eq!(parse_request(&printed), Ok(request));
+        }
+    }
+}